@@ -0,0 +1,120 @@
+//! `diff` command.
+
+use {
+    crate::{
+        error::Error,
+        output::{CommandOutput, Table},
+    },
+    serde::Serialize,
+    solana_commitment_config::CommitmentConfig,
+    solana_feature_gate_client::{
+        cluster::normalize_url,
+        diff::{diff as diff_feature_sets, FeatureSet, FeatureSetDiff},
+        known_features::KnownFeatureRegistry,
+        snapshot::FeatureStatus,
+    },
+    solana_rpc_client::rpc_client::RpcClient,
+    std::path::Path,
+};
+
+/// The output of the `diff` command.
+#[derive(Debug, Serialize)]
+pub struct DiffOutput {
+    /// The left-hand feature set source.
+    pub left: String,
+    /// The right-hand feature set source.
+    pub right: String,
+    /// The features whose status differs.
+    #[serde(flatten)]
+    pub diff: FeatureSetDiff,
+}
+
+impl CommandOutput for DiffOutput {
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["FEATURE", "NAME", &self.left, &self.right]);
+        for feature in &self.diff.features {
+            table.push(vec![
+                feature.id.to_string(),
+                feature.name.clone().unwrap_or_default(),
+                status_cell(feature.left),
+                status_cell(feature.right),
+            ]);
+        }
+        table
+    }
+}
+
+fn status_cell(status: Option<FeatureStatus>) -> String {
+    match status {
+        Some(FeatureStatus::Active { activated_at }) => format!("active (slot {activated_at})"),
+        Some(status) => status.label().to_string(),
+        None => "missing".to_string(),
+    }
+}
+
+/// Loads a feature set from a snapshot file, or fetches it from a cluster if
+/// `source` is not an existing file.
+fn load_feature_set(source: &str) -> Result<FeatureSet, Error> {
+    if Path::new(source).is_file() {
+        return Ok(FeatureSet::load(source)?);
+    }
+    let rpc_client = RpcClient::new_with_commitment(
+        normalize_url(source).to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    Ok(FeatureSet::fetch(&rpc_client)?)
+}
+
+/// Compares the feature sets of two clusters or snapshots.
+pub fn diff(left: &str, right: &str) -> Result<DiffOutput, Error> {
+    let diff = diff_feature_sets(
+        &load_feature_set(left)?,
+        &load_feature_set(right)?,
+        &KnownFeatureRegistry::new(),
+    );
+    Ok(DiffOutput {
+        left: left.to_string(),
+        right: right.to_string(),
+        diff,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_feature_gate_client::ID};
+
+    #[test]
+    fn test_diff_snapshot_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, data: &str| {
+            let path = dir.path().join(name);
+            let json = format!(
+                r#"[{{ "address": "2aQJYqER2aKyb3cZw22v4SL2xMX7vwXBRWfvS4pTrtED", "lamports": 1, "owner": "{ID}", "data": "{data}" }}]"#
+            );
+            std::fs::write(&path, json).unwrap();
+            path.display().to_string()
+        };
+        let testnet = write("testnet.json", "AQUAAAAAAAAA");
+        let mainnet = write("mainnet.json", "AAAAAAAAAAAA");
+
+        let output = diff(&testnet, &mainnet).unwrap();
+
+        assert_eq!(output.diff.features.len(), 1);
+        let table = output.table().to_string();
+        let row = table.lines().nth(1).unwrap().split("  ");
+        assert_eq!(
+            row.map(str::trim)
+                .filter(|cell| !cell.is_empty())
+                .collect::<Vec<_>>(),
+            [
+                "2aQJYqER2aKyb3cZw22v4SL2xMX7vwXBRWfvS4pTrtED",
+                "enable_loader_v4",
+                "active (slot 5)",
+                "pending"
+            ]
+        );
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["features"][0]["left"]["activatedAt"], 5);
+        assert_eq!(json["features"][0]["right"]["status"], "pending");
+    }
+}
@@ -0,0 +1,50 @@
+//! `export` command.
+
+use {
+    crate::{
+        error::Error,
+        output::{CommandOutput, Table},
+    },
+    serde::Serialize,
+    solana_clock::Slot,
+    solana_feature_gate_client::snapshot::ClusterSnapshot,
+    solana_rpc_client::rpc_client::RpcClient,
+    std::path::Path,
+};
+
+/// The output of the `export` command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportOutput {
+    /// The file the snapshot was written to.
+    pub path: String,
+    /// The slot at which the accounts were fetched.
+    pub slot: Option<Slot>,
+    /// The number of accounts exported.
+    pub accounts: usize,
+}
+
+impl CommandOutput for ExportOutput {
+    fn table(&self) -> Table {
+        Table::key_value([
+            ("Path", self.path.clone()),
+            (
+                "Slot",
+                self.slot.map(|slot| slot.to_string()).unwrap_or_default(),
+            ),
+            ("Accounts", self.accounts.to_string()),
+        ])
+    }
+}
+
+/// Exports every account owned by the Feature Gate program to a `.json` or
+/// `.csv` snapshot file.
+pub fn export(rpc_client: &RpcClient, path: &Path) -> Result<ExportOutput, Error> {
+    let snapshot = ClusterSnapshot::fetch(rpc_client)?;
+    snapshot.save(path)?;
+    Ok(ExportOutput {
+        path: path.display().to_string(),
+        slot: snapshot.slot,
+        accounts: snapshot.accounts.len(),
+    })
+}
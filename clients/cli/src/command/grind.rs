@@ -0,0 +1,115 @@
+//! `grind` command.
+
+use {
+    crate::{
+        error::Error,
+        output::{CommandOutput, Table},
+    },
+    serde::Serialize,
+    solana_feature_gate_client::{
+        grind::{GrindPattern, Grinder},
+        snapshot::ClusterSnapshot,
+    },
+    solana_keypair::write_keypair_file,
+    solana_signer::Signer,
+    std::{
+        num::NonZeroUsize,
+        path::{Path, PathBuf},
+    },
+};
+
+/// Options for the `grind` command.
+pub struct GrindArgs<'a> {
+    /// The required address prefix.
+    pub prefix: &'a str,
+    /// The required address suffix.
+    pub suffix: &'a str,
+    /// Whether to match case-insensitively.
+    pub ignore_case: bool,
+    /// The number of worker threads, or one per CPU.
+    pub threads: Option<NonZeroUsize>,
+    /// Snapshots whose addresses must not be produced.
+    pub exclude: &'a [PathBuf],
+    /// Where to write the keypair, defaulting to `<ADDRESS>.json`.
+    pub outfile: Option<&'a Path>,
+}
+
+/// The output of the `grind` command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GrindOutput {
+    /// The ground feature ID.
+    pub address: String,
+    /// The keypair file.
+    pub path: String,
+    /// The number of keypairs generated.
+    pub attempts: u64,
+}
+
+impl CommandOutput for GrindOutput {
+    fn table(&self) -> Table {
+        Table::key_value([
+            ("Feature", self.address.clone()),
+            ("Keypair", self.path.clone()),
+            ("Attempts", self.attempts.to_string()),
+        ])
+    }
+}
+
+/// Grinds a vanity feature keypair and writes it to a keypair file.
+pub fn grind(args: GrindArgs) -> Result<GrindOutput, Error> {
+    let pattern = GrindPattern::new(args.prefix, args.suffix, args.ignore_case)?;
+    let mut grinder = Grinder::new(pattern);
+    if let Some(threads) = args.threads {
+        grinder = grinder.threads(threads);
+    }
+    for path in args.exclude {
+        let snapshot = ClusterSnapshot::load(path)?;
+        grinder = grinder.exclude(snapshot.accounts.iter().map(|account| account.address));
+    }
+    if let Some(outfile) = args.outfile.filter(|outfile| outfile.exists()) {
+        return Err(Error::OutfileExists(outfile.to_path_buf()));
+    }
+
+    let result = grinder.grind()?;
+    let address = result.keypair.pubkey();
+    let path = args
+        .outfile
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from(format!("{address}.json")));
+    write_keypair_file(&result.keypair, &path).map_err(|error| Error::WriteKeypair {
+        path: path.clone(),
+        message: error.to_string(),
+    })?;
+    Ok(GrindOutput {
+        address: address.to_string(),
+        path: path.display().to_string(),
+        attempts: result.attempts,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_keypair::read_keypair_file};
+
+    #[test]
+    fn test_grind_writes_keypair() {
+        let dir = tempfile::tempdir().unwrap();
+        let outfile = dir.path().join("feature.json");
+        let args = || GrindArgs {
+            prefix: "",
+            suffix: "z",
+            ignore_case: true,
+            threads: NonZeroUsize::new(1),
+            exclude: &[],
+            outfile: Some(&outfile),
+        };
+
+        let output = grind(args()).unwrap();
+        let keypair = read_keypair_file(&outfile).unwrap();
+        assert_eq!(keypair.pubkey().to_string(), output.address);
+        assert!(output.address.to_ascii_lowercase().ends_with('z'));
+
+        assert!(matches!(grind(args()), Err(Error::OutfileExists(_))));
+    }
+}
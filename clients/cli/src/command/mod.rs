@@ -0,0 +1,21 @@
+//! Subcommand implementations.
+
+mod diff;
+mod export;
+mod grind;
+mod queue;
+mod readiness;
+mod revoke;
+mod status;
+mod watch;
+
+pub use {
+    diff::diff,
+    export::export,
+    grind::{grind, GrindArgs},
+    queue::queue,
+    readiness::readiness,
+    revoke::revoke,
+    status::{list, list_snapshot, resolve_feature, status},
+    watch::{watch, WatchTargets},
+};
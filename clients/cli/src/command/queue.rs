@@ -0,0 +1,52 @@
+//! `queue` command.
+
+use {
+    crate::{
+        error::Error,
+        transaction::{process_transaction, TransactionOutput},
+    },
+    solana_feature_gate_client::snapshot::{FeatureAccountSnapshot, FeatureStatus},
+    solana_feature_gate_interface::{activate_with_lamports, Feature},
+    solana_rpc_client::rpc_client::RpcClient,
+    solana_signer::Signer,
+};
+
+/// Queues a feature for activation at the next epoch boundary.
+///
+/// The feature account is funded by the fee payer with the rent-exempt
+/// minimum, then allocated and assigned to the Feature Gate program. The
+/// runtime only activates features that the cluster's validator software
+/// recognizes.
+pub fn queue(
+    rpc_client: &RpcClient,
+    fee_payer: &dyn Signer,
+    feature: &dyn Signer,
+    dry_run: bool,
+) -> Result<TransactionOutput, Error> {
+    let feature_id = feature.pubkey();
+    let existing = FeatureAccountSnapshot::fetch_many(rpc_client, &[feature_id])?
+        .into_iter()
+        .flatten()
+        .next();
+    match existing.as_ref().map(FeatureAccountSnapshot::status) {
+        Some(FeatureStatus::Scheduled { .. } | FeatureStatus::Pending) => {
+            return Err(Error::FeatureAlreadyQueued(feature_id))
+        }
+        Some(FeatureStatus::Active { .. }) => return Err(Error::FeatureAlreadyActive(feature_id)),
+        Some(FeatureStatus::Tombstone { .. }) => return Err(Error::InvalidFeature(feature_id)),
+        Some(FeatureStatus::Invalid) | None => {}
+    }
+
+    let lamports = rpc_client
+        .get_minimum_balance_for_rent_exemption(Feature::size_of())
+        .map_err(Box::new)?;
+    let instructions = activate_with_lamports(&feature_id, &fee_payer.pubkey(), lamports);
+    process_transaction(
+        rpc_client,
+        &feature_id,
+        &instructions,
+        fee_payer,
+        &[feature],
+        dry_run,
+    )
+}
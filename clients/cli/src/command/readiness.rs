@@ -0,0 +1,50 @@
+//! `readiness` command.
+
+use {
+    crate::{
+        error::Error,
+        output::{CommandOutput, Table},
+    },
+    serde::Serialize,
+    solana_address::Address,
+    solana_feature_gate_client::readiness::FeatureReadiness,
+    solana_rpc_client::rpc_client::RpcClient,
+};
+
+/// The output of the `readiness` command.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct ReadinessOutput(FeatureReadiness);
+
+impl CommandOutput for ReadinessOutput {
+    fn table(&self) -> Table {
+        let readiness = &self.0;
+        let mut table = Table::new(&["VOTE ACCOUNT", "VERSION", "STAKE", "SIGNALED AT"]);
+        for validator in &readiness.validators {
+            table.push(vec![
+                validator.vote_account.to_string(),
+                validator.version.clone(),
+                validator.stake.to_string(),
+                format!("slot {}", validator.signaled_at),
+            ]);
+        }
+        table.push(vec![
+            format!("Total in epoch {}", readiness.epoch),
+            String::new(),
+            format!(
+                "{} of {} ({:.2}%)",
+                readiness.supporting_stake,
+                readiness.total_stake,
+                readiness.supporting_stake_percent()
+            ),
+        ]);
+        table
+    }
+}
+
+/// Shows the stake signaling support for a pending feature.
+pub fn readiness(rpc_client: &RpcClient, feature_id: &Address) -> Result<ReadinessOutput, Error> {
+    Ok(ReadinessOutput(FeatureReadiness::fetch(
+        rpc_client, feature_id,
+    )?))
+}
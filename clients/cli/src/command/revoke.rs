@@ -0,0 +1,55 @@
+//! `revoke` command.
+
+use {
+    crate::{
+        error::Error,
+        transaction::{process_transaction, TransactionOutput},
+    },
+    solana_feature_gate_client::{
+        audit_log::find_audit_log_address,
+        instructions::RevokePendingActivationBuilder,
+        snapshot::{FeatureAccountSnapshot, FeatureStatus, SnapshotError},
+    },
+    solana_rpc_client::rpc_client::RpcClient,
+    solana_sdk_ids::incinerator,
+    solana_signer::Signer,
+};
+
+/// Revokes a pending or scheduled feature activation.
+///
+/// The feature account is checked before the transaction is built, so that
+/// features which were never queued or have already been activated are
+/// reported without spending a transaction fee.
+pub fn revoke(
+    rpc_client: &RpcClient,
+    fee_payer: &dyn Signer,
+    feature: &dyn Signer,
+    dry_run: bool,
+) -> Result<TransactionOutput, Error> {
+    let feature_id = feature.pubkey();
+    let snapshot = match FeatureAccountSnapshot::fetch(rpc_client, &feature_id) {
+        Err(SnapshotError::AccountNotFound(_)) => return Err(Error::FeatureNotFound(feature_id)),
+        result => result?,
+    };
+    match snapshot.status() {
+        FeatureStatus::Scheduled { .. } | FeatureStatus::Pending => {}
+        FeatureStatus::Active { .. } => return Err(Error::FeatureAlreadyActive(feature_id)),
+        FeatureStatus::Tombstone { .. } | FeatureStatus::Invalid => {
+            return Err(Error::InvalidFeature(feature_id))
+        }
+    }
+
+    let instruction = RevokePendingActivationBuilder::new()
+        .feature(feature_id)
+        .incinerator(incinerator::id())
+        .audit_log(Some(find_audit_log_address().0))
+        .instruction();
+    process_transaction(
+        rpc_client,
+        &feature_id,
+        &[instruction],
+        fee_payer,
+        &[feature],
+        dry_run,
+    )
+}
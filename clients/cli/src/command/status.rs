@@ -0,0 +1,281 @@
+//! `status` and `list` commands.
+
+use {
+    crate::{
+        error::Error,
+        output::{format_duration, CommandOutput, Table},
+    },
+    serde::Serialize,
+    solana_address::Address,
+    solana_clock::{Epoch, Slot, UnixTimestamp},
+    solana_epoch_schedule::EpochSchedule,
+    solana_feature_gate_client::{
+        activation::{
+            predict_activation, ActivationEstimateConfig, ActivationPrediction, PendingActivation,
+        },
+        known_features,
+        snapshot::{ClusterSnapshot, FeatureAccountSnapshot, FeatureStatus},
+    },
+    solana_feature_gate_interface::Feature,
+    solana_rpc_client::rpc_client::RpcClient,
+    std::{
+        path::Path,
+        time::{SystemTime, UNIX_EPOCH},
+    },
+};
+
+/// The cluster state used to predict activations.
+pub struct ClusterClock {
+    epoch_schedule: EpochSchedule,
+    slot: Slot,
+    now: UnixTimestamp,
+}
+
+impl ClusterClock {
+    /// Fetches the cluster's epoch schedule and current slot.
+    pub fn fetch(rpc_client: &RpcClient) -> Result<Self, Error> {
+        let epoch_schedule = rpc_client.get_epoch_schedule().map_err(Box::new)?;
+        let slot = rpc_client.get_slot().map_err(Box::new)?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| UnixTimestamp::try_from(elapsed.as_secs()).unwrap_or(UnixTimestamp::MAX))
+            .unwrap_or_default();
+        Ok(Self {
+            epoch_schedule,
+            slot,
+            now,
+        })
+    }
+
+    fn predict_pending(&self) -> Option<PendingActivation> {
+        match predict_activation(
+            &Feature::default(),
+            &self.epoch_schedule,
+            self.slot,
+            self.now,
+            &ActivationEstimateConfig::default(),
+        ) {
+            ActivationPrediction::Pending(pending) => Some(pending),
+            ActivationPrediction::Active { .. } => None,
+        }
+    }
+}
+
+/// The state of a single feature.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureEntry {
+    /// The feature ID.
+    pub id: String,
+    /// The feature's known name, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// One of `inactive`, `scheduled`, `pending`, `active`, `tombstone` or
+    /// `invalid`.
+    pub status: &'static str,
+    /// The earliest epoch in which a scheduled feature may activate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub not_before_epoch: Option<Epoch>,
+    /// The slot at which the feature was activated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activated_at: Option<Slot>,
+    /// The slot at which the feature was revoked, leaving a tombstone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revoked_at: Option<Slot>,
+    /// The predicted activation of a pending feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activation: Option<PendingActivation>,
+}
+
+impl FeatureEntry {
+    fn new(id: &Address, status: Option<FeatureStatus>, clock: Option<&ClusterClock>) -> Self {
+        Self {
+            id: id.to_string(),
+            name: known_features::find_by_id(id).map(|feature| feature.name.to_string()),
+            status: status.as_ref().map_or("inactive", FeatureStatus::label),
+            not_before_epoch: match status {
+                Some(FeatureStatus::Scheduled { not_before_epoch }) => Some(not_before_epoch),
+                _ => None,
+            },
+            activated_at: match status {
+                Some(FeatureStatus::Active { activated_at }) => Some(activated_at),
+                _ => None,
+            },
+            revoked_at: match status {
+                Some(FeatureStatus::Tombstone { revoked_at }) => Some(revoked_at),
+                _ => None,
+            },
+            activation: match status {
+                Some(FeatureStatus::Pending) => clock.and_then(ClusterClock::predict_pending),
+                _ => None,
+            },
+        }
+    }
+
+    fn activation_summary(&self) -> String {
+        if let Some(activated_at) = self.activated_at {
+            return format!("slot {activated_at}");
+        }
+        if let Some(not_before_epoch) = self.not_before_epoch {
+            return format!("not before epoch {not_before_epoch}");
+        }
+        if let Some(revoked_at) = self.revoked_at {
+            return format!("revoked at slot {revoked_at}");
+        }
+        self.activation
+            .as_ref()
+            .map(|pending| {
+                format!(
+                    "epoch {} (slot {}, in ~{})",
+                    pending.activation_epoch,
+                    pending.activation_slot,
+                    format_duration(pending.time_until_activation)
+                )
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// The output of the `status` and `list` commands.
+#[derive(Debug, Serialize)]
+pub struct FeatureList {
+    /// The features, in display order.
+    pub features: Vec<FeatureEntry>,
+}
+
+impl CommandOutput for FeatureList {
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["FEATURE", "NAME", "STATUS", "ACTIVATION"]);
+        for feature in &self.features {
+            table.push(vec![
+                feature.id.clone(),
+                feature.name.clone().unwrap_or_default(),
+                feature.status.to_string(),
+                feature.activation_summary(),
+            ]);
+        }
+        table
+    }
+}
+
+/// Resolves a feature given as an ID or a known feature name.
+pub fn resolve_feature(feature: &str) -> Result<Address, Error> {
+    feature
+        .parse()
+        .ok()
+        .or_else(|| known_features::find_by_name(feature).map(|known| known.id))
+        .ok_or_else(|| Error::UnknownFeature(feature.to_string()))
+}
+
+/// Shows the status of the given features.
+pub fn status(rpc_client: &RpcClient, features: &[Address]) -> Result<FeatureList, Error> {
+    let clock = ClusterClock::fetch(rpc_client)?;
+    let snapshots = FeatureAccountSnapshot::fetch_many(rpc_client, features)?;
+    Ok(FeatureList {
+        features: features
+            .iter()
+            .zip(snapshots)
+            .map(|(id, snapshot)| {
+                FeatureEntry::new(
+                    id,
+                    snapshot.as_ref().map(FeatureAccountSnapshot::status),
+                    Some(&clock),
+                )
+            })
+            .collect(),
+    })
+}
+
+/// Lists every account owned by the Feature Gate program: pending features
+/// first, then active features from most to least recently activated.
+pub fn list(rpc_client: &RpcClient) -> Result<FeatureList, Error> {
+    let clock = ClusterClock::fetch(rpc_client)?;
+    let snapshots = FeatureAccountSnapshot::fetch_all(rpc_client)?;
+    Ok(feature_list(&snapshots, Some(&clock)))
+}
+
+/// Lists every account in an exported snapshot file.
+///
+/// Activation times are not predicted, since the snapshot may be stale.
+pub fn list_snapshot(path: &Path) -> Result<FeatureList, Error> {
+    let snapshot = ClusterSnapshot::load(path)?;
+    Ok(feature_list(&snapshot.accounts, None))
+}
+
+fn feature_list(snapshots: &[FeatureAccountSnapshot], clock: Option<&ClusterClock>) -> FeatureList {
+    let mut statuses = snapshots
+        .iter()
+        .map(|snapshot| (snapshot.address, snapshot.status()))
+        .collect::<Vec<_>>();
+    statuses.sort_by_key(|(address, status)| {
+        let (rank, activated_at) = match status {
+            FeatureStatus::Pending => (0, 0),
+            FeatureStatus::Scheduled { not_before_epoch } => (1, *not_before_epoch),
+            FeatureStatus::Active { activated_at } => (2, u64::MAX.saturating_sub(*activated_at)),
+            FeatureStatus::Tombstone { revoked_at } => (3, u64::MAX.saturating_sub(*revoked_at)),
+            FeatureStatus::Invalid => (4, 0),
+        };
+        (rank, activated_at, *address)
+    });
+    FeatureList {
+        features: statuses
+            .iter()
+            .map(|(address, status)| FeatureEntry::new(address, Some(*status), clock))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_address::address};
+
+    #[test]
+    fn test_resolve_feature() {
+        let loader_v4 = address!("2aQJYqER2aKyb3cZw22v4SL2xMX7vwXBRWfvS4pTrtED");
+        assert_eq!(resolve_feature("enable_loader_v4").unwrap(), loader_v4);
+        assert_eq!(resolve_feature(&loader_v4.to_string()).unwrap(), loader_v4);
+        assert!(matches!(
+            resolve_feature("not_a_feature"),
+            Err(Error::UnknownFeature(_))
+        ));
+    }
+
+    #[test]
+    fn test_feature_entries() {
+        let clock = ClusterClock {
+            epoch_schedule: EpochSchedule::without_warmup(),
+            slot: 100,
+            now: 0,
+        };
+        let id = Address::new_unique();
+
+        let pending = FeatureEntry::new(&id, Some(FeatureStatus::Pending), Some(&clock));
+        assert_eq!(pending.status, "pending");
+        assert_eq!(pending.activation.as_ref().unwrap().activation_epoch, 1);
+        assert!(pending
+            .activation_summary()
+            .starts_with("epoch 1 (slot 432000"));
+
+        let active = FeatureEntry::new(
+            &id,
+            Some(FeatureStatus::Active { activated_at: 7 }),
+            Some(&clock),
+        );
+        assert_eq!(active.status, "active");
+        assert_eq!(active.activation_summary(), "slot 7");
+
+        let scheduled = FeatureEntry::new(
+            &id,
+            Some(FeatureStatus::Scheduled {
+                not_before_epoch: 5,
+            }),
+            Some(&clock),
+        );
+        assert_eq!(scheduled.status, "scheduled");
+        assert_eq!(scheduled.activation_summary(), "not before epoch 5");
+
+        let inactive = FeatureEntry::new(&id, None, Some(&clock));
+        assert_eq!(inactive.status, "inactive");
+        assert_eq!(inactive.activation_summary(), "");
+    }
+}
@@ -0,0 +1,107 @@
+//! `watch` command.
+
+use {
+    crate::error::Error,
+    solana_feature_gate_client::watch::{
+        EventSink, JsonLinesSink, WatchError, WatchEvent, Watcher, WebhookSink,
+    },
+    solana_rpc_client::rpc_client::RpcClient,
+    std::{path::PathBuf, time::Duration},
+};
+
+/// Where the `watch` command delivers events.
+#[derive(Debug, Default)]
+pub struct WatchTargets {
+    /// Files to append JSON lines to.
+    pub files: Vec<PathBuf>,
+    /// Webhook URLs to POST each event to.
+    pub webhooks: Vec<String>,
+}
+
+/// Delivers each event to every configured sink.
+struct Sinks(Vec<Box<dyn EventSink>>);
+
+impl Sinks {
+    /// Opens the sinks for `targets`, writing to stdout if there are none.
+    fn open(targets: WatchTargets) -> Result<Self, Error> {
+        let mut sinks = Vec::<Box<dyn EventSink>>::new();
+        for path in targets.files {
+            sinks.push(Box::new(
+                JsonLinesSink::append(&path).map_err(WatchError::from)?,
+            ));
+        }
+        for url in targets.webhooks {
+            sinks.push(Box::new(WebhookSink::new(url)));
+        }
+        if sinks.is_empty() {
+            sinks.push(Box::new(JsonLinesSink::stdout()));
+        }
+        Ok(Self(sinks))
+    }
+}
+
+impl EventSink for Sinks {
+    /// Delivers the event to every sink, even if an earlier one fails, and
+    /// returns the last failure.
+    fn send(&mut self, event: &WatchEvent) -> Result<(), WatchError> {
+        let mut result = Ok(());
+        for sink in &mut self.0 {
+            if let Err(error) = sink.send(event) {
+                result = Err(error);
+            }
+        }
+        result
+    }
+}
+
+/// Polls the cluster's feature accounts every `interval` and streams each
+/// queued, revoked or activated feature as a JSON event.
+///
+/// RPC and delivery errors are reported on stderr without stopping the
+/// watch.
+pub fn watch(
+    rpc_client: RpcClient,
+    targets: WatchTargets,
+    interval: Duration,
+) -> Result<(), Error> {
+    let mut sinks = Sinks::open(targets)?;
+    Watcher::new(rpc_client).run(
+        &mut sinks,
+        interval,
+        || true,
+        |error| eprintln!("Warning: {error}"),
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_address::Address, solana_feature_gate_client::watch::FeatureTransition};
+
+    #[test]
+    fn test_sinks() {
+        let dir = tempfile::tempdir().unwrap();
+        let files = vec![dir.path().join("a.jsonl"), dir.path().join("b.jsonl")];
+        let mut sinks = Sinks::open(WatchTargets {
+            files: files.clone(),
+            webhooks: Vec::new(),
+        })
+        .unwrap();
+
+        let event = WatchEvent {
+            slot: Some(1),
+            id: Address::new_unique(),
+            name: None,
+            transition: FeatureTransition::Queued,
+        };
+        sinks.send(&event).unwrap();
+        sinks.send(&event).unwrap();
+
+        for file in files {
+            let contents = std::fs::read_to_string(file).unwrap();
+            assert_eq!(contents.lines().count(), 2);
+            let first = contents.lines().next().unwrap();
+            assert_eq!(serde_json::from_str::<WatchEvent>(first).unwrap(), event);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! The Solana CLI config file.
+//!
+//! Like the Solana CLI, this tool defaults to the cluster and fee payer set
+//! with `solana config set`.
+
+use {serde::Deserialize, std::path::PathBuf};
+
+/// The settings shared with the Solana CLI.
+#[derive(Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct SolanaCliConfig {
+    /// The RPC URL of the cluster.
+    #[serde(default)]
+    pub json_rpc_url: Option<String>,
+    /// The default signer source.
+    #[serde(default)]
+    pub keypair_path: Option<String>,
+}
+
+impl SolanaCliConfig {
+    /// Loads `~/.config/solana/cli/config.yml`.
+    ///
+    /// As in the Solana CLI, a missing or unreadable file yields an empty
+    /// config.
+    pub fn load() -> Self {
+        let home = std::env::var_os("HOME").unwrap_or_default();
+        std::fs::read_to_string(PathBuf::from(home).join(".config/solana/cli/config.yml"))
+            .map(|contents| Self::parse(&contents))
+            .unwrap_or_default()
+    }
+
+    fn parse(contents: &str) -> Self {
+        serde_yaml::from_str(contents).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = SolanaCliConfig::parse(
+            "---\njson_rpc_url: \"https://api.devnet.solana.com\"\nwebsocket_url: \"\"\n\
+             keypair_path: /keys/id.json\ncommitment: confirmed\n",
+        );
+        assert_eq!(
+            config,
+            SolanaCliConfig {
+                json_rpc_url: Some("https://api.devnet.solana.com".to_string()),
+                keypair_path: Some("/keys/id.json".to_string()),
+            }
+        );
+        assert_eq!(
+            SolanaCliConfig::parse("not: [valid"),
+            SolanaCliConfig::default()
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Command errors.
+
+use {
+    crate::signer::SignerError,
+    solana_address::Address,
+    solana_feature_gate_client::{
+        grind::GrindError, readiness::ReadinessError, snapshot::SnapshotError,
+        transaction_error::DecodedTransactionError, watch::WatchError,
+    },
+    solana_rpc_client::api::client_error::Error as ClientError,
+    std::path::PathBuf,
+    thiserror::Error,
+};
+
+/// Errors that can occur while running a command.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// A signer could not be loaded.
+    #[error(transparent)]
+    Signer(#[from] SignerError),
+    /// A feature account could not be fetched.
+    #[error(transparent)]
+    Snapshot(#[from] SnapshotError),
+    /// Validator readiness could not be fetched.
+    #[error(transparent)]
+    Readiness(#[from] ReadinessError),
+    /// An RPC request failed.
+    #[error("RPC error: {0}")]
+    Rpc(#[from] Box<ClientError>),
+    /// The transaction failed in the Feature Gate program.
+    #[error("{error}\nHint: {hint}", hint = error.hint())]
+    Program {
+        /// The decoded program error.
+        error: DecodedTransactionError,
+    },
+    /// The argument is neither a feature ID nor a known feature name.
+    #[error("Unknown feature `{0}`: expected a feature ID or a known feature name")]
+    UnknownFeature(String),
+    /// No cluster was given for a command that sends transactions.
+    #[error("No cluster given: pass --url or set one with `solana config set --url`")]
+    MissingUrl,
+    /// The feature account does not exist.
+    #[error("Feature {0} has not been queued for activation")]
+    FeatureNotFound(Address),
+    /// The feature account exists but is not owned by the Feature Gate
+    /// program.
+    #[error("Account {0} is not a valid feature account")]
+    InvalidFeature(Address),
+    /// The feature has already been activated.
+    #[error("Feature {0} has already been activated")]
+    FeatureAlreadyActive(Address),
+    /// The feature has already been queued for activation.
+    #[error("Feature {0} has already been queued for activation")]
+    FeatureAlreadyQueued(Address),
+    /// The vanity keypair search failed.
+    #[error(transparent)]
+    Grind(#[from] GrindError),
+    /// The keypair output file already exists.
+    #[error("{} already exists", .0.display())]
+    OutfileExists(PathBuf),
+    /// The keypair could not be written.
+    #[error("Failed to write keypair to {}: {message}", path.display())]
+    WriteKeypair {
+        /// The keypair file.
+        path: PathBuf,
+        /// The underlying error.
+        message: String,
+    },
+    /// Watching the feature accounts failed.
+    #[error(transparent)]
+    Watch(#[from] WatchError),
+    /// The output could not be serialized.
+    #[error("Failed to serialize output: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The transaction could not be serialized.
+    #[error("Failed to serialize transaction: {0}")]
+    Bincode(#[from] bincode::Error),
+}
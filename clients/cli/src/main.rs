@@ -0,0 +1,352 @@
+//! `feature-gate`: a command-line tool for the Feature Gate program.
+
+mod command;
+mod config;
+mod error;
+mod output;
+mod signer;
+mod transaction;
+
+use {
+    crate::{
+        config::SolanaCliConfig,
+        error::Error,
+        output::{print, OutputFormat},
+        signer::SignerSource,
+    },
+    clap::{Args, Parser, Subcommand},
+    solana_commitment_config::CommitmentConfig,
+    solana_feature_gate_client::cluster::normalize_url,
+    solana_rpc_client::rpc_client::RpcClient,
+    std::{num::NonZeroUsize, path::PathBuf, process::ExitCode, time::Duration},
+};
+
+#[derive(Debug, Parser)]
+#[command(name = "feature-gate", version, about)]
+struct Cli {
+    /// RPC URL or moniker of the cluster: `mainnet-beta`, `testnet`,
+    /// `devnet` or `localhost` (or their first letter). Defaults to the
+    /// Solana CLI config's cluster. Commands that only read fall back to
+    /// `mainnet-beta`; `revoke` and `queue` require a cluster.
+    #[arg(long, short = 'u', global = true, env = "SOLANA_RPC_URL")]
+    url: Option<String>,
+
+    /// Fee payer: a keypair file path, `file:<PATH>`, `stdin:`, `prompt:` or
+    /// `usb://<WALLET>`. Defaults to the Solana CLI config's keypair, then
+    /// `~/.config/solana/id.json`.
+    #[arg(long, short = 'k', global = true, value_name = "KEYPAIR")]
+    keypair: Option<SignerSource>,
+
+    /// Output format.
+    #[arg(long, short = 'o', global = true, value_enum, default_value_t)]
+    output: OutputFormat,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Show the status of one or more features.
+    Status {
+        /// Feature IDs or known feature names.
+        #[arg(required = true, value_name = "FEATURE")]
+        features: Vec<String>,
+    },
+    /// List every feature account owned by the Feature Gate program.
+    List {
+        /// List the accounts in an exported `.json` or `.csv` snapshot file
+        /// instead of fetching them.
+        #[arg(long, value_name = "PATH")]
+        snapshot: Option<PathBuf>,
+    },
+    /// Export every feature account to a `.json` or `.csv` snapshot file.
+    Export {
+        /// The file to write.
+        path: PathBuf,
+    },
+    /// Grind a vanity feature keypair.
+    Grind {
+        /// The required address prefix.
+        #[arg(long, default_value = "")]
+        prefix: String,
+        /// The required address suffix.
+        #[arg(long, default_value = "")]
+        suffix: String,
+        /// Match the prefix and suffix case-insensitively.
+        #[arg(long)]
+        ignore_case: bool,
+        /// The number of worker threads. Defaults to one per CPU.
+        #[arg(long)]
+        threads: Option<NonZeroUsize>,
+        /// An exported snapshot file; addresses that already exist in it are
+        /// never produced. May be repeated.
+        #[arg(long = "exclude-snapshot", value_name = "PATH")]
+        exclude: Vec<PathBuf>,
+        /// Where to write the keypair. Defaults to `<ADDRESS>.json`.
+        #[arg(long, value_name = "PATH")]
+        outfile: Option<PathBuf>,
+    },
+    /// Compare the features of two clusters or snapshots.
+    ///
+    /// Each side is either an exported `.json` or `.csv` snapshot file or a
+    /// cluster RPC URL or moniker.
+    Diff {
+        /// The left-hand cluster or snapshot file, e.g. `testnet`.
+        left: String,
+        /// The right-hand cluster or snapshot file, e.g. `mainnet-beta`.
+        right: String,
+    },
+    /// Stream feature queue, revoke and activation events as JSON lines.
+    ///
+    /// Events are written to stdout unless a file or webhook is given.
+    Watch {
+        /// Seconds between polls of the feature accounts.
+        #[arg(long, default_value_t = 10)]
+        interval: u64,
+        /// Append events to a file. May be repeated.
+        #[arg(long = "file", value_name = "PATH")]
+        files: Vec<PathBuf>,
+        /// POST each event to a webhook URL. May be repeated.
+        #[arg(long = "webhook", value_name = "URL")]
+        webhooks: Vec<String>,
+    },
+    /// Show the stake of validators signaling support for a pending feature.
+    Readiness {
+        /// The feature ID or known feature name.
+        #[arg(value_name = "FEATURE")]
+        feature: String,
+    },
+    /// Revoke a pending or scheduled feature activation.
+    Revoke(FeatureTransactionArgs),
+    /// Queue a feature for activation at the next epoch boundary.
+    Queue(FeatureTransactionArgs),
+}
+
+impl Command {
+    /// Whether the command sends transactions, which must not go to a
+    /// cluster the user did not choose.
+    fn sends_transactions(&self) -> bool {
+        matches!(self, Self::Revoke(_) | Self::Queue(_))
+    }
+}
+
+#[derive(Debug, Args)]
+struct FeatureTransactionArgs {
+    /// The feature signer: a keypair file path, `file:<PATH>`, `stdin:`,
+    /// `prompt:` or `usb://<WALLET>`.
+    #[arg(value_name = "FEATURE_KEYPAIR")]
+    feature: SignerSource,
+
+    /// Sign and print the transaction without sending it.
+    #[arg(long)]
+    dry_run: bool,
+}
+
+/// The cluster to connect to: `--url`, then the Solana CLI config, then
+/// `mainnet-beta` for commands that do not send transactions.
+fn cluster_url(
+    url: Option<String>,
+    config: &SolanaCliConfig,
+    command: &Command,
+) -> Result<String, Error> {
+    match url.or_else(|| config.json_rpc_url.clone()) {
+        Some(url) => Ok(normalize_url(&url).to_string()),
+        None if command.sends_transactions() => Err(Error::MissingUrl),
+        None => Ok(normalize_url("mainnet-beta").to_string()),
+    }
+}
+
+/// The fee payer: `--keypair`, then the Solana CLI config, then
+/// `~/.config/solana/id.json`.
+fn fee_payer(
+    source: Option<SignerSource>,
+    config: &SolanaCliConfig,
+) -> Result<SignerSource, Error> {
+    match (source, &config.keypair_path) {
+        (Some(source), _) => Ok(source),
+        (None, Some(path)) => Ok(path.parse()?),
+        (None, None) => Ok(SignerSource::default_keypair()),
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Error> {
+    let config = SolanaCliConfig::load();
+    let rpc_client = RpcClient::new_with_commitment(
+        cluster_url(cli.url, &config, &cli.command)?,
+        CommitmentConfig::confirmed(),
+    );
+    let mut wallet_manager = None;
+
+    match cli.command {
+        Command::Status { features } => {
+            let features = features
+                .iter()
+                .map(|feature| command::resolve_feature(feature))
+                .collect::<Result<Vec<_>, _>>()?;
+            print(&command::status(&rpc_client, &features)?, cli.output)?;
+        }
+        Command::List { snapshot } => {
+            let output = match snapshot {
+                Some(path) => command::list_snapshot(&path)?,
+                None => command::list(&rpc_client)?,
+            };
+            print(&output, cli.output)?;
+        }
+        Command::Export { path } => print(&command::export(&rpc_client, &path)?, cli.output)?,
+        Command::Grind {
+            prefix,
+            suffix,
+            ignore_case,
+            threads,
+            exclude,
+            outfile,
+        } => {
+            let output = command::grind(command::GrindArgs {
+                prefix: &prefix,
+                suffix: &suffix,
+                ignore_case,
+                threads,
+                exclude: &exclude,
+                outfile: outfile.as_deref(),
+            })?;
+            print(&output, cli.output)?;
+        }
+        Command::Diff { left, right } => print(&command::diff(&left, &right)?, cli.output)?,
+        Command::Watch {
+            interval,
+            files,
+            webhooks,
+        } => command::watch(
+            rpc_client,
+            command::WatchTargets { files, webhooks },
+            Duration::from_secs(interval),
+        )?,
+        Command::Readiness { feature } => {
+            let feature = command::resolve_feature(&feature)?;
+            print(&command::readiness(&rpc_client, &feature)?, cli.output)?;
+        }
+        Command::Revoke(args) => {
+            let fee_payer =
+                fee_payer(cli.keypair, &config)?.signer("fee payer", &mut wallet_manager)?;
+            let feature = args.feature.signer("feature", &mut wallet_manager)?;
+            let output = command::revoke(&rpc_client, &*fee_payer, &*feature, args.dry_run)?;
+            print(&output, cli.output)?;
+        }
+        Command::Queue(args) => {
+            let fee_payer =
+                fee_payer(cli.keypair, &config)?.signer("fee payer", &mut wallet_manager)?;
+            let feature = args.feature.signer("feature", &mut wallet_manager)?;
+            let output = command::queue(&rpc_client, &*fee_payer, &*feature, args.dry_run)?;
+            print(&output, cli.output)?;
+        }
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("Error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, clap::CommandFactory};
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+
+        let cli = Cli::try_parse_from([
+            "feature-gate",
+            "revoke",
+            "feature.json",
+            "--dry-run",
+            "-k",
+            "file:payer.json",
+            "--output",
+            "json",
+        ])
+        .unwrap();
+        assert_eq!(cli.output, OutputFormat::Json);
+        assert_eq!(cli.keypair, Some(SignerSource::File("payer.json".into())));
+        let Command::Revoke(args) = cli.command else {
+            panic!("expected the revoke command");
+        };
+        assert_eq!(args.feature, SignerSource::File("feature.json".into()));
+        assert!(args.dry_run);
+
+        let cli = Cli::try_parse_from([
+            "feature-gate",
+            "queue",
+            "usb://ledger?key=0",
+            "-k",
+            "prompt:",
+        ])
+        .unwrap();
+        assert!(matches!(cli.keypair, Some(SignerSource::Prompt { .. })));
+        let Command::Queue(args) = cli.command else {
+            panic!("expected the queue command");
+        };
+        assert!(matches!(args.feature, SignerSource::Usb { .. }));
+
+        assert!(Cli::try_parse_from(["feature-gate", "revoke", "https://example.com"]).is_err());
+        assert!(Cli::try_parse_from(["feature-gate", "status"]).is_err());
+    }
+
+    #[test]
+    fn test_cluster_url() {
+        let revoke = Cli::try_parse_from(["feature-gate", "revoke", "feature.json"])
+            .unwrap()
+            .command;
+        let list = Cli::try_parse_from(["feature-gate", "list"])
+            .unwrap()
+            .command;
+        let config = SolanaCliConfig {
+            json_rpc_url: Some("https://api.devnet.solana.com".to_string()),
+            keypair_path: None,
+        };
+
+        assert_eq!(
+            cluster_url(Some("t".to_string()), &config, &revoke).unwrap(),
+            "https://api.testnet.solana.com"
+        );
+        assert_eq!(
+            cluster_url(None, &config, &revoke).unwrap(),
+            "https://api.devnet.solana.com"
+        );
+        assert!(matches!(
+            cluster_url(None, &SolanaCliConfig::default(), &revoke),
+            Err(Error::MissingUrl)
+        ));
+        assert_eq!(
+            cluster_url(None, &SolanaCliConfig::default(), &list).unwrap(),
+            "https://api.mainnet-beta.solana.com"
+        );
+    }
+
+    #[test]
+    fn test_fee_payer() {
+        let config = SolanaCliConfig {
+            json_rpc_url: None,
+            keypair_path: Some("/keys/payer.json".to_string()),
+        };
+        assert_eq!(
+            fee_payer(Some(SignerSource::Stdin), &config).unwrap(),
+            SignerSource::Stdin
+        );
+        assert_eq!(
+            fee_payer(None, &config).unwrap(),
+            SignerSource::File("/keys/payer.json".into())
+        );
+        assert_eq!(
+            fee_payer(None, &SolanaCliConfig::default()).unwrap(),
+            SignerSource::default_keypair()
+        );
+    }
+}
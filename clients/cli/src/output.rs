@@ -0,0 +1,136 @@
+//! Output formatting.
+
+use {
+    clap::ValueEnum,
+    serde::Serialize,
+    std::{fmt, time::Duration},
+};
+
+/// How command results are printed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable, column-aligned tables.
+    #[default]
+    Table,
+    /// Pretty-printed JSON.
+    Json,
+}
+
+/// The result of a command, printable as JSON or as a table.
+pub trait CommandOutput: Serialize {
+    /// Renders the output as a table.
+    fn table(&self) -> Table;
+}
+
+/// Prints a command's output in the requested format.
+pub fn print(output: &impl CommandOutput, format: OutputFormat) -> serde_json::Result<()> {
+    match format {
+        OutputFormat::Table => print!("{}", output.table()),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(output)?),
+    }
+    Ok(())
+}
+
+/// A column-aligned table.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Creates a table with the given column headers.
+    pub fn new(headers: &[&str]) -> Self {
+        Self {
+            headers: headers.iter().map(ToString::to_string).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Creates a two-column table of labelled values, without headers.
+    pub fn key_value<'a>(entries: impl IntoIterator<Item = (&'a str, String)>) -> Self {
+        Self {
+            headers: Vec::new(),
+            rows: entries
+                .into_iter()
+                .map(|(key, value)| vec![format!("{key}:"), value])
+                .collect(),
+        }
+    }
+
+    /// Appends a row.
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn column_widths(&self) -> Vec<usize> {
+        let mut widths = vec![0; self.headers.len()];
+        for row in std::iter::once(&self.headers).chain(&self.rows) {
+            if widths.len() < row.len() {
+                widths.resize(row.len(), 0);
+            }
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        widths
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.column_widths();
+        let headers = (!self.headers.is_empty()).then_some(&self.headers);
+        for row in headers.into_iter().chain(&self.rows) {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// Formats a duration as a coarse, human-readable estimate, e.g. `1d 2h 5m`.
+pub fn format_duration(duration: Duration) -> String {
+    let minutes = duration.as_secs().saturating_div(60);
+    let (days, hours, minutes) = (
+        minutes.saturating_div(1_440),
+        minutes.saturating_div(60).checked_rem(24).unwrap_or(0),
+        minutes.checked_rem(60).unwrap_or(0),
+    );
+    match (days, hours) {
+        (0, 0) => format!("{minutes}m"),
+        (0, _) => format!("{hours}h {minutes}m"),
+        _ => format!("{days}d {hours}h {minutes}m"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_table_alignment() {
+        let mut table = Table::new(&["ID", "STATUS", "NOTE"]);
+        table.push(vec!["a".into(), "pending".into(), String::new()]);
+        table.push(vec!["abcdef".into(), "active".into(), "x".into()]);
+        assert_eq!(
+            table.to_string(),
+            "ID      STATUS   NOTE\na       pending\nabcdef  active   x\n"
+        );
+
+        let table = Table::key_value([("Signature", "abc".to_string()), ("Fee", "5".to_string())]);
+        assert_eq!(table.to_string(), "Signature:  abc\nFee:        5\n");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(59)), "0m");
+        assert_eq!(format_duration(Duration::from_secs(3_720)), "1h 2m");
+        assert_eq!(format_duration(Duration::from_secs(183_840)), "2d 3h 4m");
+    }
+}
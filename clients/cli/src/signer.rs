@@ -0,0 +1,320 @@
+//! Signer source parsing.
+//!
+//! Signers are given on the command line as a signer source, as in the
+//! Solana CLI:
+//!
+//! - a path to a keypair JSON file, optionally written as a `file:` or `file://`
+//!   URL;
+//! - `stdin:` (or `-`) to read keypair JSON from standard input;
+//! - `prompt:` to derive the keypair from a seed phrase entered at a prompt,
+//!   with an optional derivation path such as `prompt://?key=0/0`;
+//! - `usb://<MANUFACTURER>[/<WALLET_ID>][?key=<ACCOUNT>[/<CHANGE>]]` to sign
+//!   with a hardware wallet, such as `usb://ledger?key=0`. Hardware wallets
+//!   are only available when the tool is built with the `usb` feature.
+
+use {
+    solana_derivation_path::DerivationPath,
+    solana_keypair::{
+        keypair_from_seed_phrase_and_passphrase, read_keypair, read_keypair_file,
+        seed_derivable::keypair_from_seed_and_derivation_path, Keypair,
+    },
+    solana_remote_wallet::{
+        locator::Locator,
+        remote_keypair::generate_remote_keypair,
+        remote_wallet::{maybe_wallet_manager, RemoteWalletManager},
+    },
+    solana_seed_phrase::generate_seed_from_seed_phrase_and_passphrase,
+    solana_signer::Signer,
+    std::{fmt, path::PathBuf, rc::Rc, str::FromStr},
+    thiserror::Error,
+    uriparse::URIReference,
+};
+
+/// Errors that can occur while parsing or loading a signer.
+#[derive(Debug, Error, PartialEq)]
+pub enum SignerError {
+    /// The signer source is empty.
+    #[error("Signer source is empty")]
+    Empty,
+    /// The signer source uses a URL scheme this tool does not support.
+    #[error(
+        "Unsupported signer source `{0}:`; use a keypair file, `stdin:`, `prompt:` or `usb://`"
+    )]
+    UnsupportedScheme(String),
+    /// The signer source URL is malformed.
+    #[error("Invalid signer source `{signer}`: {message}")]
+    InvalidUri {
+        /// The signer source.
+        signer: String,
+        /// Why it is invalid.
+        message: String,
+    },
+    /// No hardware wallet is connected.
+    #[error("No hardware wallet found for {0}")]
+    NoDeviceFound(String),
+    /// The keypair could not be read.
+    #[error("Failed to read keypair from {signer}: {message}")]
+    Read {
+        /// The signer source that failed.
+        signer: String,
+        /// The underlying error.
+        message: String,
+    },
+}
+
+/// Where to load a signer from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignerSource {
+    /// A keypair JSON file.
+    File(PathBuf),
+    /// Keypair JSON read from standard input.
+    Stdin,
+    /// A keypair derived from a seed phrase entered at a prompt.
+    Prompt {
+        /// The derivation path, if any. Without one, the keypair is derived
+        /// directly from the seed, as by `solana-keygen recover`.
+        derivation_path: Option<DerivationPath>,
+    },
+    /// A hardware wallet.
+    Usb {
+        /// The wallet to sign with.
+        locator: Locator,
+        /// The derivation path of the signing key.
+        derivation_path: Option<DerivationPath>,
+    },
+}
+
+impl SignerSource {
+    /// The default fee payer keypair used by the Solana CLI,
+    /// `~/.config/solana/id.json`.
+    pub fn default_keypair() -> Self {
+        let home = std::env::var_os("HOME").unwrap_or_default();
+        Self::File(PathBuf::from(home).join(".config/solana/id.json"))
+    }
+
+    /// Loads the signer, prompting for input or connecting to a hardware
+    /// wallet as needed. `name` identifies the signer in prompts.
+    ///
+    /// The hardware wallet manager is created the first time a hardware
+    /// wallet is used and shared by later signers.
+    pub fn signer(
+        &self,
+        name: &str,
+        wallet_manager: &mut Option<Rc<RemoteWalletManager>>,
+    ) -> Result<Box<dyn Signer>, SignerError> {
+        let read_error = |error: &dyn fmt::Display| SignerError::Read {
+            signer: self.to_string(),
+            message: error.to_string(),
+        };
+        match self {
+            Self::File(path) => Ok(Box::new(
+                read_keypair_file(path).map_err(|error| read_error(&error))?,
+            )),
+            Self::Stdin => Ok(Box::new(
+                read_keypair(&mut std::io::stdin()).map_err(|error| read_error(&error))?,
+            )),
+            Self::Prompt { derivation_path } => Ok(Box::new(
+                prompt_keypair(name, derivation_path.clone())
+                    .map_err(|error| read_error(&error))?,
+            )),
+            Self::Usb {
+                locator,
+                derivation_path,
+            } => {
+                if wallet_manager.is_none() {
+                    *wallet_manager = maybe_wallet_manager().map_err(|error| read_error(&error))?;
+                }
+                let Some(wallet_manager) = wallet_manager else {
+                    return Err(SignerError::NoDeviceFound(self.to_string()));
+                };
+                Ok(Box::new(
+                    generate_remote_keypair(
+                        locator.clone(),
+                        derivation_path.clone().unwrap_or_default(),
+                        wallet_manager,
+                        false,
+                        name,
+                    )
+                    .map_err(|error| read_error(&error))?,
+                ))
+            }
+        }
+    }
+}
+
+/// Prompts for a seed phrase and passphrase and derives a keypair from them.
+fn prompt_keypair(
+    name: &str,
+    derivation_path: Option<DerivationPath>,
+) -> Result<Keypair, Box<dyn std::error::Error>> {
+    let seed_phrase = rpassword::prompt_password(format!("[{name}] seed phrase: "))?;
+    let seed_phrase = seed_phrase
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    bip39::Mnemonic::parse_normalized(&seed_phrase)?;
+    let passphrase = rpassword::prompt_password(format!(
+        "[{name}] If this seed phrase has an associated passphrase, enter it now. Otherwise, \
+         press ENTER to continue: "
+    ))?;
+    match derivation_path {
+        Some(derivation_path) => keypair_from_seed_and_derivation_path(
+            &generate_seed_from_seed_phrase_and_passphrase(&seed_phrase, &passphrase),
+            Some(derivation_path),
+        ),
+        None => keypair_from_seed_phrase_and_passphrase(&seed_phrase, &passphrase),
+    }
+}
+
+impl FromStr for SignerSource {
+    type Err = SignerError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        if source.is_empty() {
+            return Err(SignerError::Empty);
+        }
+        if source == "-" {
+            return Ok(Self::Stdin);
+        }
+        let Some((scheme, rest)) = source.split_once(':') else {
+            return Ok(Self::File(source.into()));
+        };
+        let path = rest.strip_prefix("//").unwrap_or(rest);
+        let invalid = |message: &dyn fmt::Display| SignerError::InvalidUri {
+            signer: source.to_string(),
+            message: message.to_string(),
+        };
+        match scheme.to_ascii_lowercase().as_str() {
+            "file" if !path.is_empty() => Ok(Self::File(path.into())),
+            "file" => Err(SignerError::Empty),
+            "stdin" => Ok(Self::Stdin),
+            "prompt" => {
+                let uri = URIReference::try_from(source).map_err(|error| invalid(&error))?;
+                Ok(Self::Prompt {
+                    derivation_path: DerivationPath::from_uri_key_query(&uri)
+                        .map_err(|error| invalid(&error))?,
+                })
+            }
+            "usb" => {
+                let uri = URIReference::try_from(source).map_err(|error| invalid(&error))?;
+                Ok(Self::Usb {
+                    locator: Locator::new_from_uri(&uri).map_err(|error| invalid(&error))?,
+                    derivation_path: DerivationPath::from_uri_key_query(&uri)
+                        .map_err(|error| invalid(&error))?,
+                })
+            }
+            "ask" => Err(SignerError::UnsupportedScheme(scheme.to_string())),
+            // Not a URL scheme; treat the whole source as a path.
+            _ if !rest.starts_with("//") => Ok(Self::File(source.into())),
+            _ => Err(SignerError::UnsupportedScheme(scheme.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for SignerSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => f.write_str("stdin"),
+            Self::Prompt { derivation_path } => write!(
+                f,
+                "prompt:{}",
+                derivation_path
+                    .as_ref()
+                    .map(DerivationPath::get_query)
+                    .unwrap_or_default()
+            ),
+            Self::Usb {
+                locator,
+                derivation_path,
+            } => write!(
+                f,
+                "{locator}{}",
+                derivation_path
+                    .as_ref()
+                    .map(DerivationPath::get_query)
+                    .unwrap_or_default()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_keypair::write_keypair_file, solana_signer::Signer};
+
+    #[test]
+    fn test_parse_signer_source() {
+        assert_eq!("id.json".parse(), Ok(SignerSource::File("id.json".into())));
+        assert_eq!(
+            "file:/keys/id.json".parse(),
+            Ok(SignerSource::File("/keys/id.json".into()))
+        );
+        assert_eq!(
+            "file:///keys/id.json".parse(),
+            Ok(SignerSource::File("/keys/id.json".into()))
+        );
+        assert_eq!("stdin:".parse(), Ok(SignerSource::Stdin));
+        assert_eq!("stdin://".parse(), Ok(SignerSource::Stdin));
+        assert_eq!("-".parse(), Ok(SignerSource::Stdin));
+        assert_eq!(
+            "keys:2024.json".parse(),
+            Ok(SignerSource::File("keys:2024.json".into()))
+        );
+
+        assert_eq!("".parse::<SignerSource>(), Err(SignerError::Empty));
+        assert_eq!("file:".parse::<SignerSource>(), Err(SignerError::Empty));
+        assert_eq!(
+            "prompt:".parse(),
+            Ok(SignerSource::Prompt {
+                derivation_path: None
+            })
+        );
+        assert_eq!(
+            "prompt://?key=0/0".parse(),
+            Ok(SignerSource::Prompt {
+                derivation_path: Some(DerivationPath::new_bip44(Some(0), Some(0)))
+            })
+        );
+        let usb = "usb://ledger?key=1".parse::<SignerSource>().unwrap();
+        assert_eq!(
+            usb,
+            SignerSource::Usb {
+                locator: Locator::new_from_path("usb://ledger").unwrap(),
+                derivation_path: Some(DerivationPath::new_bip44(Some(1), None)),
+            }
+        );
+        assert_eq!(usb.to_string(), "usb://ledger/?key=1'");
+        assert!(matches!(
+            "usb://not-a-wallet".parse::<SignerSource>(),
+            Err(SignerError::InvalidUri { .. })
+        ));
+        assert!(matches!(
+            "prompt://?key=x".parse::<SignerSource>(),
+            Err(SignerError::InvalidUri { .. })
+        ));
+        assert_eq!(
+            "https://example.com/id.json".parse::<SignerSource>(),
+            Err(SignerError::UnsupportedScheme("https".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_load_keypair_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("feature.json");
+        let keypair = Keypair::new();
+        write_keypair_file(&keypair, &path).unwrap();
+
+        let source: SignerSource = format!("file:{}", path.display()).parse().unwrap();
+        let signer = source.signer("feature", &mut None).unwrap();
+        assert_eq!(signer.pubkey(), keypair.pubkey());
+
+        let missing = SignerSource::File(dir.path().join("missing.json"));
+        assert!(matches!(
+            missing.signer("feature", &mut None),
+            Err(SignerError::Read { .. })
+        ));
+    }
+}
@@ -0,0 +1,182 @@
+//! Transaction signing, sending and dry runs.
+
+use {
+    crate::{
+        error::Error,
+        output::{CommandOutput, Table},
+    },
+    base64::{prelude::BASE64_STANDARD, Engine},
+    serde::Serialize,
+    solana_address::Address,
+    solana_feature_gate_client::{
+        parser::{parse_instruction, AccountRoleIssue, ParsedFeatureGateInstruction},
+        transaction_error::decode_transaction_error,
+        ID,
+    },
+    solana_instruction::Instruction,
+    solana_message::AccountKeys,
+    solana_rpc_client::{
+        api::{
+            client_error::{Error as ClientError, ErrorKind},
+            request::{RpcError, RpcResponseErrorData},
+        },
+        rpc_client::RpcClient,
+    },
+    solana_signer::Signer,
+    solana_transaction::Transaction,
+};
+
+/// A Feature Gate instruction as it appears in a dry-run transaction.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstructionSummary {
+    /// The instruction's program.
+    pub program_id: String,
+    /// The decoded instruction, if it targets the Feature Gate program.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parsed: Option<ParsedFeatureGateInstruction>,
+}
+
+/// The result of a transaction-producing command.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionOutput {
+    /// The feature the transaction operates on.
+    pub feature: String,
+    /// The transaction signature.
+    pub signature: String,
+    /// Whether the transaction was printed instead of sent.
+    pub dry_run: bool,
+    /// The signed transaction, base64-encoded. Only set for dry runs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<String>,
+    /// The instructions in the transaction. Only set for dry runs.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub instructions: Vec<InstructionSummary>,
+}
+
+impl CommandOutput for TransactionOutput {
+    fn table(&self) -> Table {
+        let mut entries = vec![
+            ("Feature", self.feature.clone()),
+            ("Signature", self.signature.clone()),
+        ];
+        if let Some(transaction) = &self.transaction {
+            entries.push(("Transaction", transaction.clone()));
+        }
+        let instructions = self
+            .instructions
+            .iter()
+            .map(|instruction| match &instruction.parsed {
+                Some(parsed) => {
+                    let mut summary = parsed.name().to_string();
+                    if let Some(feature) = parsed.feature_id() {
+                        summary.push_str(&format!(" ({feature})"));
+                    }
+                    for issue in parsed.issues() {
+                        summary.push_str(&format!(" [{}]", describe_issue(issue)));
+                    }
+                    summary
+                }
+                None => format!("program {}", instruction.program_id),
+            });
+        entries.extend(instructions.map(|summary| ("Instruction", summary)));
+        if self.dry_run {
+            entries.push(("Status", "not sent (dry run)".to_string()));
+        }
+        Table::key_value(entries)
+    }
+}
+
+fn describe_issue(issue: &AccountRoleIssue) -> String {
+    match issue {
+        AccountRoleIssue::MissingSigner { account } => format!("{account} must sign"),
+        AccountRoleIssue::NotWritable { account } => format!("{account} must be writable"),
+        AccountRoleIssue::UnexpectedAddress { account, expected } => {
+            format!("{account} must be {expected}")
+        }
+        AccountRoleIssue::UnexpectedAccount { address } => {
+            format!("{address} is not an account of this instruction")
+        }
+    }
+}
+
+/// Signs a transaction and either sends it or, for dry runs, describes it.
+pub fn process_transaction(
+    rpc_client: &RpcClient,
+    feature: &Address,
+    instructions: &[Instruction],
+    fee_payer: &dyn Signer,
+    signers: &[&dyn Signer],
+    dry_run: bool,
+) -> Result<TransactionOutput, Error> {
+    let blockhash = rpc_client.get_latest_blockhash().map_err(Box::new)?;
+    let signers = std::iter::once(fee_payer)
+        .chain(signers.iter().copied())
+        .collect::<Vec<_>>();
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&fee_payer.pubkey()),
+        &signers,
+        blockhash,
+    );
+    let signature = transaction.signatures[0].to_string();
+
+    if dry_run {
+        return Ok(TransactionOutput {
+            feature: feature.to_string(),
+            signature,
+            dry_run,
+            transaction: Some(BASE64_STANDARD.encode(bincode::serialize(&transaction)?)),
+            instructions: summarize_instructions(&transaction),
+        });
+    }
+
+    rpc_client
+        .send_and_confirm_transaction(&transaction)
+        .map_err(|error| decode_send_error(&transaction, error))?;
+    Ok(TransactionOutput {
+        feature: feature.to_string(),
+        signature,
+        dry_run,
+        transaction: None,
+        instructions: Vec::new(),
+    })
+}
+
+fn summarize_instructions(transaction: &Transaction) -> Vec<InstructionSummary> {
+    let message = &transaction.message;
+    let account_keys = AccountKeys::new(&message.account_keys, None);
+    message
+        .instructions
+        .iter()
+        .map(|instruction| {
+            let program_id = instruction.program_id(&message.account_keys);
+            InstructionSummary {
+                program_id: program_id.to_string(),
+                parsed: (program_id == &ID)
+                    .then(|| parse_instruction(instruction, &account_keys, message).ok())
+                    .flatten(),
+            }
+        })
+        .collect()
+}
+
+/// Decodes failures of Feature Gate instructions into program errors, so
+/// they can be reported with a hint.
+fn decode_send_error(transaction: &Transaction, error: ClientError) -> Error {
+    let Some(transaction_error) = error.get_transaction_error() else {
+        return Box::new(error).into();
+    };
+    let logs = match error.kind() {
+        ErrorKind::RpcError(RpcError::RpcResponseError {
+            data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+            ..
+        }) => result.logs.clone(),
+        _ => None,
+    };
+    match decode_transaction_error(&transaction_error, &transaction.message, logs.as_deref()) {
+        Some(error) => Error::Program { error },
+        None => Box::new(error).into(),
+    }
+}
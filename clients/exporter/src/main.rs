@@ -0,0 +1,78 @@
+//! `feature-gate-exporter`: a Prometheus exporter for the Feature Gate
+//! program.
+
+mod metrics;
+mod server;
+
+use {
+    crate::{metrics::FeatureMetrics, server::MetricsState},
+    clap::Parser,
+    solana_commitment_config::CommitmentConfig,
+    solana_feature_gate_client::cluster::normalize_url,
+    solana_rpc_client::rpc_client::RpcClient,
+    std::{net::SocketAddr, process::ExitCode, sync::Arc, time::Duration},
+};
+
+#[derive(Debug, Parser)]
+#[command(name = "feature-gate-exporter", version, about)]
+struct Cli {
+    /// RPC URL or moniker of the cluster: `mainnet-beta`, `testnet`,
+    /// `devnet` or `localhost` (or their first letter).
+    #[arg(
+        long,
+        short = 'u',
+        env = "SOLANA_RPC_URL",
+        default_value = "mainnet-beta"
+    )]
+    url: String,
+
+    /// The `cluster` label attached to every metric. Defaults to `--url`.
+    #[arg(long)]
+    cluster: Option<String>,
+
+    /// The address to serve `/metrics` on.
+    #[arg(long, default_value = "0.0.0.0:9877")]
+    listen: SocketAddr,
+
+    /// Seconds between refreshes of the feature accounts.
+    #[arg(long, default_value_t = 30)]
+    interval: u64,
+}
+
+/// Refreshes the metrics every `interval`, forever.
+fn refresh(rpc_client: &RpcClient, state: &MetricsState, interval: Duration) {
+    loop {
+        match FeatureMetrics::fetch(rpc_client) {
+            Ok(metrics) => state.update(metrics),
+            Err(error) => {
+                eprintln!("Failed to refresh feature metrics: {error}");
+                state.record_error();
+            }
+        }
+        std::thread::sleep(interval);
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let rpc_client = RpcClient::new_with_commitment(
+        normalize_url(&cli.url).to_string(),
+        CommitmentConfig::confirmed(),
+    );
+    let state = Arc::new(MetricsState::new(cli.cluster.unwrap_or(cli.url)));
+
+    let server = match tiny_http::Server::http(cli.listen) {
+        Ok(server) => server,
+        Err(error) => {
+            eprintln!("Error: failed to listen on {}: {error}", cli.listen);
+            return ExitCode::FAILURE;
+        }
+    };
+    let refresh_state = Arc::clone(&state);
+    let interval = Duration::from_secs(cli.interval);
+    std::thread::spawn(move || refresh(&rpc_client, &refresh_state, interval));
+
+    eprintln!("Serving metrics on http://{}/metrics", cli.listen);
+    server::serve(&server, &state);
+    ExitCode::SUCCESS
+}
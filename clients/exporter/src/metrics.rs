@@ -0,0 +1,333 @@
+//! Feature gate metrics and their Prometheus text exposition.
+
+use {
+    solana_address::Address,
+    solana_clock::{Slot, UnixTimestamp},
+    solana_epoch_schedule::EpochSchedule,
+    solana_feature_gate_client::{
+        activation::{
+            predict_activation, ActivationEstimateConfig, ActivationPrediction, PendingActivation,
+        },
+        known_features::KnownFeatureRegistry,
+        snapshot::{ClusterSnapshot, FeatureStatus, SnapshotError},
+    },
+    solana_feature_gate_interface::Feature,
+    solana_rpc_client::rpc_client::RpcClient,
+    std::{
+        fmt::Write,
+        time::{SystemTime, UNIX_EPOCH},
+    },
+};
+
+/// An activated feature.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeatureActivation {
+    /// The feature ID.
+    pub id: Address,
+    /// The feature's name in the known-feature registry, if any.
+    pub name: Option<String>,
+    /// The slot at which the feature was activated.
+    pub activated_at: Slot,
+}
+
+/// The state of a cluster's feature accounts at a single point in time.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeatureMetrics {
+    /// The slot at which the accounts were fetched.
+    pub slot: Slot,
+    /// The number of features scheduled for a future epoch.
+    pub scheduled: usize,
+    /// The number of features pending activation.
+    pub pending: usize,
+    /// The number of activated features.
+    pub active: usize,
+    /// The number of features revoked for lack of support, leaving a
+    /// tombstone.
+    pub tombstoned: usize,
+    /// The number of feature-sized program accounts that are not valid
+    /// features.
+    pub invalid: usize,
+    /// Every activated feature, ordered by ID.
+    pub activations: Vec<FeatureActivation>,
+    /// When the next epoch boundary, and so the next activation, occurs.
+    pub next_epoch: PendingActivation,
+    /// The Unix timestamp at which the metrics were collected.
+    pub collected_at: UnixTimestamp,
+}
+
+impl FeatureMetrics {
+    /// Computes metrics from a snapshot of every feature account.
+    pub fn new(
+        snapshot: &ClusterSnapshot,
+        slot: Slot,
+        epoch_schedule: &EpochSchedule,
+        now: UnixTimestamp,
+        registry: &KnownFeatureRegistry,
+    ) -> Self {
+        let (mut scheduled, mut pending, mut active, mut tombstoned, mut invalid) =
+            (0usize, 0usize, 0usize, 0usize, 0usize);
+        let mut activations = Vec::new();
+        for account in &snapshot.accounts {
+            match account.status() {
+                FeatureStatus::Scheduled { .. } => scheduled = scheduled.saturating_add(1),
+                FeatureStatus::Pending => pending = pending.saturating_add(1),
+                FeatureStatus::Active { activated_at } => {
+                    active = active.saturating_add(1);
+                    activations.push(FeatureActivation {
+                        id: account.address,
+                        name: registry
+                            .get(&account.address)
+                            .map(|feature| feature.name.to_string()),
+                        activated_at,
+                    });
+                }
+                FeatureStatus::Tombstone { .. } => tombstoned = tombstoned.saturating_add(1),
+                FeatureStatus::Invalid => invalid = invalid.saturating_add(1),
+            }
+        }
+
+        let next_epoch = match predict_activation(
+            &Feature::default(),
+            epoch_schedule,
+            slot,
+            now,
+            &ActivationEstimateConfig::default(),
+        ) {
+            ActivationPrediction::Pending(pending) => pending,
+            ActivationPrediction::Active { .. } => unreachable!("the default feature is pending"),
+        };
+
+        Self {
+            slot,
+            scheduled,
+            pending,
+            active,
+            tombstoned,
+            invalid,
+            activations,
+            next_epoch,
+            collected_at: now,
+        }
+    }
+
+    /// Fetches every feature account, the current slot and the epoch
+    /// schedule, and computes their metrics.
+    pub fn fetch(rpc_client: &RpcClient) -> Result<Self, SnapshotError> {
+        let snapshot = ClusterSnapshot::fetch(rpc_client)?;
+        let slot = match snapshot.slot {
+            Some(slot) => slot,
+            None => rpc_client.get_slot().map_err(Box::new)?,
+        };
+        let epoch_schedule = rpc_client.get_epoch_schedule().map_err(Box::new)?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| UnixTimestamp::try_from(elapsed.as_secs()).unwrap_or(UnixTimestamp::MAX))
+            .unwrap_or_default();
+        Ok(Self::new(
+            &snapshot,
+            slot,
+            &epoch_schedule,
+            now,
+            &KnownFeatureRegistry::new(),
+        ))
+    }
+
+    /// Writes the metrics in the Prometheus text format, labelled with
+    /// `cluster`.
+    pub fn render(&self, cluster: &str, out: &mut String) {
+        let cluster = format!("cluster=\"{}\"", escape_label(cluster));
+        let gauges = [
+            (
+                "feature_gate_scheduled_features",
+                "Features scheduled to activate no earlier than a future epoch.",
+                self.scheduled.to_string(),
+            ),
+            (
+                "feature_gate_pending_features",
+                "Features queued for activation at the next epoch boundary.",
+                self.pending.to_string(),
+            ),
+            (
+                "feature_gate_active_features",
+                "Activated features.",
+                self.active.to_string(),
+            ),
+            (
+                "feature_gate_tombstoned_features",
+                "Features revoked for lack of support, which can never be queued again.",
+                self.tombstoned.to_string(),
+            ),
+            (
+                "feature_gate_invalid_accounts",
+                "Feature-sized accounts owned by the Feature Gate program that are not valid \
+                 features.",
+                self.invalid.to_string(),
+            ),
+            (
+                "feature_gate_slot",
+                "The slot at which the feature accounts were fetched.",
+                self.slot.to_string(),
+            ),
+            (
+                "feature_gate_slots_until_next_epoch",
+                "Slots until the next epoch boundary.",
+                self.next_epoch.slots_until_activation.to_string(),
+            ),
+            (
+                "feature_gate_seconds_until_next_epoch",
+                "Estimated seconds until the next epoch boundary.",
+                self.next_epoch.time_until_activation.as_secs().to_string(),
+            ),
+            (
+                "feature_gate_last_refresh_timestamp_seconds",
+                "Unix time of the last successful refresh.",
+                self.collected_at.to_string(),
+            ),
+        ];
+        for (name, help, value) in gauges {
+            write_header(out, name, help, "gauge");
+            let _ = writeln!(out, "{name}{{{cluster}}} {value}");
+        }
+
+        let name = "feature_gate_feature_activation_slot";
+        write_header(
+            out,
+            name,
+            "The slot at which each feature was activated.",
+            "gauge",
+        );
+        for activation in &self.activations {
+            let _ = writeln!(
+                out,
+                "{name}{{{cluster},feature=\"{}\",name=\"{}\"}} {}",
+                activation.id,
+                escape_label(activation.name.as_deref().unwrap_or_default()),
+                activation.activated_at,
+            );
+        }
+    }
+}
+
+/// Writes the `HELP` and `TYPE` lines of a metric.
+pub fn write_header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+/// Escapes a Prometheus label value.
+pub fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use {
+        super::*,
+        base64::{prelude::BASE64_STANDARD, Engine},
+        solana_account::ReadableAccount,
+        solana_address::address,
+        solana_feature_gate_interface::create_account,
+        solana_rpc_client::{api::request::RpcRequest, mock_sender::MocksMap},
+    };
+
+    pub const LOADER_V4: Address = address!("2aQJYqER2aKyb3cZw22v4SL2xMX7vwXBRWfvS4pTrtED");
+
+    fn keyed_account(address: &Address, data: &[u8]) -> serde_json::Value {
+        serde_json::json!({
+            "pubkey": address.to_string(),
+            "account": {
+                "lamports": 1,
+                "data": [BASE64_STANDARD.encode(data), "base64"],
+                "owner": solana_feature_gate_client::ID.to_string(),
+                "executable": false,
+                "rentEpoch": 0,
+                "space": data.len(),
+            },
+        })
+    }
+
+    fn keyed_feature(address: &Address, activated_at: Option<Slot>) -> serde_json::Value {
+        keyed_account(address, create_account(&Feature { activated_at }, 1).data())
+    }
+
+    /// An RPC client whose cluster has one active, one pending, one scheduled
+    /// and one tombstoned feature.
+    pub fn mock_rpc_client(slot: Slot) -> RpcClient {
+        let mut mocks = MocksMap::default();
+        mocks.insert(RpcRequest::GetSlot, serde_json::json!(slot));
+        mocks.insert(
+            RpcRequest::GetProgramAccounts,
+            serde_json::json!([
+                keyed_feature(&LOADER_V4, Some(1_000)),
+                keyed_feature(&Address::new_unique(), None),
+                keyed_account(&Address::new_unique(), &[0xFE, 12, 0, 0, 0, 0, 0, 0, 0]),
+                keyed_account(&Address::new_unique(), &[0xFF, 0x84, 3, 0, 0, 0, 0, 0, 0]),
+            ]),
+        );
+        mocks.insert(
+            RpcRequest::GetEpochSchedule,
+            serde_json::to_value(EpochSchedule::without_warmup()).unwrap(),
+        );
+        RpcClient::new_mock_with_mocks_map("succeeds", mocks)
+    }
+
+    #[test]
+    fn test_fetch_from_mock_rpc() {
+        let epoch_schedule = EpochSchedule::without_warmup();
+        let slot = epoch_schedule
+            .get_first_slot_in_epoch(10)
+            .saturating_add(1_000);
+        let metrics = FeatureMetrics::fetch(&mock_rpc_client(slot)).unwrap();
+
+        assert_eq!(metrics.slot, slot);
+        assert_eq!(
+            (
+                metrics.scheduled,
+                metrics.pending,
+                metrics.active,
+                metrics.tombstoned,
+                metrics.invalid
+            ),
+            (1, 1, 1, 1, 0)
+        );
+        assert_eq!(
+            metrics.activations,
+            vec![FeatureActivation {
+                id: LOADER_V4,
+                name: Some("enable_loader_v4".to_string()),
+                activated_at: 1_000,
+            }]
+        );
+        assert_eq!(metrics.next_epoch.activation_epoch, 11);
+        assert_eq!(
+            metrics.next_epoch.slots_until_activation,
+            epoch_schedule.slots_per_epoch.saturating_sub(1_000)
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let epoch_schedule = EpochSchedule::without_warmup();
+        let slot = epoch_schedule.get_last_slot_in_epoch(3).saturating_sub(9);
+        let metrics = FeatureMetrics::fetch(&mock_rpc_client(slot)).unwrap();
+
+        let mut out = String::new();
+        metrics.render("test\"net", &mut out);
+        let lines = out.lines().collect::<Vec<_>>();
+        for expected in [
+            "# TYPE feature_gate_pending_features gauge",
+            r#"feature_gate_pending_features{cluster="test\"net"} 1"#,
+            r#"feature_gate_active_features{cluster="test\"net"} 1"#,
+            r#"feature_gate_slots_until_next_epoch{cluster="test\"net"} 10"#,
+            r#"feature_gate_seconds_until_next_epoch{cluster="test\"net"} 4"#,
+            &format!(
+                r#"feature_gate_feature_activation_slot{{cluster="test\"net",feature="{LOADER_V4}",name="enable_loader_v4"}} 1000"#
+            ),
+        ] {
+            assert!(lines.contains(&expected), "missing `{expected}` in:\n{out}");
+        }
+    }
+}
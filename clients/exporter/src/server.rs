@@ -0,0 +1,147 @@
+//! The `/metrics` HTTP endpoint.
+
+use {
+    crate::metrics::{escape_label, write_header, FeatureMetrics},
+    std::{
+        fmt::Write,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            RwLock,
+        },
+    },
+    tiny_http::{Header, Request, Response, Server},
+};
+
+/// The content type of the Prometheus text format.
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// The latest metrics, shared between the refresh loop and the server.
+#[derive(Debug)]
+pub struct MetricsState {
+    cluster: String,
+    metrics: RwLock<Option<FeatureMetrics>>,
+    refresh_errors: AtomicU64,
+}
+
+impl MetricsState {
+    /// Creates an empty state whose metrics are labelled with `cluster`.
+    pub fn new(cluster: String) -> Self {
+        Self {
+            cluster,
+            metrics: RwLock::new(None),
+            refresh_errors: AtomicU64::new(0),
+        }
+    }
+
+    /// Replaces the metrics with the result of a successful refresh.
+    pub fn update(&self, metrics: FeatureMetrics) {
+        *self
+            .metrics
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(metrics);
+    }
+
+    /// Counts a failed refresh. The previous metrics keep being served.
+    pub fn record_error(&self) {
+        self.refresh_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Renders every metric in the Prometheus text format.
+    ///
+    /// Until the first successful refresh, only the error counter is
+    /// reported.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        if let Some(metrics) = self
+            .metrics
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .as_ref()
+        {
+            metrics.render(&self.cluster, &mut out);
+        }
+        let name = "feature_gate_refresh_errors_total";
+        write_header(
+            &mut out,
+            name,
+            "Failed refreshes of the feature accounts.",
+            "counter",
+        );
+        let _ = writeln!(
+            out,
+            "{name}{{cluster=\"{}\"}} {}",
+            escape_label(&self.cluster),
+            self.refresh_errors.load(Ordering::Relaxed),
+        );
+        out
+    }
+}
+
+/// Answers a single request: `GET /metrics` with the metrics, anything else
+/// with a 404.
+pub fn respond(request: Request, state: &MetricsState) {
+    let path = request.url().split('?').next().unwrap_or_default();
+    let result = if path == "/metrics" {
+        let header = Header::from_bytes("Content-Type", CONTENT_TYPE)
+            .expect("the content type header is valid");
+        request.respond(Response::from_string(state.render()).with_header(header))
+    } else {
+        request.respond(Response::from_string("Not Found").with_status_code(404))
+    };
+    if let Err(error) = result {
+        eprintln!("Failed to send response: {error}");
+    }
+}
+
+/// Serves requests until the server shuts down.
+pub fn serve(server: &Server, state: &MetricsState) {
+    for request in server.incoming_requests() {
+        respond(request, state);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::metrics::test::mock_rpc_client,
+        std::{
+            io::{Read, Write as _},
+            net::TcpStream,
+        },
+    };
+
+    fn get(server: &Server, state: &MetricsState, path: &str) -> String {
+        let address = server.server_addr().to_ip().unwrap();
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "GET {path} HTTP/1.1\r\nHost: {address}\r\nConnection: close\r\n\r\n"
+        )
+        .unwrap();
+        respond(server.recv().unwrap(), state);
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_serve_metrics() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let state = MetricsState::new("devnet".to_string());
+
+        state.record_error();
+        let response = get(&server, &state, "/metrics");
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.contains(CONTENT_TYPE));
+        assert!(response.contains(r#"feature_gate_refresh_errors_total{cluster="devnet"} 1"#));
+        assert!(!response.contains("feature_gate_pending_features"));
+
+        state.update(FeatureMetrics::fetch(&mock_rpc_client(100)).unwrap());
+        let response = get(&server, &state, "/metrics");
+        assert!(response.contains(r#"feature_gate_pending_features{cluster="devnet"} 1"#));
+        assert!(response.contains(r#"feature_gate_slot{cluster="devnet"} 100"#));
+
+        assert!(get(&server, &state, "/").starts_with("HTTP/1.1 404"));
+    }
+}
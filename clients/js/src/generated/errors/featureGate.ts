@@ -16,12 +16,43 @@ import { FEATURE_GATE_PROGRAM_ADDRESS } from '../programs';
 
 /** FeatureAlreadyActivated: Feature already activated */
 export const FEATURE_GATE_ERROR__FEATURE_ALREADY_ACTIVATED = 0x0; // 0
+/** InvalidVoteAuthority: Signer is not an authority of the vote account */
+export const FEATURE_GATE_ERROR__INVALID_VOTE_AUTHORITY = 0x1; // 1
+/** FeatureSufficientlySupported: Feature has sufficient supporting stake */
+export const FEATURE_GATE_ERROR__FEATURE_SUFFICIENTLY_SUPPORTED = 0x2; // 2
+/** RevokeWindowClosed: Stake guard revoke window is not open */
+export const FEATURE_GATE_ERROR__REVOKE_WINDOW_CLOSED = 0x3; // 3
+/** InvalidRefundRecipient: Recipient is not allowed to receive refunds */
+export const FEATURE_GATE_ERROR__INVALID_REFUND_RECIPIENT = 0x4; // 4
+/** ActivationNotDue: Scheduled activation cannot be released yet */
+export const FEATURE_GATE_ERROR__ACTIVATION_NOT_DUE = 0x5; // 5
+/** InvalidBundleMember: Account does not match the bundle member */
+export const FEATURE_GATE_ERROR__INVALID_BUNDLE_MEMBER = 0x6; // 6
+/** InvalidConfig: Config is invalid */
+export const FEATURE_GATE_ERROR__INVALID_CONFIG = 0x7; // 7
 
-export type FeatureGateError = typeof FEATURE_GATE_ERROR__FEATURE_ALREADY_ACTIVATED;
+export type FeatureGateError =
+    | typeof FEATURE_GATE_ERROR__ACTIVATION_NOT_DUE
+    | typeof FEATURE_GATE_ERROR__FEATURE_ALREADY_ACTIVATED
+    | typeof FEATURE_GATE_ERROR__FEATURE_SUFFICIENTLY_SUPPORTED
+    | typeof FEATURE_GATE_ERROR__INVALID_BUNDLE_MEMBER
+    | typeof FEATURE_GATE_ERROR__INVALID_CONFIG
+    | typeof FEATURE_GATE_ERROR__INVALID_REFUND_RECIPIENT
+    | typeof FEATURE_GATE_ERROR__INVALID_VOTE_AUTHORITY
+    | typeof FEATURE_GATE_ERROR__REVOKE_WINDOW_CLOSED;
 
 let featureGateErrorMessages: Record<FeatureGateError, string> | undefined;
 if (process.env['NODE_ENV'] !== 'production') {
-    featureGateErrorMessages = { [FEATURE_GATE_ERROR__FEATURE_ALREADY_ACTIVATED]: `Feature already activated` };
+    featureGateErrorMessages = {
+        [FEATURE_GATE_ERROR__ACTIVATION_NOT_DUE]: `Scheduled activation cannot be released yet`,
+        [FEATURE_GATE_ERROR__FEATURE_ALREADY_ACTIVATED]: `Feature already activated`,
+        [FEATURE_GATE_ERROR__FEATURE_SUFFICIENTLY_SUPPORTED]: `Feature has sufficient supporting stake`,
+        [FEATURE_GATE_ERROR__INVALID_BUNDLE_MEMBER]: `Account does not match the bundle member`,
+        [FEATURE_GATE_ERROR__INVALID_CONFIG]: `Config is invalid`,
+        [FEATURE_GATE_ERROR__INVALID_REFUND_RECIPIENT]: `Recipient is not allowed to receive refunds`,
+        [FEATURE_GATE_ERROR__INVALID_VOTE_AUTHORITY]: `Signer is not an authority of the vote account`,
+        [FEATURE_GATE_ERROR__REVOKE_WINDOW_CLOSED]: `Stake guard revoke window is not open`,
+    };
 }
 
 export function getFeatureGateErrorMessage(code: FeatureGateError): string {
@@ -6,4 +6,17 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './extendAuditLog';
+export * from './initializeAuditLog';
+export * from './initializeBundle';
+export * from './initializeConfig';
+export * from './initializeStakeGuard';
+export * from './queueBundle';
+export * from './releaseScheduledActivation';
+export * from './revokeBundle';
 export * from './revokePendingActivation';
+export * from './revokeUnsupportedActivation';
+export * from './scheduleActivation';
+export * from './signalSupport';
+export * from './tallySupport';
+export * from './updateConfig';
@@ -44,6 +44,7 @@ export type RevokePendingActivationInstruction<
     TAccountFeature extends string | AccountMeta<string> = string,
     TAccountIncinerator extends string | AccountMeta<string> = string,
     TAccountSystemProgram extends string | AccountMeta<string> = '11111111111111111111111111111111',
+    TAccountAuditLog extends string | AccountMeta<string> = string,
     TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
     InstructionWithData<ReadonlyUint8Array> &
@@ -54,6 +55,7 @@ export type RevokePendingActivationInstruction<
                 : TAccountFeature,
             TAccountIncinerator extends string ? WritableAccount<TAccountIncinerator> : TAccountIncinerator,
             TAccountSystemProgram extends string ? ReadonlyAccount<TAccountSystemProgram> : TAccountSystemProgram,
+            TAccountAuditLog extends string ? WritableAccount<TAccountAuditLog> : TAccountAuditLog,
             ...TRemainingAccounts,
         ]
     >;
@@ -87,24 +89,34 @@ export type RevokePendingActivationInput<
     TAccountFeature extends string = string,
     TAccountIncinerator extends string = string,
     TAccountSystemProgram extends string = string,
+    TAccountAuditLog extends string = string,
 > = {
     /** The feature account to revoke */
     feature: TransactionSigner<TAccountFeature>;
-    /** The incinerator account */
+    /** The incinerator account, or an allowed refund recipient */
     incinerator: Address<TAccountIncinerator>;
     /** The system program */
     systemProgram?: Address<TAccountSystemProgram>;
+    /** The audit log account */
+    auditLog?: Address<TAccountAuditLog>;
 };
 
 export function getRevokePendingActivationInstruction<
     TAccountFeature extends string,
     TAccountIncinerator extends string,
     TAccountSystemProgram extends string,
+    TAccountAuditLog extends string,
     TProgramAddress extends Address = typeof FEATURE_GATE_PROGRAM_ADDRESS,
 >(
-    input: RevokePendingActivationInput<TAccountFeature, TAccountIncinerator, TAccountSystemProgram>,
+    input: RevokePendingActivationInput<TAccountFeature, TAccountIncinerator, TAccountSystemProgram, TAccountAuditLog>,
     config?: { programAddress?: TProgramAddress },
-): RevokePendingActivationInstruction<TProgramAddress, TAccountFeature, TAccountIncinerator, TAccountSystemProgram> {
+): RevokePendingActivationInstruction<
+    TProgramAddress,
+    TAccountFeature,
+    TAccountIncinerator,
+    TAccountSystemProgram,
+    TAccountAuditLog
+> {
     // Program address.
     const programAddress = config?.programAddress ?? FEATURE_GATE_PROGRAM_ADDRESS;
 
@@ -113,6 +125,7 @@ export function getRevokePendingActivationInstruction<
         feature: { value: input.feature ?? null, isWritable: true },
         incinerator: { value: input.incinerator ?? null, isWritable: true },
         systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+        auditLog: { value: input.auditLog ?? null, isWritable: true },
     };
     const accounts = originalAccounts as Record<keyof typeof originalAccounts, ResolvedInstructionAccount>;
 
@@ -128,6 +141,7 @@ export function getRevokePendingActivationInstruction<
             getAccountMeta('feature', accounts.feature),
             getAccountMeta('incinerator', accounts.incinerator),
             getAccountMeta('systemProgram', accounts.systemProgram),
+            getAccountMeta('auditLog', accounts.auditLog),
         ],
         data: getRevokePendingActivationInstructionDataEncoder().encode({}),
         programAddress,
@@ -135,7 +149,8 @@ export function getRevokePendingActivationInstruction<
         TProgramAddress,
         TAccountFeature,
         TAccountIncinerator,
-        TAccountSystemProgram
+        TAccountSystemProgram,
+        TAccountAuditLog
     >);
 }
 
@@ -147,10 +162,12 @@ export type ParsedRevokePendingActivationInstruction<
     accounts: {
         /** The feature account to revoke */
         feature: TAccountMetas[0];
-        /** The incinerator account */
+        /** The incinerator account, or an allowed refund recipient */
         incinerator: TAccountMetas[1];
         /** The system program */
         systemProgram: TAccountMetas[2];
+        /** The audit log account */
+        auditLog?: TAccountMetas[3] | undefined;
     };
     data: RevokePendingActivationInstructionData;
 };
@@ -163,10 +180,10 @@ export function parseRevokePendingActivationInstruction<
         InstructionWithAccounts<TAccountMetas> &
         InstructionWithData<ReadonlyUint8Array>,
 ): ParsedRevokePendingActivationInstruction<TProgram, TAccountMetas> {
-    if (instruction.accounts.length < 3) {
+    if (instruction.accounts.length < 4) {
         throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__INSUFFICIENT_ACCOUNT_METAS, {
             actualAccountMetas: instruction.accounts.length,
-            expectedAccountMetas: 3,
+            expectedAccountMetas: 4,
         });
     }
     let accountIndex = 0;
@@ -175,9 +192,18 @@ export function parseRevokePendingActivationInstruction<
         accountIndex += 1;
         return accountMeta;
     };
+    const getNextOptionalAccount = () => {
+        const accountMeta = getNextAccount();
+        return accountMeta.address === FEATURE_GATE_PROGRAM_ADDRESS ? undefined : accountMeta;
+    };
     return {
         programAddress: instruction.programAddress,
-        accounts: { feature: getNextAccount(), incinerator: getNextAccount(), systemProgram: getNextAccount() },
+        accounts: {
+            feature: getNextAccount(),
+            incinerator: getNextAccount(),
+            systemProgram: getNextAccount(),
+            auditLog: getNextOptionalAccount(),
+        },
         data: getRevokePendingActivationInstructionDataDecoder().decode(instruction.data),
     };
 }
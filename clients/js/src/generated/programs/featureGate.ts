@@ -24,10 +24,62 @@ import {
 } from '@solana/kit';
 import { addSelfPlanAndSendFunctions, type SelfPlanAndSendFunctions } from '@solana/kit/program-client-core';
 import {
+    getExtendAuditLogInstruction,
+    getInitializeAuditLogInstruction,
+    getInitializeBundleInstruction,
+    getInitializeConfigInstruction,
+    getInitializeStakeGuardInstruction,
+    getQueueBundleInstruction,
+    getReleaseScheduledActivationInstruction,
+    getRevokeBundleInstruction,
     getRevokePendingActivationInstruction,
+    getRevokeUnsupportedActivationInstruction,
+    getScheduleActivationInstruction,
+    getSignalSupportInstruction,
+    getTallySupportInstruction,
+    getUpdateConfigInstruction,
+    parseExtendAuditLogInstruction,
+    parseInitializeAuditLogInstruction,
+    parseInitializeBundleInstruction,
+    parseInitializeConfigInstruction,
+    parseInitializeStakeGuardInstruction,
+    parseQueueBundleInstruction,
+    parseReleaseScheduledActivationInstruction,
+    parseRevokeBundleInstruction,
     parseRevokePendingActivationInstruction,
+    parseRevokeUnsupportedActivationInstruction,
+    parseScheduleActivationInstruction,
+    parseSignalSupportInstruction,
+    parseTallySupportInstruction,
+    parseUpdateConfigInstruction,
+    type ExtendAuditLogInput,
+    type InitializeAuditLogInput,
+    type InitializeBundleInput,
+    type InitializeConfigInput,
+    type InitializeStakeGuardInput,
+    type ParsedExtendAuditLogInstruction,
+    type ParsedInitializeAuditLogInstruction,
+    type ParsedInitializeBundleInstruction,
+    type ParsedInitializeConfigInstruction,
+    type ParsedInitializeStakeGuardInstruction,
+    type ParsedQueueBundleInstruction,
+    type ParsedReleaseScheduledActivationInstruction,
+    type ParsedRevokeBundleInstruction,
     type ParsedRevokePendingActivationInstruction,
+    type ParsedRevokeUnsupportedActivationInstruction,
+    type ParsedScheduleActivationInstruction,
+    type ParsedSignalSupportInstruction,
+    type ParsedTallySupportInstruction,
+    type ParsedUpdateConfigInstruction,
+    type QueueBundleInput,
+    type ReleaseScheduledActivationInput,
+    type RevokeBundleInput,
     type RevokePendingActivationInput,
+    type RevokeUnsupportedActivationInput,
+    type ScheduleActivationInput,
+    type SignalSupportInput,
+    type TallySupportInput,
+    type UpdateConfigInput,
 } from '../instructions';
 
 export const FEATURE_GATE_PROGRAM_ADDRESS =
@@ -35,6 +87,19 @@ export const FEATURE_GATE_PROGRAM_ADDRESS =
 
 export enum FeatureGateInstruction {
     RevokePendingActivation,
+    SignalSupport,
+    InitializeStakeGuard,
+    TallySupport,
+    RevokeUnsupportedActivation,
+    InitializeConfig,
+    UpdateConfig,
+    ScheduleActivation,
+    ReleaseScheduledActivation,
+    InitializeAuditLog,
+    ExtendAuditLog,
+    InitializeBundle,
+    QueueBundle,
+    RevokeBundle,
 }
 
 export function identifyFeatureGateInstruction(
@@ -44,15 +109,94 @@ export function identifyFeatureGateInstruction(
     if (containsBytes(data, getU8Encoder().encode(0), 0)) {
         return FeatureGateInstruction.RevokePendingActivation;
     }
+    if (containsBytes(data, getU8Encoder().encode(1), 0)) {
+        return FeatureGateInstruction.SignalSupport;
+    }
+    if (containsBytes(data, getU8Encoder().encode(2), 0)) {
+        return FeatureGateInstruction.InitializeStakeGuard;
+    }
+    if (containsBytes(data, getU8Encoder().encode(3), 0)) {
+        return FeatureGateInstruction.TallySupport;
+    }
+    if (containsBytes(data, getU8Encoder().encode(4), 0)) {
+        return FeatureGateInstruction.RevokeUnsupportedActivation;
+    }
+    if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+        return FeatureGateInstruction.InitializeConfig;
+    }
+    if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+        return FeatureGateInstruction.UpdateConfig;
+    }
+    if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+        return FeatureGateInstruction.ScheduleActivation;
+    }
+    if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+        return FeatureGateInstruction.ReleaseScheduledActivation;
+    }
+    if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+        return FeatureGateInstruction.InitializeAuditLog;
+    }
+    if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+        return FeatureGateInstruction.ExtendAuditLog;
+    }
+    if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+        return FeatureGateInstruction.InitializeBundle;
+    }
+    if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+        return FeatureGateInstruction.QueueBundle;
+    }
+    if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+        return FeatureGateInstruction.RevokeBundle;
+    }
     throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__FAILED_TO_IDENTIFY_INSTRUCTION, {
         instructionData: data,
         programName: 'featureGate',
     });
 }
 
-export type ParsedFeatureGateInstruction<TProgram extends string = 'Feature111111111111111111111111111111111111'> = {
-    instructionType: FeatureGateInstruction.RevokePendingActivation;
-} & ParsedRevokePendingActivationInstruction<TProgram>;
+export type ParsedFeatureGateInstruction<TProgram extends string = 'Feature111111111111111111111111111111111111'> =
+    | ({
+          instructionType: FeatureGateInstruction.RevokePendingActivation;
+      } & ParsedRevokePendingActivationInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.SignalSupport;
+      } & ParsedSignalSupportInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.InitializeStakeGuard;
+      } & ParsedInitializeStakeGuardInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.TallySupport;
+      } & ParsedTallySupportInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.RevokeUnsupportedActivation;
+      } & ParsedRevokeUnsupportedActivationInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.InitializeConfig;
+      } & ParsedInitializeConfigInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.UpdateConfig;
+      } & ParsedUpdateConfigInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.ScheduleActivation;
+      } & ParsedScheduleActivationInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.ReleaseScheduledActivation;
+      } & ParsedReleaseScheduledActivationInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.InitializeAuditLog;
+      } & ParsedInitializeAuditLogInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.ExtendAuditLog;
+      } & ParsedExtendAuditLogInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.InitializeBundle;
+      } & ParsedInitializeBundleInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.QueueBundle;
+      } & ParsedQueueBundleInstruction<TProgram>)
+    | ({
+          instructionType: FeatureGateInstruction.RevokeBundle;
+      } & ParsedRevokeBundleInstruction<TProgram>);
 
 export function parseFeatureGateInstruction<TProgram extends string>(
     instruction: Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array>,
@@ -66,6 +210,97 @@ export function parseFeatureGateInstruction<TProgram extends string>(
                 ...parseRevokePendingActivationInstruction(instruction),
             };
         }
+        case FeatureGateInstruction.SignalSupport: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.SignalSupport,
+                ...parseSignalSupportInstruction(instruction),
+            };
+        }
+        case FeatureGateInstruction.InitializeStakeGuard: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.InitializeStakeGuard,
+                ...parseInitializeStakeGuardInstruction(instruction),
+            };
+        }
+        case FeatureGateInstruction.TallySupport: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.TallySupport,
+                ...parseTallySupportInstruction(instruction),
+            };
+        }
+        case FeatureGateInstruction.RevokeUnsupportedActivation: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.RevokeUnsupportedActivation,
+                ...parseRevokeUnsupportedActivationInstruction(instruction),
+            };
+        }
+        case FeatureGateInstruction.InitializeConfig: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.InitializeConfig,
+                ...parseInitializeConfigInstruction(instruction),
+            };
+        }
+        case FeatureGateInstruction.UpdateConfig: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.UpdateConfig,
+                ...parseUpdateConfigInstruction(instruction),
+            };
+        }
+        case FeatureGateInstruction.ScheduleActivation: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.ScheduleActivation,
+                ...parseScheduleActivationInstruction(instruction),
+            };
+        }
+        case FeatureGateInstruction.ReleaseScheduledActivation: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.ReleaseScheduledActivation,
+                ...parseReleaseScheduledActivationInstruction(instruction),
+            };
+        }
+        case FeatureGateInstruction.InitializeAuditLog: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.InitializeAuditLog,
+                ...parseInitializeAuditLogInstruction(instruction),
+            };
+        }
+        case FeatureGateInstruction.ExtendAuditLog: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.ExtendAuditLog,
+                ...parseExtendAuditLogInstruction(instruction),
+            };
+        }
+        case FeatureGateInstruction.InitializeBundle: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.InitializeBundle,
+                ...parseInitializeBundleInstruction(instruction),
+            };
+        }
+        case FeatureGateInstruction.QueueBundle: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.QueueBundle,
+                ...parseQueueBundleInstruction(instruction),
+            };
+        }
+        case FeatureGateInstruction.RevokeBundle: {
+            assertIsInstructionWithAccounts(instruction);
+            return {
+                instructionType: FeatureGateInstruction.RevokeBundle,
+                ...parseRevokeBundleInstruction(instruction),
+            };
+        }
         default:
             throw new SolanaError(SOLANA_ERROR__PROGRAM_CLIENTS__UNRECOGNIZED_INSTRUCTION_TYPE, {
                 instructionType: instructionType as string,
@@ -84,6 +319,43 @@ export type FeatureGatePluginInstructions = {
     revokePendingActivation: (
         input: RevokePendingActivationInput,
     ) => ReturnType<typeof getRevokePendingActivationInstruction> & SelfPlanAndSendFunctions;
+    signalSupport: (
+        input: SignalSupportInput,
+    ) => ReturnType<typeof getSignalSupportInstruction> & SelfPlanAndSendFunctions;
+    initializeStakeGuard: (
+        input: InitializeStakeGuardInput,
+    ) => ReturnType<typeof getInitializeStakeGuardInstruction> & SelfPlanAndSendFunctions;
+    tallySupport: (
+        input: TallySupportInput,
+    ) => ReturnType<typeof getTallySupportInstruction> & SelfPlanAndSendFunctions;
+    revokeUnsupportedActivation: (
+        input: RevokeUnsupportedActivationInput,
+    ) => ReturnType<typeof getRevokeUnsupportedActivationInstruction> & SelfPlanAndSendFunctions;
+    initializeConfig: (
+        input: InitializeConfigInput,
+    ) => ReturnType<typeof getInitializeConfigInstruction> & SelfPlanAndSendFunctions;
+    updateConfig: (
+        input: UpdateConfigInput,
+    ) => ReturnType<typeof getUpdateConfigInstruction> & SelfPlanAndSendFunctions;
+    scheduleActivation: (
+        input: ScheduleActivationInput,
+    ) => ReturnType<typeof getScheduleActivationInstruction> & SelfPlanAndSendFunctions;
+    releaseScheduledActivation: (
+        input: ReleaseScheduledActivationInput,
+    ) => ReturnType<typeof getReleaseScheduledActivationInstruction> & SelfPlanAndSendFunctions;
+    initializeAuditLog: (
+        input: InitializeAuditLogInput,
+    ) => ReturnType<typeof getInitializeAuditLogInstruction> & SelfPlanAndSendFunctions;
+    extendAuditLog: (
+        input: ExtendAuditLogInput,
+    ) => ReturnType<typeof getExtendAuditLogInstruction> & SelfPlanAndSendFunctions;
+    initializeBundle: (
+        input: InitializeBundleInput,
+    ) => ReturnType<typeof getInitializeBundleInstruction> & SelfPlanAndSendFunctions;
+    queueBundle: (input: QueueBundleInput) => ReturnType<typeof getQueueBundleInstruction> & SelfPlanAndSendFunctions;
+    revokeBundle: (
+        input: RevokeBundleInput,
+    ) => ReturnType<typeof getRevokeBundleInstruction> & SelfPlanAndSendFunctions;
 };
 
 export type FeatureGatePluginRequirements = ClientWithTransactionPlanning & ClientWithTransactionSending;
@@ -97,6 +369,26 @@ export function featureGateProgram() {
                 instructions: {
                     revokePendingActivation: input =>
                         addSelfPlanAndSendFunctions(client, getRevokePendingActivationInstruction(input)),
+                    signalSupport: input => addSelfPlanAndSendFunctions(client, getSignalSupportInstruction(input)),
+                    initializeStakeGuard: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeStakeGuardInstruction(input)),
+                    tallySupport: input => addSelfPlanAndSendFunctions(client, getTallySupportInstruction(input)),
+                    revokeUnsupportedActivation: input =>
+                        addSelfPlanAndSendFunctions(client, getRevokeUnsupportedActivationInstruction(input)),
+                    initializeConfig: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeConfigInstruction(input)),
+                    updateConfig: input => addSelfPlanAndSendFunctions(client, getUpdateConfigInstruction(input)),
+                    scheduleActivation: input =>
+                        addSelfPlanAndSendFunctions(client, getScheduleActivationInstruction(input)),
+                    releaseScheduledActivation: input =>
+                        addSelfPlanAndSendFunctions(client, getReleaseScheduledActivationInstruction(input)),
+                    initializeAuditLog: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeAuditLogInstruction(input)),
+                    extendAuditLog: input => addSelfPlanAndSendFunctions(client, getExtendAuditLogInstruction(input)),
+                    initializeBundle: input =>
+                        addSelfPlanAndSendFunctions(client, getInitializeBundleInstruction(input)),
+                    queueBundle: input => addSelfPlanAndSendFunctions(client, getQueueBundleInstruction(input)),
+                    revokeBundle: input => addSelfPlanAndSendFunctions(client, getRevokeBundleInstruction(input)),
                 },
                 identifyInstruction: identifyFeatureGateInstruction,
                 parseInstruction: parseFeatureGateInstruction,
@@ -0,0 +1,57 @@
+//! Generates the built-in known feature table from
+//! `data/known_features.toml`.
+
+use {
+    serde::Deserialize,
+    std::{env, fmt::Write, fs, path::Path},
+};
+
+const DATA_FILE: &str = "data/known_features.toml";
+
+#[derive(Deserialize)]
+struct KnownFeatureFile {
+    feature: Vec<KnownFeatureEntry>,
+}
+
+#[derive(Deserialize)]
+struct KnownFeatureEntry {
+    id: String,
+    name: String,
+    description: String,
+    #[serde(default)]
+    simd: Vec<u16>,
+    introduced_in: Option<String>,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={DATA_FILE}");
+
+    let data = fs::read_to_string(DATA_FILE).expect("failed to read known feature data");
+    let file: KnownFeatureFile = toml::from_str(&data).expect("invalid known feature data");
+
+    let mut generated = String::from("static BUILTIN_KNOWN_FEATURES: &[KnownFeature] = &[\n");
+    for entry in file.feature {
+        let simd = entry
+            .simd
+            .iter()
+            .map(u16::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let introduced_in = match entry.introduced_in {
+            Some(version) => format!("Some(Cow::Borrowed({version:?}))"),
+            None => "None".to_string(),
+        };
+        writeln!(
+            generated,
+            "    KnownFeature {{ id: solana_address::address!({:?}), name: Cow::Borrowed({:?}), \
+             description: Cow::Borrowed({:?}), simd: Cow::Borrowed(&[{simd}]), introduced_in: \
+             {introduced_in} }},",
+            entry.id, entry.name, entry.description,
+        )
+        .unwrap();
+    }
+    generated.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("known_features.rs"), generated).unwrap();
+}
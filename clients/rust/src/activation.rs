@@ -0,0 +1,213 @@
+//! Activation time prediction for queued features.
+//!
+//! The runtime activates every pending feature at the first slot of the next
+//! epoch. Until that boundary, the feature can still be revoked. Given the
+//! cluster's epoch schedule and current slot, this module computes the
+//! activation boundary, an estimated wall-clock time for it, and how long the
+//! revocation window remains open.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use {
+    solana_clock::{Epoch, Slot, UnixTimestamp, DEFAULT_MS_PER_SLOT},
+    solana_epoch_schedule::EpochSchedule,
+    solana_feature_gate_interface::Feature,
+    std::time::Duration,
+};
+
+/// Configuration for wall-clock estimates.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ActivationEstimateConfig {
+    /// The expected duration of a single slot.
+    pub slot_duration: Duration,
+}
+
+impl Default for ActivationEstimateConfig {
+    fn default() -> Self {
+        Self {
+            slot_duration: Duration::from_millis(DEFAULT_MS_PER_SLOT),
+        }
+    }
+}
+
+impl ActivationEstimateConfig {
+    /// Estimates the time it takes for `slots` slots to elapse.
+    pub fn duration_of(&self, slots: u64) -> Duration {
+        let millis = u64::try_from(self.slot_duration.as_millis()).unwrap_or(u64::MAX);
+        Duration::from_millis(millis.saturating_mul(slots))
+    }
+}
+
+/// A pending feature's predicted activation.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingActivation {
+    /// The epoch containing the current slot.
+    pub current_epoch: Epoch,
+    /// The epoch at whose boundary the feature activates.
+    pub activation_epoch: Epoch,
+    /// The first slot of the activation epoch.
+    pub activation_slot: Slot,
+    /// The last slot in which a revocation can land.
+    pub revocation_deadline_slot: Slot,
+    /// The number of slots until the activation boundary.
+    pub slots_until_activation: u64,
+    /// Estimated time until the activation boundary. This is also how long
+    /// the revocation window remains open.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DurationSeconds<u64>>")
+    )]
+    pub time_until_activation: Duration,
+    /// Estimated Unix timestamp of the activation boundary.
+    pub estimated_activation_timestamp: UnixTimestamp,
+}
+
+/// The predicted activation state of a feature.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase", tag = "status")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ActivationPrediction {
+    /// The feature has already been activated.
+    Active {
+        /// The slot at which the feature was activated.
+        activated_at: Slot,
+        /// The epoch at which the feature was activated.
+        activation_epoch: Epoch,
+    },
+    /// The feature is pending activation.
+    Pending(PendingActivation),
+}
+
+/// Predicts when a feature activates.
+///
+/// `now` is the current Unix timestamp, used as the base for wall-clock
+/// estimates.
+pub fn predict_activation(
+    feature: &Feature,
+    epoch_schedule: &EpochSchedule,
+    current_slot: Slot,
+    now: UnixTimestamp,
+    config: &ActivationEstimateConfig,
+) -> ActivationPrediction {
+    if let Some(activated_at) = feature.activated_at {
+        return ActivationPrediction::Active {
+            activated_at,
+            activation_epoch: epoch_schedule.get_epoch(activated_at),
+        };
+    }
+
+    let current_epoch = epoch_schedule.get_epoch(current_slot);
+    let activation_epoch = current_epoch.saturating_add(1);
+    let activation_slot = epoch_schedule.get_first_slot_in_epoch(activation_epoch);
+    let slots_until_activation = activation_slot.saturating_sub(current_slot);
+    let time_until_activation = config.duration_of(slots_until_activation);
+    let estimated_activation_timestamp = now.saturating_add(
+        UnixTimestamp::try_from(time_until_activation.as_secs()).unwrap_or(UnixTimestamp::MAX),
+    );
+
+    ActivationPrediction::Pending(PendingActivation {
+        current_epoch,
+        activation_epoch,
+        activation_slot,
+        revocation_deadline_slot: activation_slot.saturating_sub(1),
+        slots_until_activation,
+        time_until_activation,
+        estimated_activation_timestamp,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const NOW: UnixTimestamp = 1_700_000_000;
+
+    #[test]
+    fn test_predict_pending() {
+        let epoch_schedule = EpochSchedule::without_warmup();
+        let slots_per_epoch = epoch_schedule.slots_per_epoch;
+        let current_slot = slots_per_epoch.saturating_mul(10).saturating_add(1_000);
+
+        let prediction = predict_activation(
+            &Feature::default(),
+            &epoch_schedule,
+            current_slot,
+            NOW,
+            &ActivationEstimateConfig::default(),
+        );
+
+        let activation_slot = slots_per_epoch.saturating_mul(11);
+        let slots_until_activation = slots_per_epoch.saturating_sub(1_000);
+        let seconds = slots_until_activation
+            .saturating_mul(DEFAULT_MS_PER_SLOT)
+            .saturating_div(1_000);
+        assert_eq!(
+            prediction,
+            ActivationPrediction::Pending(PendingActivation {
+                current_epoch: 10,
+                activation_epoch: 11,
+                activation_slot,
+                revocation_deadline_slot: activation_slot.saturating_sub(1),
+                slots_until_activation,
+                time_until_activation: Duration::from_secs(seconds),
+                estimated_activation_timestamp: NOW
+                    .saturating_add(UnixTimestamp::try_from(seconds).unwrap()),
+            })
+        );
+    }
+
+    #[test]
+    fn test_predict_pending_with_warmup() {
+        // With warmup, epoch 0 spans the first 32 slots.
+        let epoch_schedule = EpochSchedule::default();
+        let config = ActivationEstimateConfig {
+            slot_duration: Duration::from_secs(1),
+        };
+
+        let ActivationPrediction::Pending(pending) =
+            predict_activation(&Feature::default(), &epoch_schedule, 10, NOW, &config)
+        else {
+            panic!("expected a pending prediction");
+        };
+        assert_eq!(pending.current_epoch, 0);
+        assert_eq!(pending.activation_epoch, 1);
+        assert_eq!(pending.activation_slot, 32);
+        assert_eq!(pending.revocation_deadline_slot, 31);
+        assert_eq!(pending.slots_until_activation, 22);
+        assert_eq!(pending.time_until_activation, Duration::from_secs(22));
+        assert_eq!(
+            pending.estimated_activation_timestamp,
+            NOW.saturating_add(22)
+        );
+    }
+
+    #[test]
+    fn test_predict_active() {
+        let epoch_schedule = EpochSchedule::without_warmup();
+        let activated_at = epoch_schedule.slots_per_epoch.saturating_mul(3);
+
+        assert_eq!(
+            predict_activation(
+                &Feature {
+                    activated_at: Some(activated_at),
+                },
+                &epoch_schedule,
+                activated_at.saturating_add(5),
+                NOW,
+                &ActivationEstimateConfig::default(),
+            ),
+            ActivationPrediction::Active {
+                activated_at,
+                activation_epoch: 3,
+            }
+        );
+    }
+}
@@ -0,0 +1,377 @@
+//! Decoding of the program's audit log.
+//!
+//! Instructions that queue or revoke features or change the program config
+//! record an entry in the audit log when it is passed to them. The log is a
+//! ring buffer in a singleton account derived from [`AUDIT_LOG_SEED`]: a
+//! header followed by fixed-size entries, the oldest of which is overwritten
+//! once the log is full. Entries carry a sequence number, so readers can page
+//! through the log from newest to oldest and tell when entries were lost.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use solana_feature_gate_program_interface::state::{
+    find_audit_log_address, AUDIT_LOG_ADDRESS, AUDIT_LOG_SEED, AUDIT_LOG_TAG,
+};
+#[cfg(feature = "fetch")]
+use solana_rpc_client::{api::client_error::Error as ClientError, rpc_client::RpcClient};
+use {
+    crate::instructions::{ExtendAuditLogBuilder, InitializeAuditLogBuilder},
+    solana_address::Address,
+    solana_clock::Slot,
+    solana_feature_gate_program_interface::state,
+    solana_instruction::Instruction,
+    thiserror::Error,
+};
+
+/// The size of the audit log header, including its discriminator.
+pub const AUDIT_LOG_HEADER_LEN: usize = state::AuditLog::LEN;
+
+/// The size of a single audit log entry.
+pub const AUDIT_ENTRY_LEN: usize = state::AuditEntry::LEN;
+
+/// Errors that can occur while reading the audit log.
+#[derive(Debug, Error)]
+pub enum AuditLogError {
+    /// The account is not an audit log, or its header is inconsistent with
+    /// the account size.
+    #[error("Invalid audit log header")]
+    InvalidHeader,
+    /// An entry holds an action this client does not recognize.
+    #[error("Unknown audit action {0}")]
+    InvalidAction(u8),
+    /// The RPC request failed.
+    #[cfg(feature = "fetch")]
+    #[error("RPC error: {0}")]
+    Rpc(#[from] Box<ClientError>),
+}
+
+/// The program action recorded by an [`AuditEntry`].
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AuditAction {
+    /// A feature was queued with `ScheduleActivation`.
+    ScheduleActivation,
+    /// A feature was revoked with `RevokePendingActivation` or as a member of
+    /// a bundle with `RevokeBundle`.
+    RevokePendingActivation,
+    /// A feature was revoked by its stake guard.
+    RevokeUnsupportedActivation,
+    /// The program config was initialized.
+    InitializeConfig,
+    /// The program config, possibly including its authority, was replaced.
+    UpdateConfig,
+    /// A feature was queued as a member of a bundle with `QueueBundle`.
+    QueueBundleMember,
+}
+
+impl TryFrom<u8> for AuditAction {
+    type Error = AuditLogError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::ScheduleActivation),
+            1 => Ok(Self::RevokePendingActivation),
+            2 => Ok(Self::RevokeUnsupportedActivation),
+            3 => Ok(Self::InitializeConfig),
+            4 => Ok(Self::UpdateConfig),
+            5 => Ok(Self::QueueBundleMember),
+            _ => Err(AuditLogError::InvalidAction(value)),
+        }
+    }
+}
+
+/// A single record in the audit log.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditEntry {
+    /// The position of the entry among all entries ever recorded.
+    pub sequence: u64,
+    /// The recorded action.
+    pub action: AuditAction,
+    /// The feature acted on or, for config changes, the config authority
+    /// after the change.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub subject: Address,
+    /// The account that authorized the action, or `None` for permissionless
+    /// actions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Option<serde_with::DisplayFromStr>>")
+    )]
+    pub signer: Option<Address>,
+    /// The slot in which the action took place.
+    pub slot: Slot,
+}
+
+impl AuditEntry {
+    fn from_bytes(sequence: u64, data: &[u8; AUDIT_ENTRY_LEN]) -> Result<Self, AuditLogError> {
+        let (action, rest) = data.split_at(1);
+        let (subject, rest) = rest.split_at(32);
+        let (signer, slot) = rest.split_at(32);
+        let signer = Address::try_from(signer).unwrap();
+        Ok(Self {
+            sequence,
+            action: action[0].try_into()?,
+            subject: Address::try_from(subject).unwrap(),
+            signer: (signer != Address::default()).then_some(signer),
+            slot: u64::from_le_bytes(slot.try_into().unwrap()),
+        })
+    }
+}
+
+/// The decoded audit log.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditLog {
+    /// The number of entries the log holds before wrapping.
+    pub capacity: usize,
+    /// The sequence number of the next entry, i.e. the number of entries
+    /// ever recorded.
+    pub next_sequence: u64,
+    /// The retained entries, oldest first.
+    pub entries: Vec<AuditEntry>,
+}
+
+/// A page of audit log entries, newest first.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditLogPage {
+    /// The entries on this page, newest first.
+    pub entries: Vec<AuditEntry>,
+    /// The `before` argument for the next page, or `None` if this page holds
+    /// the oldest retained entry.
+    pub next_before: Option<u64>,
+}
+
+impl AuditLog {
+    /// Decodes the audit log from account data.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, AuditLogError> {
+        let (header, entries) = data
+            .split_first_chunk::<AUDIT_LOG_HEADER_LEN>()
+            .ok_or(AuditLogError::InvalidHeader)?;
+        if header[0] != AUDIT_LOG_TAG {
+            return Err(AuditLogError::InvalidHeader);
+        }
+        let next_sequence = u64::from_le_bytes(header[1..9].try_into().unwrap());
+        let head = u32::from_le_bytes(header[9..13].try_into().unwrap()) as usize;
+        let len = u32::from_le_bytes(header[13..].try_into().unwrap()) as usize;
+
+        let (entries, remainder) = entries.as_chunks::<AUDIT_ENTRY_LEN>();
+        let capacity = entries.len();
+        if !remainder.is_empty() || head >= capacity || len > capacity || len as u64 > next_sequence
+        {
+            return Err(AuditLogError::InvalidHeader);
+        }
+
+        // The retained entries run from the tail up to the head, wrapping
+        // around the end of the buffer.
+        let tail = head.saturating_add(capacity).saturating_sub(len);
+        let first_sequence = next_sequence.saturating_sub(len as u64);
+        let entries = entries
+            .iter()
+            .cycle()
+            .skip(tail)
+            .take(len)
+            .zip(first_sequence..)
+            .map(|(data, sequence)| AuditEntry::from_bytes(sequence, data))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            capacity,
+            next_sequence,
+            entries,
+        })
+    }
+
+    /// Fetches the audit log, or `None` if it has not been created.
+    #[cfg(feature = "fetch")]
+    pub fn fetch(rpc_client: &RpcClient) -> Result<Option<Self>, AuditLogError> {
+        let response = rpc_client
+            .get_account_with_commitment(&find_audit_log_address().0, rpc_client.commitment())
+            .map_err(Box::new)?;
+        match response.value {
+            Some(account) if account.owner == crate::ID => {
+                Self::from_bytes(&account.data).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// The number of entries overwritten since the log was created.
+    pub fn evicted(&self) -> u64 {
+        self.next_sequence.saturating_sub(self.entries.len() as u64)
+    }
+
+    /// Returns up to `limit` entries with a sequence number below `before`,
+    /// newest first. Pass `None` to start from the newest entry.
+    pub fn page(&self, before: Option<u64>, limit: usize) -> AuditLogPage {
+        let end = self
+            .entries
+            .partition_point(|entry| before.is_none_or(|before| entry.sequence < before));
+        let start = end.saturating_sub(limit);
+        AuditLogPage {
+            entries: self.entries[start..end].iter().rev().cloned().collect(),
+            next_before: (start > 0).then(|| self.entries[start].sequence),
+        }
+    }
+}
+
+/// Builds an `InitializeAuditLog` instruction creating the audit log with
+/// room for `capacity` entries.
+pub fn initialize_instruction(payer: &Address, capacity: u32) -> Instruction {
+    InitializeAuditLogBuilder::new()
+        .audit_log(find_audit_log_address().0)
+        .payer(*payer)
+        .capacity(capacity)
+        .instruction()
+}
+
+/// Builds an `ExtendAuditLog` instruction adding room for
+/// `additional_entries` entries.
+pub fn extend_instruction(payer: &Address, additional_entries: u32) -> Instruction {
+    ExtendAuditLogBuilder::new()
+        .audit_log(find_audit_log_address().0)
+        .payer(*payer)
+        .additional_entries(additional_entries)
+        .instruction()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Builds the account data of a log with `capacity` entries, the newest
+    /// `len` of `next_sequence` entries written with their sequence number
+    /// as the slot.
+    fn log_data(capacity: usize, next_sequence: u64, len: usize, head: usize) -> Vec<u8> {
+        let mut data = vec![AUDIT_LOG_TAG];
+        data.extend_from_slice(&next_sequence.to_le_bytes());
+        data.extend_from_slice(&u32::try_from(head).unwrap().to_le_bytes());
+        data.extend_from_slice(&u32::try_from(len).unwrap().to_le_bytes());
+        data.resize(
+            AUDIT_LOG_HEADER_LEN.saturating_add(capacity.saturating_mul(AUDIT_ENTRY_LEN)),
+            0,
+        );
+        let tail = head
+            .saturating_add(capacity)
+            .saturating_sub(len)
+            .checked_rem(capacity)
+            .unwrap();
+        for i in 0..len {
+            let sequence = next_sequence
+                .saturating_sub(len as u64)
+                .saturating_add(i as u64);
+            let offset = AUDIT_LOG_HEADER_LEN.saturating_add(
+                tail.saturating_add(i)
+                    .checked_rem(capacity)
+                    .unwrap()
+                    .saturating_mul(AUDIT_ENTRY_LEN),
+            );
+            data[offset] = 1;
+            data[offset.saturating_add(1)..offset.saturating_add(33)].fill(7);
+            data[offset.saturating_add(65)..offset.saturating_add(AUDIT_ENTRY_LEN)]
+                .copy_from_slice(&sequence.to_le_bytes());
+        }
+        data
+    }
+
+    fn sequences(entries: &[AuditEntry]) -> Vec<u64> {
+        entries.iter().map(|entry| entry.sequence).collect()
+    }
+
+    #[test]
+    fn test_from_bytes_wrapped() {
+        // Seven entries in a ring of four: 3, 4, 5 and 6 remain, and the
+        // next entry overwrites 3 at index 3.
+        let log = AuditLog::from_bytes(&log_data(4, 7, 4, 3)).unwrap();
+        assert_eq!(log.capacity, 4);
+        assert_eq!(log.evicted(), 3);
+        assert_eq!(sequences(&log.entries), vec![3, 4, 5, 6]);
+        assert!(log.entries.iter().all(|entry| entry.slot == entry.sequence));
+        assert_eq!(log.entries[0].action, AuditAction::RevokePendingActivation);
+        assert_eq!(log.entries[0].subject, Address::new_from_array([7; 32]));
+        assert_eq!(log.entries[0].signer, None);
+    }
+
+    #[test]
+    fn test_from_bytes_invalid() {
+        assert!(matches!(
+            AuditLog::from_bytes(&log_data(4, 2, 3, 3)),
+            Err(AuditLogError::InvalidHeader)
+        ));
+        assert!(matches!(
+            AuditLog::from_bytes(&log_data(4, 7, 4, 4)),
+            Err(AuditLogError::InvalidHeader)
+        ));
+        let mut data = log_data(4, 7, 4, 3);
+        data.push(0);
+        assert!(matches!(
+            AuditLog::from_bytes(&data),
+            Err(AuditLogError::InvalidHeader)
+        ));
+        let mut data = log_data(4, 7, 4, 3);
+        data[0] = 0;
+        assert!(matches!(
+            AuditLog::from_bytes(&data),
+            Err(AuditLogError::InvalidHeader)
+        ));
+        let mut data = log_data(4, 1, 1, 1);
+        data[AUDIT_LOG_HEADER_LEN] = 9;
+        assert!(matches!(
+            AuditLog::from_bytes(&data),
+            Err(AuditLogError::InvalidAction(9))
+        ));
+    }
+
+    #[test]
+    fn test_page() {
+        let log = AuditLog::from_bytes(&log_data(5, 12, 5, 2)).unwrap();
+        assert_eq!(sequences(&log.entries), vec![7, 8, 9, 10, 11]);
+
+        let first = log.page(None, 2);
+        assert_eq!(sequences(&first.entries), vec![11, 10]);
+        assert_eq!(first.next_before, Some(10));
+        let second = log.page(first.next_before, 2);
+        assert_eq!(sequences(&second.entries), vec![9, 8]);
+        let last = log.page(second.next_before, 2);
+        assert_eq!(sequences(&last.entries), vec![7]);
+        assert_eq!(last.next_before, None);
+
+        // Paging from a sequence that has since been evicted is empty.
+        assert_eq!(
+            log.page(Some(3), 2),
+            AuditLogPage {
+                entries: Vec::new(),
+                next_before: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_instructions() {
+        let payer = Address::new_unique();
+        let initialize = initialize_instruction(&payer, 64);
+        assert_eq!(initialize.accounts[0].pubkey, find_audit_log_address().0);
+        assert_eq!(initialize.data, [&[9][..], &64u32.to_le_bytes()].concat());
+        let extend = extend_instruction(&payer, 8);
+        assert_eq!(extend.data, [&[10][..], &8u32.to_le_bytes()].concat());
+    }
+}
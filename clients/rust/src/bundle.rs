@@ -0,0 +1,501 @@
+//! Feature bundles.
+//!
+//! Large changes often ship as several features that must activate together.
+//! A bundle is an account derived from its authority and name that lists up
+//! to [`MAX_BUNDLE_MEMBERS`] member features. `QueueBundle` queues every
+//! member in one instruction, so the runtime activates them at the same
+//! epoch boundary, and `RevokeBundle` revokes them all. Both fail if any
+//! member is already active.
+//!
+//! This module looks bundles up, aggregates the status of their members and
+//! builds the bundle instructions.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use solana_feature_gate_program_interface::state::{
+    BUNDLE_SEED, BUNDLE_TAG, MAX_BUNDLE_MEMBERS,
+};
+#[cfg(feature = "fetch")]
+use solana_rpc_client::{api::client_error::Error as ClientError, rpc_client::RpcClient};
+use {
+    crate::{
+        accounts::Bundle,
+        audit_log::find_audit_log_address,
+        instructions::{InitializeBundleBuilder, QueueBundleBuilder, RevokeBundleBuilder},
+        schedule::ScheduledActivation,
+    },
+    solana_account::Account,
+    solana_address::Address,
+    solana_clock::{Epoch, Slot},
+    solana_feature_gate_interface::{from_account, Feature},
+    solana_feature_gate_program_interface::state,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_sdk_ids::incinerator,
+    thiserror::Error,
+};
+
+/// The maximum length of a bundle name, in bytes.
+pub const MAX_BUNDLE_NAME_LEN: usize = 32;
+
+/// Derives the address of the bundle named `name` created by `authority`.
+pub fn find_bundle_address(authority: &Address, name: &str) -> Result<(Address, u8), BundleError> {
+    Ok(state::find_bundle_address(authority, &encode_name(name)?))
+}
+
+/// Errors that can occur while reading or building a bundle.
+#[derive(Debug, Error)]
+pub enum BundleError {
+    /// The bundle name is empty or longer than [`MAX_BUNDLE_NAME_LEN`] bytes.
+    #[error("Bundle names must be 1 to {MAX_BUNDLE_NAME_LEN} bytes long, found {0}")]
+    InvalidNameLength(usize),
+    /// The bundle has no members or more than [`MAX_BUNDLE_MEMBERS`].
+    #[error("Bundles must have 1 to {MAX_BUNDLE_MEMBERS} members, found {0}")]
+    InvalidMemberCount(usize),
+    /// A feature is listed more than once.
+    #[error("Feature {0} is listed more than once")]
+    DuplicateMember(Address),
+    /// The bundle account data could not be decoded.
+    #[error("Invalid bundle account data: {0}")]
+    InvalidAccountData(#[from] std::io::Error),
+    /// The RPC request failed.
+    #[cfg(feature = "fetch")]
+    #[error("RPC error: {0}")]
+    Rpc(#[from] Box<ClientError>),
+}
+
+/// The state of a single bundle member.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase",
+        tag = "status"
+    )
+)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MemberStatus {
+    /// The feature has not been queued.
+    NotQueued,
+    /// The feature was queued on its own for a future epoch.
+    Scheduled {
+        /// The earliest epoch in which the feature may activate.
+        not_before_epoch: Epoch,
+    },
+    /// The feature is queued for activation at the next epoch boundary.
+    Pending,
+    /// The feature has been activated by the runtime.
+    Active {
+        /// The slot at which the feature was activated.
+        activated_at: Slot,
+    },
+    /// The account is owned by the program but is not a feature, such as a
+    /// tombstone.
+    Invalid,
+}
+
+impl MemberStatus {
+    /// Decodes the status of a member from its feature account, which is
+    /// `None` if the account does not exist.
+    pub fn from_account(account: Option<&Account>) -> Self {
+        let Some(account) = account.filter(|account| account.owner == crate::ID) else {
+            return Self::NotQueued;
+        };
+        if let Ok(scheduled) = ScheduledActivation::unpack(&account.data) {
+            return Self::Scheduled {
+                not_before_epoch: scheduled.not_before_epoch,
+            };
+        }
+        match from_account(account) {
+            Some(Feature {
+                activated_at: Some(activated_at),
+            }) => Self::Active { activated_at },
+            Some(Feature { activated_at: None }) => Self::Pending,
+            None => Self::Invalid,
+        }
+    }
+}
+
+/// The combined state of a bundle's members.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BundleState {
+    /// No member has been queued, so the bundle can be queued.
+    NotQueued,
+    /// Every member is pending and will activate at the next epoch boundary.
+    Pending,
+    /// Every member has been activated.
+    Active,
+    /// The members disagree, for example because some were queued or
+    /// revoked on their own. The bundle can be neither queued nor revoked as
+    /// a whole.
+    Mixed,
+}
+
+/// The status of every member of a bundle.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BundleStatus {
+    /// The combined state of the members.
+    pub state: BundleState,
+    /// Each member and its status, in bundle order.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<(serde_with::DisplayFromStr, serde_with::Same)>>")
+    )]
+    pub members: Vec<(Address, MemberStatus)>,
+}
+
+impl BundleStatus {
+    /// Aggregates the status of each member.
+    pub fn new(members: Vec<(Address, MemberStatus)>) -> Self {
+        let all =
+            |matches: fn(&MemberStatus) -> bool| members.iter().all(|(_, status)| matches(status));
+        let state = if all(|status| matches!(status, MemberStatus::NotQueued)) {
+            BundleState::NotQueued
+        } else if all(|status| matches!(status, MemberStatus::Pending)) {
+            BundleState::Pending
+        } else if all(|status| matches!(status, MemberStatus::Active { .. })) {
+            BundleState::Active
+        } else {
+            BundleState::Mixed
+        };
+        Self { state, members }
+    }
+
+    /// The members that have been activated.
+    pub fn active_members(&self) -> impl Iterator<Item = &Address> {
+        self.members
+            .iter()
+            .filter(|(_, status)| matches!(status, MemberStatus::Active { .. }))
+            .map(|(member, _)| member)
+    }
+}
+
+/// A bundle of features that are queued and revoked together.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeatureBundle {
+    /// The bundle account address.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub address: Address,
+    /// The account that created the bundle.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub authority: Address,
+    /// The bundle name.
+    pub name: String,
+    /// The member feature IDs, in bundle order.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    pub members: Vec<Address>,
+}
+
+impl FeatureBundle {
+    /// Decodes the bundle stored at `address` from account data.
+    pub fn from_bytes(address: Address, data: &[u8]) -> Result<Self, BundleError> {
+        let bundle = Bundle::from_bytes(data)?;
+        if bundle.discriminator != BUNDLE_TAG {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "not a bundle account",
+            )
+            .into());
+        }
+        Ok(Self {
+            address,
+            authority: bundle.authority,
+            name: decode_name(&bundle.name),
+            members: bundle
+                .members
+                .into_iter()
+                .take_while(|member| member != &Address::default())
+                .collect(),
+        })
+    }
+
+    /// Fetches the bundle named `name` created by `authority`, or `None` if
+    /// it does not exist.
+    #[cfg(feature = "fetch")]
+    pub fn fetch(
+        rpc_client: &RpcClient,
+        authority: &Address,
+        name: &str,
+    ) -> Result<Option<Self>, BundleError> {
+        let (address, _) = find_bundle_address(authority, name)?;
+        let response = rpc_client
+            .get_account_with_commitment(&address, rpc_client.commitment())
+            .map_err(Box::new)?;
+        match response.value {
+            Some(account) if account.owner == crate::ID => {
+                Self::from_bytes(address, &account.data).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Aggregates the status of the members from their feature accounts,
+    /// given in bundle order.
+    pub fn status(&self, accounts: &[Option<Account>]) -> BundleStatus {
+        BundleStatus::new(
+            self.members
+                .iter()
+                .zip(
+                    accounts
+                        .iter()
+                        .map(Option::as_ref)
+                        .chain(std::iter::repeat(None)),
+                )
+                .map(|(member, account)| (*member, MemberStatus::from_account(account)))
+                .collect(),
+        )
+    }
+
+    /// Fetches the member feature accounts and aggregates their status.
+    #[cfg(feature = "fetch")]
+    pub fn fetch_status(&self, rpc_client: &RpcClient) -> Result<BundleStatus, BundleError> {
+        let response = rpc_client
+            .get_multiple_accounts_with_commitment(&self.members, rpc_client.commitment())
+            .map_err(Box::new)?;
+        Ok(self.status(&response.value))
+    }
+
+    /// Builds a `QueueBundle` instruction, signed by every member keypair
+    /// and `payer`.
+    pub fn queue_instruction(&self, payer: &Address) -> Instruction {
+        QueueBundleBuilder::new()
+            .bundle(self.address)
+            .payer(*payer)
+            .add_remaining_accounts(&self.member_metas())
+            .audit_log(find_audit_log_address().0)
+            .instruction()
+    }
+
+    /// Builds a `RevokeBundle` instruction, signed by every member keypair.
+    pub fn revoke_instruction(&self) -> Instruction {
+        RevokeBundleBuilder::new()
+            .bundle(self.address)
+            .incinerator(incinerator::id())
+            .add_remaining_accounts(&self.member_metas())
+            .audit_log(find_audit_log_address().0)
+            .instruction()
+    }
+
+    fn member_metas(&self) -> Vec<AccountMeta> {
+        self.members
+            .iter()
+            .map(|member| AccountMeta::new(*member, true))
+            .collect()
+    }
+}
+
+/// Builds an `InitializeBundle` instruction creating the bundle named `name`
+/// with the given members, signed by `authority` and `payer`.
+pub fn initialize_bundle_instruction(
+    authority: &Address,
+    payer: &Address,
+    name: &str,
+    members: &[Address],
+) -> Result<Instruction, BundleError> {
+    if members.is_empty() || members.len() > MAX_BUNDLE_MEMBERS {
+        return Err(BundleError::InvalidMemberCount(members.len()));
+    }
+    if let Some(member) = members
+        .iter()
+        .enumerate()
+        .find_map(|(index, member)| members[..index].contains(member).then_some(member))
+    {
+        return Err(BundleError::DuplicateMember(*member));
+    }
+
+    let (bundle, _) = find_bundle_address(authority, name)?;
+    Ok(InitializeBundleBuilder::new()
+        .bundle(bundle)
+        .authority(*authority)
+        .payer(*payer)
+        .name(encode_name(name)?)
+        .add_remaining_accounts(
+            &members
+                .iter()
+                .map(|member| AccountMeta::new_readonly(*member, false))
+                .collect::<Vec<_>>(),
+        )
+        .instruction())
+}
+
+fn encode_name(name: &str) -> Result<[u8; MAX_BUNDLE_NAME_LEN], BundleError> {
+    if name.is_empty() || name.len() > MAX_BUNDLE_NAME_LEN {
+        return Err(BundleError::InvalidNameLength(name.len()));
+    }
+    let mut encoded = [0; MAX_BUNDLE_NAME_LEN];
+    encoded[..name.len()].copy_from_slice(name.as_bytes());
+    Ok(encoded)
+}
+
+/// Decodes a zero-padded bundle name, replacing invalid UTF-8.
+pub(crate) fn decode_name(name: &[u8; MAX_BUNDLE_NAME_LEN]) -> String {
+    let len = name
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(MAX_BUNDLE_NAME_LEN);
+    String::from_utf8_lossy(&name[..len]).into_owned()
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, borsh::to_vec};
+
+    fn feature_account(data: Vec<u8>) -> Option<Account> {
+        Some(Account {
+            lamports: 1,
+            data,
+            owner: crate::ID,
+            ..Account::default()
+        })
+    }
+
+    fn bundle(members: Vec<Address>) -> FeatureBundle {
+        let authority = Address::new_unique();
+        FeatureBundle {
+            address: find_bundle_address(&authority, "core-bpf").unwrap().0,
+            authority,
+            name: "core-bpf".to_string(),
+            members,
+        }
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let expected = bundle(vec![Address::new_unique(), Address::new_unique()]);
+        let mut members = [Address::default(); MAX_BUNDLE_MEMBERS];
+        members[..2].copy_from_slice(&expected.members);
+        let data = to_vec(&Bundle {
+            discriminator: BUNDLE_TAG,
+            authority: expected.authority,
+            name: encode_name(&expected.name).unwrap(),
+            members,
+        })
+        .unwrap();
+        assert_eq!(data.len(), crate::accounts::BUNDLE_SIZE);
+        assert_eq!(
+            FeatureBundle::from_bytes(expected.address, &data).unwrap(),
+            expected
+        );
+        assert!(matches!(
+            FeatureBundle::from_bytes(expected.address, &data[1..]),
+            Err(BundleError::InvalidAccountData(_))
+        ));
+        let mut invalid = data.clone();
+        invalid[0] = 0;
+        assert!(matches!(
+            FeatureBundle::from_bytes(expected.address, &invalid),
+            Err(BundleError::InvalidAccountData(_))
+        ));
+    }
+
+    #[test]
+    fn test_status() {
+        let bundle = bundle(vec![Address::new_unique(), Address::new_unique()]);
+        let pending = feature_account(vec![0; 9]);
+        let mut active = vec![1];
+        active.extend_from_slice(&42u64.to_le_bytes());
+        let active = feature_account(active);
+        let mut scheduled = vec![crate::schedule::SCHEDULED_ACTIVATION_TAG];
+        scheduled.extend_from_slice(&5u64.to_le_bytes());
+        let scheduled = feature_account(scheduled);
+        let not_owned = Some(Account::default());
+
+        let state = |accounts: &[Option<Account>]| bundle.status(accounts).state;
+        assert_eq!(state(&[None, not_owned.clone()]), BundleState::NotQueued);
+        assert_eq!(state(&[]), BundleState::NotQueued);
+        assert_eq!(
+            state(&[pending.clone(), pending.clone()]),
+            BundleState::Pending
+        );
+        assert_eq!(
+            state(&[active.clone(), active.clone()]),
+            BundleState::Active
+        );
+        assert_eq!(state(&[pending.clone(), None]), BundleState::Mixed);
+        assert_eq!(
+            state(&[scheduled.clone(), scheduled.clone()]),
+            BundleState::Mixed
+        );
+
+        let status = bundle.status(&[active, scheduled]);
+        assert_eq!(
+            status.members,
+            vec![
+                (bundle.members[0], MemberStatus::Active { activated_at: 42 }),
+                (
+                    bundle.members[1],
+                    MemberStatus::Scheduled {
+                        not_before_epoch: 5
+                    }
+                ),
+            ]
+        );
+        assert!(status.active_members().eq([&bundle.members[0]]));
+        assert_eq!(
+            MemberStatus::from_account(feature_account(vec![u8::MAX; 9]).as_ref()),
+            MemberStatus::Invalid
+        );
+    }
+
+    #[test]
+    fn test_instructions() {
+        let bundle = bundle(vec![Address::new_unique(), Address::new_unique()]);
+        let payer = Address::new_unique();
+
+        let initialize =
+            initialize_bundle_instruction(&bundle.authority, &payer, &bundle.name, &bundle.members)
+                .unwrap();
+        assert_eq!(initialize.data[0], 11);
+        assert_eq!(initialize.data[1..], encode_name(&bundle.name).unwrap());
+        assert_eq!(initialize.accounts[0].pubkey, bundle.address);
+        assert_eq!(initialize.accounts.len(), 6);
+        assert!(!initialize.accounts[5].is_signer);
+
+        let queue = bundle.queue_instruction(&payer);
+        assert_eq!(queue.data, vec![12]);
+        assert_eq!(queue.accounts.len(), 6);
+        assert!(queue.accounts[4..].iter().all(|meta| meta.is_signer));
+
+        let revoke = bundle.revoke_instruction();
+        assert_eq!(revoke.data, vec![13]);
+        assert_eq!(revoke.accounts[4].pubkey, bundle.members[0]);
+
+        let member = Address::new_unique();
+        assert!(matches!(
+            initialize_bundle_instruction(&bundle.authority, &payer, "x", &[member, member]),
+            Err(BundleError::DuplicateMember(duplicate)) if duplicate == member
+        ));
+        assert!(matches!(
+            initialize_bundle_instruction(&bundle.authority, &payer, "x", &[]),
+            Err(BundleError::InvalidMemberCount(0))
+        ));
+        assert!(matches!(
+            initialize_bundle_instruction(&bundle.authority, &payer, &"x".repeat(33), &[member]),
+            Err(BundleError::InvalidNameLength(33))
+        ));
+    }
+}
@@ -0,0 +1,29 @@
+//! Cluster selection.
+//!
+//! Tools built on this crate accept a cluster as an RPC URL or, like the
+//! Solana CLI, as a moniker.
+
+/// Expands a cluster moniker, `mainnet-beta`, `testnet`, `devnet` or
+/// `localhost` (or their first letter), into its RPC URL. Any other value is
+/// returned unchanged.
+pub fn normalize_url(url: &str) -> &str {
+    match url {
+        "m" | "mainnet-beta" => "https://api.mainnet-beta.solana.com",
+        "t" | "testnet" => "https://api.testnet.solana.com",
+        "d" | "devnet" => "https://api.devnet.solana.com",
+        "l" | "localhost" => "http://localhost:8899",
+        url => url,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize_url() {
+        assert_eq!(normalize_url("d"), "https://api.devnet.solana.com");
+        assert_eq!(normalize_url("localhost"), "http://localhost:8899");
+        assert_eq!(normalize_url("https://example.com"), "https://example.com");
+    }
+}
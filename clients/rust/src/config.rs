@@ -0,0 +1,360 @@
+//! Typed access to the program config.
+//!
+//! The program reads its tunables from a singleton config account derived
+//! from [`CONFIG_SEED`]. Until the config is initialized, the program uses
+//! fixed defaults, which [`ProgramConfig::default`] mirrors, so readers get
+//! the configuration in effect whether or not the account exists.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use solana_feature_gate_program_interface::state::{
+    find_config_address, CONFIG_ADDRESS, CONFIG_SEED, CONFIG_TAG, MAX_REFUND_RECIPIENTS,
+    TOMBSTONE_TAG,
+};
+#[cfg(feature = "fetch")]
+use solana_rpc_client::{api::client_error::Error as ClientError, rpc_client::RpcClient};
+use {
+    crate::{
+        accounts::Config,
+        audit_log::find_audit_log_address,
+        instructions::{InitializeConfigBuilder, UpdateConfigBuilder},
+    },
+    solana_address::Address,
+    solana_feature_gate_program_interface::state::STAKE_GUARD_REVOKE_WINDOW,
+    solana_instruction::Instruction,
+    solana_sdk_ids::bpf_loader_upgradeable,
+    thiserror::Error,
+};
+
+/// The revoke guard window the program uses until a config is initialized.
+pub const DEFAULT_REVOKE_GUARD_WINDOW: u64 = STAKE_GUARD_REVOKE_WINDOW;
+
+/// Errors that can occur while reading or building the program config.
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    /// The config holds a tombstone policy this client does not recognize.
+    #[error("Unknown tombstone policy {0}")]
+    InvalidTombstonePolicy(u8),
+    /// More refund recipients than the config can hold.
+    #[error("At most {MAX_REFUND_RECIPIENTS} refund recipients are allowed, found {0}")]
+    TooManyRefundRecipients(usize),
+    /// The config account data could not be decoded.
+    #[error("Invalid config account data: {0}")]
+    InvalidAccountData(#[from] std::io::Error),
+    /// The RPC request failed.
+    #[cfg(feature = "fetch")]
+    #[error("RPC error: {0}")]
+    Rpc(#[from] Box<ClientError>),
+}
+
+/// What happens to a feature account revoked by its stake guard.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TombstonePolicy {
+    /// Close the feature account, so the feature can be queued again.
+    #[default]
+    Close,
+    /// Leave a tombstone at the feature address, so the feature can never be
+    /// queued or activated again.
+    Tombstone,
+}
+
+impl TryFrom<u8> for TombstonePolicy {
+    type Error = ConfigError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Close),
+            1 => Ok(Self::Tombstone),
+            _ => Err(ConfigError::InvalidTombstonePolicy(value)),
+        }
+    }
+}
+
+impl From<TombstonePolicy> for u8 {
+    fn from(policy: TombstonePolicy) -> Self {
+        policy as u8
+    }
+}
+
+/// The program configuration in effect.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramConfig {
+    /// The governance authority, or `None` if the config has not been
+    /// initialized.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Option<serde_with::DisplayFromStr>>")
+    )]
+    pub authority: Option<Address>,
+    /// The number of slots at the end of an epoch during which anyone may
+    /// revoke a guarded feature that lacks supporting stake.
+    pub revoke_guard_window: u64,
+    /// What happens to features revoked by their stake guard.
+    pub tombstone_policy: TombstonePolicy,
+    /// Accounts that may receive revoked feature lamports instead of the
+    /// incinerator.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<Vec<serde_with::DisplayFromStr>>")
+    )]
+    pub refund_recipients: Vec<Address>,
+}
+
+impl Default for ProgramConfig {
+    fn default() -> Self {
+        Self {
+            authority: None,
+            revoke_guard_window: DEFAULT_REVOKE_GUARD_WINDOW,
+            tombstone_policy: TombstonePolicy::Close,
+            refund_recipients: Vec::new(),
+        }
+    }
+}
+
+impl TryFrom<Config> for ProgramConfig {
+    type Error = ConfigError;
+
+    fn try_from(config: Config) -> Result<Self, Self::Error> {
+        if config.discriminator != CONFIG_TAG {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "not a config account",
+            )
+            .into());
+        }
+        Ok(Self {
+            authority: Some(config.authority),
+            revoke_guard_window: config.revoke_guard_window,
+            tombstone_policy: config.tombstone_policy.try_into()?,
+            refund_recipients: config
+                .refund_recipients
+                .into_iter()
+                .filter(|recipient| recipient != &Address::default())
+                .collect(),
+        })
+    }
+}
+
+impl ProgramConfig {
+    /// Decodes the config from account data.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ConfigError> {
+        Config::from_bytes(data)?.try_into()
+    }
+
+    /// Decodes the config from the arguments of an `InitializeConfig` or
+    /// `UpdateConfig` instruction, which carry the account fields without
+    /// the leading [`CONFIG_TAG`].
+    pub fn from_instruction_args(args: &[u8]) -> Result<Self, ConfigError> {
+        Self::from_bytes(&[&[CONFIG_TAG][..], args].concat())
+    }
+
+    /// Fetches the config in effect, falling back to the defaults if the
+    /// config account has not been initialized.
+    #[cfg(feature = "fetch")]
+    pub fn fetch(rpc_client: &RpcClient) -> Result<Self, ConfigError> {
+        let response = rpc_client
+            .get_account_with_commitment(&find_config_address().0, rpc_client.commitment())
+            .map_err(Box::new)?;
+        match response.value {
+            Some(account) if account.owner == crate::ID => Self::from_bytes(&account.data),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    /// Whether revoked feature lamports may be sent to `recipient`.
+    pub fn is_refund_recipient(&self, recipient: &Address) -> bool {
+        self.refund_recipients.contains(recipient)
+    }
+
+    /// Builds an `InitializeConfig` instruction storing this config under
+    /// `authority`, signed by the program's upgrade authority.
+    pub fn initialize_instruction(
+        &self,
+        authority: &Address,
+        upgrade_authority: &Address,
+        payer: &Address,
+    ) -> Result<Instruction, ConfigError> {
+        let config = self.to_account(authority)?;
+        let (program_data, _) =
+            Address::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::id());
+        Ok(InitializeConfigBuilder::new()
+            .config(find_config_address().0)
+            .upgrade_authority(*upgrade_authority)
+            .program_data(program_data)
+            .payer(*payer)
+            .authority(config.authority)
+            .revoke_guard_window(config.revoke_guard_window)
+            .tombstone_policy(config.tombstone_policy)
+            .refund_recipients(config.refund_recipients)
+            .audit_log(find_audit_log_address().0)
+            .instruction())
+    }
+
+    /// Builds an `UpdateConfig` instruction replacing the config with this
+    /// one under `authority`, signed by the current authority.
+    pub fn update_instruction(
+        &self,
+        authority: &Address,
+        current_authority: &Address,
+    ) -> Result<Instruction, ConfigError> {
+        let config = self.to_account(authority)?;
+        Ok(UpdateConfigBuilder::new()
+            .config(find_config_address().0)
+            .config_authority(*current_authority)
+            .authority(config.authority)
+            .revoke_guard_window(config.revoke_guard_window)
+            .tombstone_policy(config.tombstone_policy)
+            .refund_recipients(config.refund_recipients)
+            .audit_log(find_audit_log_address().0)
+            .instruction())
+    }
+
+    fn to_account(&self, authority: &Address) -> Result<Config, ConfigError> {
+        if self.refund_recipients.len() > MAX_REFUND_RECIPIENTS {
+            return Err(ConfigError::TooManyRefundRecipients(
+                self.refund_recipients.len(),
+            ));
+        }
+        let mut refund_recipients = [Address::default(); MAX_REFUND_RECIPIENTS];
+        refund_recipients[..self.refund_recipients.len()].copy_from_slice(&self.refund_recipients);
+        Ok(Config {
+            discriminator: CONFIG_TAG,
+            authority: *authority,
+            revoke_guard_window: self.revoke_guard_window,
+            tombstone_policy: self.tombstone_policy.into(),
+            refund_recipients,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, borsh::to_vec};
+
+    fn config() -> ProgramConfig {
+        ProgramConfig {
+            authority: None,
+            revoke_guard_window: 1_000,
+            tombstone_policy: TombstonePolicy::Tombstone,
+            refund_recipients: vec![Address::new_unique()],
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let authority = Address::new_unique();
+        let config = config();
+        let data = to_vec(&config.to_account(&authority).unwrap()).unwrap();
+        assert_eq!(data.len(), crate::accounts::CONFIG_SIZE);
+        assert_eq!(
+            ProgramConfig::from_bytes(&data).unwrap(),
+            ProgramConfig {
+                authority: Some(authority),
+                ..config
+            }
+        );
+
+        let mut invalid = data.clone();
+        invalid[0] = 0;
+        assert!(matches!(
+            ProgramConfig::from_bytes(&invalid),
+            Err(ConfigError::InvalidAccountData(_))
+        ));
+
+        let mut invalid = data.clone();
+        invalid[41] = 2;
+        assert!(matches!(
+            ProgramConfig::from_bytes(&invalid),
+            Err(ConfigError::InvalidTombstonePolicy(2))
+        ));
+    }
+
+    #[test]
+    fn test_instructions() {
+        let authority = Address::new_unique();
+        let config = config();
+        assert!(config.is_refund_recipient(&config.refund_recipients[0]));
+        assert!(!config.is_refund_recipient(&Address::default()));
+
+        let instruction = config
+            .update_instruction(&authority, &Address::new_unique())
+            .unwrap();
+        assert_eq!(instruction.accounts[0].pubkey, find_config_address().0);
+        assert_eq!(instruction.data[0], 6);
+        assert_eq!(
+            ProgramConfig::from_instruction_args(&instruction.data[1..])
+                .unwrap()
+                .authority,
+            Some(authority)
+        );
+
+        let too_many = ProgramConfig {
+            refund_recipients: vec![Address::new_unique(); MAX_REFUND_RECIPIENTS + 1],
+            ..config
+        };
+        assert!(matches!(
+            too_many.initialize_instruction(
+                &authority,
+                &Address::new_unique(),
+                &Address::new_unique()
+            ),
+            Err(ConfigError::TooManyRefundRecipients(5))
+        ));
+    }
+
+    #[cfg(all(feature = "fetch", feature = "serde"))]
+    #[test]
+    fn test_fetch_with_mock_rpc() {
+        use {
+            base64::{prelude::BASE64_STANDARD, Engine},
+            solana_rpc_client::{api::request::RpcRequest, mock_sender::MocksMap},
+        };
+
+        let authority = Address::new_unique();
+        let config = config();
+        let data = to_vec(&config.to_account(&authority).unwrap()).unwrap();
+
+        let mut mocks = MocksMap::default();
+        mocks.insert(
+            RpcRequest::GetAccountInfo,
+            serde_json::json!({ "context": { "slot": 1 }, "value": null }),
+        );
+        mocks.insert(
+            RpcRequest::GetAccountInfo,
+            serde_json::json!({
+                "context": { "slot": 1 },
+                "value": {
+                    "lamports": 1_000_000,
+                    "data": [BASE64_STANDARD.encode(&data), "base64"],
+                    "owner": crate::ID.to_string(),
+                    "executable": false,
+                    "rentEpoch": 0,
+                    "space": data.len(),
+                },
+            }),
+        );
+
+        let rpc_client = RpcClient::new_mock_with_mocks_map("succeeds", mocks);
+        assert_eq!(
+            ProgramConfig::fetch(&rpc_client).unwrap(),
+            ProgramConfig::default()
+        );
+        assert_eq!(
+            ProgramConfig::fetch(&rpc_client).unwrap(),
+            ProgramConfig {
+                authority: Some(authority),
+                ..config
+            }
+        );
+    }
+}
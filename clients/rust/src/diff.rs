@@ -0,0 +1,314 @@
+//! Feature-set comparison between clusters.
+//!
+//! A [`FeatureSet`] holds the status of every feature account on a cluster,
+//! either fetched live (with the `fetch` feature) or built from snapshots,
+//! including exported [`ClusterSnapshot`] files.
+//! [`diff`] compares two feature sets and reports every feature whose status
+//! differs, e.g. features active on testnet but still pending or missing on
+//! mainnet.
+
+#[cfg(any(feature = "fetch", feature = "serde"))]
+use crate::snapshot::SnapshotError;
+#[cfg(feature = "serde")]
+use crate::snapshot::SnapshotFormat;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "fetch")]
+use solana_rpc_client::rpc_client::RpcClient;
+use {
+    crate::{
+        known_features::KnownFeatureRegistry,
+        snapshot::{ClusterSnapshot, FeatureAccountSnapshot, FeatureStatus},
+    },
+    solana_address::Address,
+    solana_clock::Slot,
+    std::collections::{BTreeMap, BTreeSet},
+};
+
+/// The status of every feature account on a cluster.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FeatureSet {
+    statuses: BTreeMap<Address, FeatureStatus>,
+}
+
+impl FeatureSet {
+    /// Builds a feature set from feature account snapshots.
+    pub fn from_snapshots<'a>(
+        snapshots: impl IntoIterator<Item = &'a FeatureAccountSnapshot>,
+    ) -> Self {
+        snapshots
+            .into_iter()
+            .map(|snapshot| (snapshot.address, snapshot.status()))
+            .collect()
+    }
+
+    /// Fetches the status of every feature account on a cluster.
+    #[cfg(feature = "fetch")]
+    pub fn fetch(rpc_client: &RpcClient) -> Result<Self, SnapshotError> {
+        Ok(Self::from_snapshots(&FeatureAccountSnapshot::fetch_all(
+            rpc_client,
+        )?))
+    }
+
+    /// Parses a feature set from a JSON cluster snapshot, or from a bare JSON
+    /// array of feature account snapshots.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, SnapshotError> {
+        if let Ok(snapshots) = serde_json::from_str::<Vec<FeatureAccountSnapshot>>(json) {
+            return Ok(Self::from_snapshots(&snapshots));
+        }
+        Ok(Self::from(&ClusterSnapshot::from_json(json)?))
+    }
+
+    /// Loads a feature set from a `.json` or `.csv` snapshot file.
+    ///
+    /// JSON files may contain either a cluster snapshot or a bare array of
+    /// feature account snapshots.
+    #[cfg(feature = "serde")]
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, SnapshotError> {
+        let path = path.as_ref();
+        match SnapshotFormat::from_path(path)? {
+            SnapshotFormat::Json => Self::from_json(&std::fs::read_to_string(path)?),
+            SnapshotFormat::Csv => Ok(Self::from(&ClusterSnapshot::load(path)?)),
+        }
+    }
+
+    /// The status of a feature, or `None` if its account does not exist.
+    pub fn get(&self, id: &Address) -> Option<FeatureStatus> {
+        self.statuses.get(id).copied()
+    }
+
+    /// Iterates over every feature in the set, ordered by ID.
+    pub fn iter(&self) -> impl Iterator<Item = (&Address, &FeatureStatus)> {
+        self.statuses.iter()
+    }
+
+    /// The number of features in the set.
+    pub fn len(&self) -> usize {
+        self.statuses.len()
+    }
+
+    /// Whether the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.statuses.is_empty()
+    }
+}
+
+impl From<&ClusterSnapshot> for FeatureSet {
+    fn from(snapshot: &ClusterSnapshot) -> Self {
+        Self::from_snapshots(&snapshot.accounts)
+    }
+}
+
+impl FromIterator<(Address, FeatureStatus)> for FeatureSet {
+    fn from_iter<T: IntoIterator<Item = (Address, FeatureStatus)>>(iter: T) -> Self {
+        Self {
+            statuses: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// A feature whose status differs between two feature sets.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeatureDiffEntry {
+    /// The feature ID.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub id: Address,
+    /// The feature's name in the known-feature registry, if any.
+    pub name: Option<String>,
+    /// The status on the left side, or `None` if the feature is missing.
+    pub left: Option<FeatureStatus>,
+    /// The status on the right side, or `None` if the feature is missing.
+    pub right: Option<FeatureStatus>,
+}
+
+impl FeatureDiffEntry {
+    /// The slot at which the feature was activated on the left side.
+    pub fn left_activated_at(&self) -> Option<Slot> {
+        activated_at(self.left)
+    }
+
+    /// The slot at which the feature was activated on the right side.
+    pub fn right_activated_at(&self) -> Option<Slot> {
+        activated_at(self.right)
+    }
+}
+
+/// The features whose status differs between two feature sets.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct FeatureSetDiff {
+    /// The differing features, ordered by ID.
+    pub features: Vec<FeatureDiffEntry>,
+}
+
+impl FeatureSetDiff {
+    /// Whether both feature sets agree on every feature.
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// Features active on the left side but not on the right.
+    pub fn active_only_on_left(&self) -> impl Iterator<Item = &FeatureDiffEntry> {
+        self.features
+            .iter()
+            .filter(|entry| is_active(entry.left) && !is_active(entry.right))
+    }
+
+    /// Features active on the right side but not on the left.
+    pub fn active_only_on_right(&self) -> impl Iterator<Item = &FeatureDiffEntry> {
+        self.features
+            .iter()
+            .filter(|entry| is_active(entry.right) && !is_active(entry.left))
+    }
+}
+
+/// Compares two feature sets, annotating differing features with their
+/// names from `registry`.
+///
+/// A feature is reported when it is missing on one side, or when its status
+/// (pending, active or invalid) differs. Features active on both sides are
+/// not reported, even if they were activated at different slots.
+pub fn diff(
+    left: &FeatureSet,
+    right: &FeatureSet,
+    registry: &KnownFeatureRegistry,
+) -> FeatureSetDiff {
+    let ids = left
+        .statuses
+        .keys()
+        .chain(right.statuses.keys())
+        .collect::<BTreeSet<_>>();
+
+    FeatureSetDiff {
+        features: ids
+            .into_iter()
+            .filter_map(|id| {
+                let (left, right) = (left.get(id), right.get(id));
+                let same = match (left, right) {
+                    (Some(FeatureStatus::Active { .. }), Some(FeatureStatus::Active { .. })) => {
+                        true
+                    }
+                    (left, right) => left == right,
+                };
+                (!same).then(|| FeatureDiffEntry {
+                    id: *id,
+                    name: registry.get(id).map(|feature| feature.name.to_string()),
+                    left,
+                    right,
+                })
+            })
+            .collect(),
+    }
+}
+
+fn activated_at(status: Option<FeatureStatus>) -> Option<Slot> {
+    match status {
+        Some(FeatureStatus::Active { activated_at }) => Some(activated_at),
+        _ => None,
+    }
+}
+
+fn is_active(status: Option<FeatureStatus>) -> bool {
+    activated_at(status).is_some()
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_address::address};
+
+    const LOADER_V4: Address = address!("2aQJYqER2aKyb3cZw22v4SL2xMX7vwXBRWfvS4pTrtED");
+
+    #[test]
+    fn test_diff() {
+        let (both_active, both_pending, pending_vs_active, left_only) = (
+            Address::new_unique(),
+            Address::new_unique(),
+            Address::new_unique(),
+            Address::new_unique(),
+        );
+        let left = FeatureSet::from_iter([
+            (both_active, FeatureStatus::Active { activated_at: 10 }),
+            (both_pending, FeatureStatus::Pending),
+            (pending_vs_active, FeatureStatus::Pending),
+            (left_only, FeatureStatus::Active { activated_at: 20 }),
+        ]);
+        let right = FeatureSet::from_iter([
+            (both_active, FeatureStatus::Active { activated_at: 99 }),
+            (both_pending, FeatureStatus::Pending),
+            (
+                pending_vs_active,
+                FeatureStatus::Active { activated_at: 30 },
+            ),
+            (LOADER_V4, FeatureStatus::Pending),
+        ]);
+
+        let changes = diff(&left, &right, &KnownFeatureRegistry::new());
+
+        let mut expected = vec![
+            FeatureDiffEntry {
+                id: pending_vs_active,
+                name: None,
+                left: Some(FeatureStatus::Pending),
+                right: Some(FeatureStatus::Active { activated_at: 30 }),
+            },
+            FeatureDiffEntry {
+                id: left_only,
+                name: None,
+                left: Some(FeatureStatus::Active { activated_at: 20 }),
+                right: None,
+            },
+            FeatureDiffEntry {
+                id: LOADER_V4,
+                name: Some("enable_loader_v4".to_string()),
+                left: None,
+                right: Some(FeatureStatus::Pending),
+            },
+        ];
+        expected.sort_by_key(|entry| entry.id);
+        assert_eq!(changes.features, expected);
+
+        let active_left = changes.active_only_on_left().collect::<Vec<_>>();
+        assert_eq!(active_left.len(), 1);
+        assert_eq!(active_left[0].id, left_only);
+        assert_eq!(active_left[0].left_activated_at(), Some(20));
+        let active_right = changes.active_only_on_right().collect::<Vec<_>>();
+        assert_eq!(active_right.len(), 1);
+        assert_eq!(active_right[0].right_activated_at(), Some(30));
+
+        assert!(diff(&left, &left, &KnownFeatureRegistry::empty()).is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_feature_set_from_json() {
+        let json = format!(
+            r#"[
+                {{ "address": "{LOADER_V4}", "lamports": 1, "owner": "{}", "data": "AAAAAAAAAAAA" }},
+                {{ "address": "{}", "lamports": 1, "owner": "{}", "data": "" }}
+            ]"#,
+            crate::ID,
+            Address::default(),
+            crate::ID,
+        );
+        let feature_set = FeatureSet::from_json(&json).unwrap();
+        assert_eq!(feature_set.len(), 2);
+        assert_eq!(feature_set.get(&LOADER_V4), Some(FeatureStatus::Pending));
+        assert_eq!(
+            feature_set.get(&Address::default()),
+            Some(FeatureStatus::Invalid)
+        );
+    }
+}
@@ -11,6 +11,27 @@ pub enum FeatureGateError {
     /// 0 - Feature already activated
     #[error("Feature already activated")]
     FeatureAlreadyActivated = 0x0,
+    /// 1 - Signer is not an authority of the vote account
+    #[error("Signer is not an authority of the vote account")]
+    InvalidVoteAuthority = 0x1,
+    /// 2 - Feature has sufficient supporting stake
+    #[error("Feature has sufficient supporting stake")]
+    FeatureSufficientlySupported = 0x2,
+    /// 3 - Stake guard revoke window is not open
+    #[error("Stake guard revoke window is not open")]
+    RevokeWindowClosed = 0x3,
+    /// 4 - Recipient is not allowed to receive refunds
+    #[error("Recipient is not allowed to receive refunds")]
+    InvalidRefundRecipient = 0x4,
+    /// 5 - Scheduled activation cannot be released yet
+    #[error("Scheduled activation cannot be released yet")]
+    ActivationNotDue = 0x5,
+    /// 6 - Account does not match the bundle member
+    #[error("Account does not match the bundle member")]
+    InvalidBundleMember = 0x6,
+    /// 7 - Config is invalid
+    #[error("Config is invalid")]
+    InvalidConfig = 0x7,
 }
 
 impl From<FeatureGateError> for solana_program_error::ProgramError {
@@ -4,6 +4,25 @@
 //!
 //! <https://github.com/codama-idl/codama>
 
+pub(crate) mod r#extend_audit_log;
+pub(crate) mod r#initialize_audit_log;
+pub(crate) mod r#initialize_bundle;
+pub(crate) mod r#initialize_config;
+pub(crate) mod r#initialize_stake_guard;
+pub(crate) mod r#queue_bundle;
+pub(crate) mod r#release_scheduled_activation;
+pub(crate) mod r#revoke_bundle;
 pub(crate) mod r#revoke_pending_activation;
+pub(crate) mod r#revoke_unsupported_activation;
+pub(crate) mod r#schedule_activation;
+pub(crate) mod r#signal_support;
+pub(crate) mod r#tally_support;
+pub(crate) mod r#update_config;
 
-pub use self::r#revoke_pending_activation::*;
+pub use self::{
+    r#extend_audit_log::*, r#initialize_audit_log::*, r#initialize_bundle::*,
+    r#initialize_config::*, r#initialize_stake_guard::*, r#queue_bundle::*,
+    r#release_scheduled_activation::*, r#revoke_bundle::*, r#revoke_pending_activation::*,
+    r#revoke_unsupported_activation::*, r#schedule_activation::*, r#signal_support::*,
+    r#tally_support::*, r#update_config::*,
+};
@@ -13,10 +13,12 @@ pub const REVOKE_PENDING_ACTIVATION_DISCRIMINATOR: u8 = 0;
 pub struct RevokePendingActivation {
     /// The feature account to revoke
     pub feature: solana_address::Address,
-    /// The incinerator account
+    /// The incinerator account, or an allowed refund recipient
     pub incinerator: solana_address::Address,
     /// The system program
     pub system_program: solana_address::Address,
+    /// The audit log account
+    pub audit_log: Option<solana_address::Address>,
 }
 
 impl RevokePendingActivation {
@@ -29,7 +31,7 @@ impl RevokePendingActivation {
         &self,
         remaining_accounts: &[solana_instruction::AccountMeta],
     ) -> solana_instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(self.feature, true));
         accounts.push(solana_instruction::AccountMeta::new(
             self.incinerator,
@@ -39,6 +41,14 @@ impl RevokePendingActivation {
             self.system_program,
             false,
         ));
+        if let Some(audit_log) = self.audit_log {
+            accounts.push(solana_instruction::AccountMeta::new(audit_log, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::FEATURE_GATE_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = RevokePendingActivationInstructionData::new()
             .try_to_vec()
@@ -81,11 +91,13 @@ impl Default for RevokePendingActivationInstructionData {
 ///   1. `[writable]` incinerator
 ///   2. `[optional]` system_program (default to
 ///      `11111111111111111111111111111111`)
+///   3. `[writable, optional]` audit_log
 #[derive(Clone, Debug, Default)]
 pub struct RevokePendingActivationBuilder {
     feature: Option<solana_address::Address>,
     incinerator: Option<solana_address::Address>,
     system_program: Option<solana_address::Address>,
+    audit_log: Option<solana_address::Address>,
     __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -99,7 +111,7 @@ impl RevokePendingActivationBuilder {
         self.feature = Some(feature);
         self
     }
-    /// The incinerator account
+    /// The incinerator account, or an allowed refund recipient
     #[inline(always)]
     pub fn incinerator(&mut self, incinerator: solana_address::Address) -> &mut Self {
         self.incinerator = Some(incinerator);
@@ -112,6 +124,13 @@ impl RevokePendingActivationBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The audit log account
+    #[inline(always)]
+    pub fn audit_log(&mut self, audit_log: Option<solana_address::Address>) -> &mut Self {
+        self.audit_log = audit_log;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(&mut self, account: solana_instruction::AccountMeta) -> &mut Self {
@@ -135,6 +154,7 @@ impl RevokePendingActivationBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_address::address!("11111111111111111111111111111111")),
+            audit_log: self.audit_log,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -145,10 +165,12 @@ impl RevokePendingActivationBuilder {
 pub struct RevokePendingActivationCpiAccounts<'a, 'b> {
     /// The feature account to revoke
     pub feature: &'b solana_account_info::AccountInfo<'a>,
-    /// The incinerator account
+    /// The incinerator account, or an allowed refund recipient
     pub incinerator: &'b solana_account_info::AccountInfo<'a>,
     /// The system program
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// The audit log account
+    pub audit_log: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 /// `revoke_pending_activation` CPI instruction.
@@ -157,10 +179,12 @@ pub struct RevokePendingActivationCpi<'a, 'b> {
     pub __program: &'b solana_account_info::AccountInfo<'a>,
     /// The feature account to revoke
     pub feature: &'b solana_account_info::AccountInfo<'a>,
-    /// The incinerator account
+    /// The incinerator account, or an allowed refund recipient
     pub incinerator: &'b solana_account_info::AccountInfo<'a>,
     /// The system program
     pub system_program: &'b solana_account_info::AccountInfo<'a>,
+    /// The audit log account
+    pub audit_log: Option<&'b solana_account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> RevokePendingActivationCpi<'a, 'b> {
@@ -173,6 +197,7 @@ impl<'a, 'b> RevokePendingActivationCpi<'a, 'b> {
             feature: accounts.feature,
             incinerator: accounts.incinerator,
             system_program: accounts.system_program,
+            audit_log: accounts.audit_log,
         }
     }
     #[inline(always)]
@@ -198,7 +223,7 @@ impl<'a, 'b> RevokePendingActivationCpi<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
         remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)],
     ) -> solana_program_error::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_instruction::AccountMeta::new(
             *self.feature.key,
             true,
@@ -211,6 +236,14 @@ impl<'a, 'b> RevokePendingActivationCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(audit_log) = self.audit_log {
+            accounts.push(solana_instruction::AccountMeta::new(*audit_log.key, false));
+        } else {
+            accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::FEATURE_GATE_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -227,11 +260,14 @@ impl<'a, 'b> RevokePendingActivationCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.feature.clone());
         account_infos.push(self.incinerator.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(audit_log) = self.audit_log {
+            account_infos.push(audit_log.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -251,6 +287,7 @@ impl<'a, 'b> RevokePendingActivationCpi<'a, 'b> {
 ///   0. `[writable, signer]` feature
 ///   1. `[writable]` incinerator
 ///   2. `[]` system_program
+///   3. `[writable, optional]` audit_log
 #[derive(Clone, Debug)]
 pub struct RevokePendingActivationCpiBuilder<'a, 'b> {
     instruction: Box<RevokePendingActivationCpiBuilderInstruction<'a, 'b>>,
@@ -263,6 +300,7 @@ impl<'a, 'b> RevokePendingActivationCpiBuilder<'a, 'b> {
             feature: None,
             incinerator: None,
             system_program: None,
+            audit_log: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -273,7 +311,7 @@ impl<'a, 'b> RevokePendingActivationCpiBuilder<'a, 'b> {
         self.instruction.feature = Some(feature);
         self
     }
-    /// The incinerator account
+    /// The incinerator account, or an allowed refund recipient
     #[inline(always)]
     pub fn incinerator(
         &mut self,
@@ -291,6 +329,16 @@ impl<'a, 'b> RevokePendingActivationCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional account]`
+    /// The audit log account
+    #[inline(always)]
+    pub fn audit_log(
+        &mut self,
+        audit_log: Option<&'b solana_account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.audit_log = audit_log;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -340,6 +388,8 @@ impl<'a, 'b> RevokePendingActivationCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            audit_log: self.instruction.audit_log,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -354,6 +404,7 @@ struct RevokePendingActivationCpiBuilderInstruction<'a, 'b> {
     feature: Option<&'b solana_account_info::AccountInfo<'a>>,
     incinerator: Option<&'b solana_account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+    audit_log: Option<&'b solana_account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -0,0 +1,91 @@
+//! Features at PDAs of a governance program.
+//!
+//! A feature ID does not have to be the address of a keypair. A governance
+//! program, such as a DAO or multisig, can derive it as a PDA of its own
+//! program ID and sign for it with `invoke_signed`; the Feature Gate program
+//! sees the feature account as a signer exactly as if a keypair had signed.
+//! The governance program then owns the feature's whole lifecycle, and no
+//! feature keypair exists that could leak or be lost.
+//!
+//! Since nothing can sign for a PDA in a transaction, only the program that
+//! derives the feature ID can queue or revoke it. The helpers below perform
+//! both from within such a program.
+
+use {
+    crate::instructions::{RevokePendingActivationCpi, RevokePendingActivationCpiAccounts},
+    solana_account_info::AccountInfo,
+    solana_address::Address,
+    solana_cpi::invoke_signed,
+    solana_feature_gate_interface::activate_with_lamports,
+    solana_program_error::ProgramResult,
+};
+
+/// Whether `feature_id` is a PDA, i.e. can only be queued or revoked by the
+/// program it is derived from.
+pub fn is_governed_feature(feature_id: &Address) -> bool {
+    !feature_id.is_on_curve()
+}
+
+/// Queues the feature at `feature_info` for activation, funded with
+/// `lamports` by `payer_info`.
+///
+/// `signers_seeds` must include the seeds of the feature ID, and of the payer
+/// if it is a PDA of the calling program too.
+pub fn queue_signed<'a>(
+    feature_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    lamports: u64,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let account_infos = [
+        feature_info.clone(),
+        payer_info.clone(),
+        system_program_info.clone(),
+    ];
+    activate_with_lamports(feature_info.key, payer_info.key, lamports)
+        .iter()
+        .try_for_each(|instruction| invoke_signed(instruction, &account_infos, signers_seeds))
+}
+
+/// Revokes the pending activation of the feature at `feature_info`, burning
+/// its lamports.
+///
+/// `signers_seeds` must include the seeds of the feature ID.
+pub fn revoke_signed<'a>(
+    feature_gate_program_info: &AccountInfo<'a>,
+    feature_info: &AccountInfo<'a>,
+    incinerator_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    audit_log_info: &AccountInfo<'a>,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    RevokePendingActivationCpi::new(
+        feature_gate_program_info,
+        RevokePendingActivationCpiAccounts {
+            feature: feature_info,
+            incinerator: incinerator_info,
+            system_program: system_program_info,
+            audit_log: Some(audit_log_info),
+        },
+    )
+    .invoke_signed(signers_seeds)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_is_governed_feature() {
+        let governance = Address::new_unique();
+        let (pda, _) = Address::find_program_address(&[b"feature", b"core-bpf"], &governance);
+        assert!(is_governed_feature(&pda));
+
+        // The Ed25519 base point, as a keypair address is a point on the
+        // curve.
+        let mut base_point = [0x66; 32];
+        base_point[0] = 0x58;
+        assert!(!is_governed_feature(&Address::new_from_array(base_point)));
+    }
+}
@@ -0,0 +1,254 @@
+//! Vanity feature keypair grinding.
+//!
+//! Feature IDs are conventionally ground to a recognizable prefix. A
+//! [`Grinder`] searches for a keypair whose base58 address matches a
+//! [`GrindPattern`], spreading the search across threads, and never returns
+//! an address from its exclusion set — typically every feature account in a
+//! [`ClusterSnapshot`](crate::snapshot::ClusterSnapshot).
+
+use {
+    solana_address::Address,
+    solana_keypair::Keypair,
+    solana_signer::Signer,
+    std::{
+        collections::HashSet,
+        num::NonZeroUsize,
+        sync::{
+            atomic::{AtomicBool, AtomicU64, Ordering},
+            Mutex,
+        },
+    },
+    thiserror::Error,
+};
+
+/// The base58 alphabet used by Solana addresses.
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Errors that can occur while grinding.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum GrindError {
+    /// Neither a prefix nor a suffix was given.
+    #[error("A prefix or suffix is required")]
+    EmptyPattern,
+    /// The pattern contains a character that cannot appear in a base58
+    /// address.
+    #[error("`{0}` cannot appear in a base58 address")]
+    InvalidCharacter(char),
+    /// No matching keypair was found within the attempt limit.
+    #[error("No matching keypair found after {0} attempts")]
+    AttemptsExhausted(u64),
+}
+
+/// The prefix and suffix a ground address must match.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrindPattern {
+    prefix: String,
+    suffix: String,
+    ignore_case: bool,
+}
+
+impl GrindPattern {
+    /// Creates a pattern, validating that it can match a base58 address.
+    ///
+    /// When `ignore_case` is set, a character is accepted if either its
+    /// uppercase or lowercase form is a base58 character.
+    pub fn new(prefix: &str, suffix: &str, ignore_case: bool) -> Result<Self, GrindError> {
+        if prefix.is_empty() && suffix.is_empty() {
+            return Err(GrindError::EmptyPattern);
+        }
+        let is_base58 = |c: char| BASE58_ALPHABET.contains(c);
+        if let Some(invalid) = prefix.chars().chain(suffix.chars()).find(|&c| {
+            if ignore_case {
+                !is_base58(c.to_ascii_uppercase()) && !is_base58(c.to_ascii_lowercase())
+            } else {
+                !is_base58(c)
+            }
+        }) {
+            return Err(GrindError::InvalidCharacter(invalid));
+        }
+
+        let normalize = |pattern: &str| {
+            if ignore_case {
+                pattern.to_ascii_lowercase()
+            } else {
+                pattern.to_string()
+            }
+        };
+        Ok(Self {
+            prefix: normalize(prefix),
+            suffix: normalize(suffix),
+            ignore_case,
+        })
+    }
+
+    /// Whether a base58-encoded address matches the pattern.
+    pub fn matches(&self, address: &str) -> bool {
+        if self.ignore_case {
+            let address = address.to_ascii_lowercase();
+            address.starts_with(&self.prefix) && address.ends_with(&self.suffix)
+        } else {
+            address.starts_with(&self.prefix) && address.ends_with(&self.suffix)
+        }
+    }
+}
+
+/// A successfully ground keypair.
+#[derive(Debug)]
+pub struct GrindResult {
+    /// The matching keypair.
+    pub keypair: Keypair,
+    /// The total number of keypairs generated across all threads.
+    pub attempts: u64,
+}
+
+/// A multi-threaded vanity keypair search.
+#[derive(Clone, Debug)]
+pub struct Grinder {
+    pattern: GrindPattern,
+    threads: NonZeroUsize,
+    max_attempts: Option<u64>,
+    exclude: HashSet<Address>,
+}
+
+impl Grinder {
+    /// Creates a grinder using one thread per available CPU.
+    pub fn new(pattern: GrindPattern) -> Self {
+        Self {
+            pattern,
+            threads: std::thread::available_parallelism().unwrap_or(NonZeroUsize::MIN),
+            max_attempts: None,
+            exclude: HashSet::new(),
+        }
+    }
+
+    /// Sets the number of worker threads.
+    pub fn threads(mut self, threads: NonZeroUsize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Gives up after roughly `max_attempts` keypairs have been generated.
+    pub fn max_attempts(mut self, max_attempts: u64) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Never returns any of the given addresses, e.g. features that already
+    /// exist on a cluster.
+    pub fn exclude(mut self, addresses: impl IntoIterator<Item = Address>) -> Self {
+        self.exclude.extend(addresses);
+        self
+    }
+
+    /// Whether `address` is an acceptable result.
+    pub fn is_candidate(&self, address: &Address) -> bool {
+        !self.exclude.contains(address) && self.pattern.matches(&address.to_string())
+    }
+
+    /// Searches for a matching keypair.
+    pub fn grind(&self) -> Result<GrindResult, GrindError> {
+        let done = AtomicBool::new(false);
+        let attempts = AtomicU64::new(0);
+        let found = Mutex::new(None);
+
+        std::thread::scope(|scope| {
+            for _ in 0..self.threads.get() {
+                scope.spawn(|| {
+                    while !done.load(Ordering::Relaxed) {
+                        let attempt = attempts.fetch_add(1, Ordering::Relaxed).saturating_add(1);
+                        if self.max_attempts.is_some_and(|max| attempt > max) {
+                            done.store(true, Ordering::Relaxed);
+                            break;
+                        }
+                        let keypair = Keypair::new();
+                        if self.is_candidate(&keypair.pubkey()) {
+                            done.store(true, Ordering::Relaxed);
+                            found
+                                .lock()
+                                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                                .get_or_insert(keypair);
+                        }
+                    }
+                });
+            }
+        });
+
+        let attempts = attempts.into_inner();
+        match found
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+        {
+            Some(keypair) => Ok(GrindResult {
+                keypair,
+                attempts: self.max_attempts.map_or(attempts, |max| attempts.min(max)),
+            }),
+            None => Err(GrindError::AttemptsExhausted(
+                self.max_attempts.unwrap_or(attempts),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pattern() {
+        assert_eq!(
+            GrindPattern::new("", "", false),
+            Err(GrindError::EmptyPattern)
+        );
+        assert_eq!(
+            GrindPattern::new("Feat0", "", false),
+            Err(GrindError::InvalidCharacter('0'))
+        );
+        // `l` is not base58, but `L` is.
+        assert_eq!(
+            GrindPattern::new("l", "", false),
+            Err(GrindError::InvalidCharacter('l'))
+        );
+        assert!(GrindPattern::new("l", "", true).is_ok());
+
+        let pattern = GrindPattern::new("Feat", "Xyz", false).unwrap();
+        assert!(pattern.matches("Feat111Xyz"));
+        assert!(!pattern.matches("feat111Xyz"));
+        assert!(!pattern.matches("Feat111xyz"));
+
+        let pattern = GrindPattern::new("Feat", "xyz", true).unwrap();
+        assert!(pattern.matches("fEAT111XyZ"));
+        assert!(!pattern.matches("Fea111xyz"));
+    }
+
+    #[test]
+    fn test_grind() {
+        let pattern = GrindPattern::new("a", "", true).unwrap();
+        let result = Grinder::new(pattern.clone())
+            .threads(NonZeroUsize::new(2).unwrap())
+            .grind()
+            .unwrap();
+        assert!(pattern.matches(&result.keypair.pubkey().to_string()));
+        assert!(result.attempts >= 1);
+
+        // A full-length pattern cannot be found in a handful of attempts.
+        let pattern = GrindPattern::new("FeatureFeatureFeature", "", false).unwrap();
+        assert_eq!(
+            Grinder::new(pattern).max_attempts(10).grind().unwrap_err(),
+            GrindError::AttemptsExhausted(10)
+        );
+    }
+
+    #[test]
+    fn test_grind_excludes_existing_addresses() {
+        let keypair = Keypair::new();
+        let address = keypair.pubkey();
+        let prefix = &address.to_string()[..4];
+        let pattern = GrindPattern::new(prefix, "", false).unwrap();
+
+        let grinder = Grinder::new(pattern.clone());
+        assert!(grinder.is_candidate(&address));
+        let grinder = grinder.exclude([address]);
+        assert!(!grinder.is_candidate(&address));
+        assert!(pattern.matches(&address.to_string()));
+    }
+}
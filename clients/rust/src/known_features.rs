@@ -0,0 +1,287 @@
+//! Registry of known cluster features.
+//!
+//! Feature IDs are opaque addresses. This module maps them to the names,
+//! descriptions and SIMD references used by Agave's `feature_set`, and to the
+//! release that introduced them. The
+//! built-in table is generated at build time from
+//! `data/known_features.toml`; additional entries can be loaded from TOML or
+//! JSON files with the `serde` feature enabled.
+
+#[cfg(feature = "serde")]
+use {
+    serde::{Deserialize, Serialize},
+    std::path::Path,
+    thiserror::Error,
+};
+use {solana_address::Address, std::borrow::Cow};
+
+include!(concat!(env!("OUT_DIR"), "/known_features.rs"));
+
+/// A feature known to the registry.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KnownFeature {
+    /// The feature ID.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub id: Address,
+    /// The feature's module name in Agave's `feature_set`.
+    pub name: Cow<'static, str>,
+    /// A short description of the feature.
+    pub description: Cow<'static, str>,
+    /// The SIMDs implemented by the feature.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub simd: Cow<'static, [u16]>,
+    /// The earliest validator release that declared the feature, if known.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub introduced_in: Option<Cow<'static, str>>,
+}
+
+impl KnownFeature {
+    /// The SIMD references of the feature, formatted as `SIMD-XXXX`.
+    pub fn simd_references(&self) -> Vec<String> {
+        self.simd
+            .iter()
+            .map(|simd| format!("SIMD-{simd:04}"))
+            .collect()
+    }
+}
+
+/// Returns the built-in known features.
+pub fn builtin() -> &'static [KnownFeature] {
+    BUILTIN_KNOWN_FEATURES
+}
+
+/// Looks up a built-in known feature by ID.
+pub fn find_by_id(id: &Address) -> Option<&'static KnownFeature> {
+    BUILTIN_KNOWN_FEATURES
+        .iter()
+        .find(|feature| &feature.id == id)
+}
+
+/// Looks up a built-in known feature by name.
+pub fn find_by_name(name: &str) -> Option<&'static KnownFeature> {
+    BUILTIN_KNOWN_FEATURES
+        .iter()
+        .find(|feature| feature.name == name)
+}
+
+/// Errors that can occur while loading known features from a file.
+#[cfg(feature = "serde")]
+#[derive(Debug, Error)]
+pub enum KnownFeaturesError {
+    /// The file could not be read.
+    #[error("Failed to read known features file: {0}")]
+    Io(#[from] std::io::Error),
+    /// The file is not valid TOML.
+    #[error("Invalid known features TOML: {0}")]
+    Toml(#[from] toml::de::Error),
+    /// The file is not valid JSON.
+    #[error("Invalid known features JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The file extension is neither `.toml` nor `.json`.
+    #[error("Unsupported known features file format: {0}")]
+    UnsupportedFormat(String),
+}
+
+/// The on-disk layout of a known features file.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct KnownFeaturesFile {
+    feature: Vec<KnownFeature>,
+}
+
+/// A collection of known features, seeded with the built-in table and
+/// extensible with user-provided entries.
+///
+/// Entries added later take precedence over earlier entries with the same ID.
+#[derive(Clone, Debug)]
+pub struct KnownFeatureRegistry {
+    features: Vec<KnownFeature>,
+}
+
+impl Default for KnownFeatureRegistry {
+    fn default() -> Self {
+        Self {
+            features: BUILTIN_KNOWN_FEATURES.to_vec(),
+        }
+    }
+}
+
+impl KnownFeatureRegistry {
+    /// Creates a registry containing the built-in known features.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty registry.
+    pub fn empty() -> Self {
+        Self {
+            features: Vec::new(),
+        }
+    }
+
+    /// Adds a feature, replacing any existing entry with the same ID.
+    pub fn insert(&mut self, feature: KnownFeature) {
+        self.features.retain(|existing| existing.id != feature.id);
+        self.features.push(feature);
+    }
+
+    /// Adds every feature in `features`, replacing existing entries with the
+    /// same ID.
+    pub fn extend(&mut self, features: impl IntoIterator<Item = KnownFeature>) {
+        features
+            .into_iter()
+            .for_each(|feature| self.insert(feature));
+    }
+
+    /// Looks up a feature by ID.
+    pub fn get(&self, id: &Address) -> Option<&KnownFeature> {
+        self.features.iter().find(|feature| &feature.id == id)
+    }
+
+    /// Looks up a feature by name.
+    pub fn get_by_name(&self, name: &str) -> Option<&KnownFeature> {
+        self.features.iter().find(|feature| feature.name == name)
+    }
+
+    /// Iterates over all features in the registry.
+    pub fn iter(&self) -> impl Iterator<Item = &KnownFeature> {
+        self.features.iter()
+    }
+
+    /// The number of features in the registry.
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// Whether the registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// Adds the features listed in a TOML document using the same layout as
+    /// `data/known_features.toml`. Returns the number of features added.
+    #[cfg(feature = "serde")]
+    pub fn extend_from_toml(&mut self, toml: &str) -> Result<usize, KnownFeaturesError> {
+        let file: KnownFeaturesFile = toml::from_str(toml)?;
+        Ok(self.extend_from_file(file))
+    }
+
+    /// Adds the features listed in a JSON document of the form
+    /// `{ "feature": [ ... ] }`. Returns the number of features added.
+    #[cfg(feature = "serde")]
+    pub fn extend_from_json(&mut self, json: &str) -> Result<usize, KnownFeaturesError> {
+        let file: KnownFeaturesFile = serde_json::from_str(json)?;
+        Ok(self.extend_from_file(file))
+    }
+
+    /// Adds the features listed in a `.toml` or `.json` file. Returns the
+    /// number of features added.
+    #[cfg(feature = "serde")]
+    pub fn load(&mut self, path: impl AsRef<Path>) -> Result<usize, KnownFeaturesError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => self.extend_from_toml(&contents),
+            Some("json") => self.extend_from_json(&contents),
+            _ => Err(KnownFeaturesError::UnsupportedFormat(
+                path.display().to_string(),
+            )),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn extend_from_file(&mut self, file: KnownFeaturesFile) -> usize {
+        let count = file.feature.len();
+        self.extend(file.feature);
+        count
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_address::address};
+
+    const LOADER_V4: Address = address!("2aQJYqER2aKyb3cZw22v4SL2xMX7vwXBRWfvS4pTrtED");
+
+    #[test]
+    fn test_builtin_lookup() {
+        let feature = find_by_id(&LOADER_V4).unwrap();
+        assert_eq!(feature.name, "enable_loader_v4");
+        assert_eq!(feature.simd.as_ref(), &[167]);
+        assert_eq!(feature.simd_references(), vec!["SIMD-0167".to_string()]);
+        assert_eq!(feature.introduced_in.as_deref(), Some("v2.2.14"));
+        assert!(BUILTIN_KNOWN_FEATURES
+            .iter()
+            .all(|feature| feature.introduced_in.is_some()));
+
+        assert_eq!(find_by_name("enable_loader_v4"), Some(feature));
+        assert_eq!(find_by_name("not_a_feature"), None);
+    }
+
+    #[test]
+    fn test_registry_insert_overrides() {
+        let mut registry = KnownFeatureRegistry::new();
+        let builtin_len = registry.len();
+
+        let mut feature = registry.get(&LOADER_V4).unwrap().clone();
+        feature.introduced_in = Some(Cow::Borrowed("v2.0.0"));
+        registry.insert(feature);
+        assert_eq!(registry.len(), builtin_len);
+        assert_eq!(
+            registry.get(&LOADER_V4).unwrap().introduced_in.as_deref(),
+            Some("v2.0.0")
+        );
+
+        let id = Address::new_unique();
+        registry.insert(KnownFeature {
+            id,
+            name: Cow::Borrowed("my_feature"),
+            description: Cow::Borrowed("My feature"),
+            simd: Cow::Borrowed(&[]),
+            introduced_in: None,
+        });
+        assert_eq!(registry.len(), builtin_len + 1);
+        assert_eq!(registry.get_by_name("my_feature").unwrap().id, id);
+        assert!(KnownFeatureRegistry::empty().is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_registry_extend_from_files() {
+        let mut registry = KnownFeatureRegistry::empty();
+        let id = Address::new_unique();
+
+        let toml = format!(
+            r#"
+            [[feature]]
+            id = "{id}"
+            name = "my_feature"
+            description = "My feature"
+            simd = [1234]
+            introduced_in = "v3.0.0"
+            "#
+        );
+        assert_eq!(registry.extend_from_toml(&toml).unwrap(), 1);
+        let feature = registry.get(&id).unwrap();
+        assert_eq!(feature.simd.as_ref(), &[1234]);
+        assert_eq!(feature.introduced_in.as_deref(), Some("v3.0.0"));
+
+        let json = format!(
+            r#"{{ "feature": [{{ "id": "{id}", "name": "renamed", "description": "Renamed" }}] }}"#
+        );
+        assert_eq!(registry.extend_from_json(&json).unwrap(), 1);
+        assert_eq!(registry.len(), 1);
+        let feature = registry.get(&id).unwrap();
+        assert_eq!(feature.name, "renamed");
+        assert!(feature.simd.is_empty());
+
+        assert!(matches!(
+            registry.extend_from_toml("not toml ["),
+            Err(KnownFeaturesError::Toml(_))
+        ));
+    }
+}
@@ -1,4 +1,24 @@
+pub mod activation;
+pub mod audit_log;
+pub mod bundle;
+pub mod cluster;
+pub mod config;
+pub mod diff;
 #[allow(deprecated)] // needed until Codama stops implementing deprecated traits
 mod generated;
+pub mod governed;
+#[cfg(feature = "grind")]
+pub mod grind;
+pub mod known_features;
+pub mod multisig;
+pub mod parser;
+pub mod readiness;
+pub mod schedule;
+#[cfg(feature = "simulation")]
+pub mod simulation;
+pub mod snapshot;
+pub mod transaction_error;
+#[cfg(feature = "watch")]
+pub mod watch;
 
 pub use generated::{programs::FEATURE_GATE_ID as ID, *};
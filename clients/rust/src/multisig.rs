@@ -0,0 +1,679 @@
+//! Multisig vault transaction proposals.
+//!
+//! Governance that runs through a Squads v4 multisig does not sign Feature
+//! Gate instructions directly: a member proposes a vault transaction holding
+//! the instructions, the members vote on the proposal, and the multisig
+//! program executes the instructions with its vault as signer. The vault is
+//! a PDA of the multisig program, so the feature ID it can queue and revoke
+//! is the vault address itself (see [`governed`](crate::governed)).
+//!
+//! [`VaultProposal`] compiles instructions into the vault transaction
+//! message format the multisig program expects and builds the instructions
+//! that create the transaction and its proposal. Before voting, members can
+//! check with [`VaultTransaction::verify`] that the transaction account holds
+//! exactly the intended instructions.
+
+use {
+    borsh::{BorshDeserialize, BorshSerialize},
+    solana_address::{address, Address},
+    solana_instruction::{AccountMeta, Instruction},
+    solana_message::Message,
+    solana_sdk_ids::system_program,
+    thiserror::Error,
+};
+
+/// The Squads v4 multisig program ID.
+pub const MULTISIG_PROGRAM_ID: Address = address!("SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf");
+
+const SEED_PREFIX: &[u8] = b"multisig";
+const SEED_VAULT: &[u8] = b"vault";
+const SEED_TRANSACTION: &[u8] = b"transaction";
+const SEED_PROPOSAL: &[u8] = b"proposal";
+
+const VAULT_TRANSACTION_CREATE_DISCRIMINATOR: [u8; 8] = [48, 250, 78, 168, 208, 226, 218, 211];
+const PROPOSAL_CREATE_DISCRIMINATOR: [u8; 8] = [220, 60, 73, 224, 30, 108, 79, 159];
+
+/// Derives the address of vault `vault_index` of `multisig`.
+pub fn find_vault_address(multisig: &Address, vault_index: u8) -> (Address, u8) {
+    Address::find_program_address(
+        &[SEED_PREFIX, multisig.as_ref(), SEED_VAULT, &[vault_index]],
+        &MULTISIG_PROGRAM_ID,
+    )
+}
+
+/// Derives the address of transaction `transaction_index` of `multisig`.
+pub fn find_transaction_address(multisig: &Address, transaction_index: u64) -> (Address, u8) {
+    Address::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig.as_ref(),
+            SEED_TRANSACTION,
+            &transaction_index.to_le_bytes(),
+        ],
+        &MULTISIG_PROGRAM_ID,
+    )
+}
+
+/// Derives the address of the proposal for transaction `transaction_index`
+/// of `multisig`.
+pub fn find_proposal_address(multisig: &Address, transaction_index: u64) -> (Address, u8) {
+    Address::find_program_address(
+        &[
+            SEED_PREFIX,
+            multisig.as_ref(),
+            SEED_TRANSACTION,
+            &transaction_index.to_le_bytes(),
+            SEED_PROPOSAL,
+        ],
+        &MULTISIG_PROGRAM_ID,
+    )
+}
+
+/// Errors that can occur while building or verifying a vault transaction.
+#[derive(Debug, Error)]
+pub enum MultisigError {
+    /// The instructions reference more accounts than a message can hold.
+    #[error("A vault transaction can reference at most 255 accounts, found {0}")]
+    TooManyAccounts(usize),
+    /// An instruction's data is longer than the message format allows.
+    #[error("Instruction data can be at most 65535 bytes long, found {0}")]
+    InstructionDataTooLarge(usize),
+    /// The bytes are not a vault transaction account.
+    #[error("Account is not a vault transaction")]
+    InvalidDiscriminator,
+    /// The message is malformed.
+    #[error("Invalid vault transaction message")]
+    InvalidMessage,
+    /// The message loads accounts from address lookup tables, which cannot
+    /// be resolved offline.
+    #[error("Vault transaction uses address lookup tables")]
+    AddressTableLookups,
+    /// The transaction holds a different number of instructions.
+    #[error("Expected {expected} instructions, found {found}")]
+    InstructionCountMismatch { expected: usize, found: usize },
+    /// An instruction differs from the intended one.
+    #[error("Instruction {0} does not match the intended instruction")]
+    InstructionMismatch(usize),
+    /// The account data could not be decoded.
+    #[error("Invalid vault transaction account data: {0}")]
+    InvalidAccountData(#[from] std::io::Error),
+}
+
+/// An instruction of a vault transaction message, referencing accounts by
+/// index into the message's account keys.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Eq, PartialEq)]
+pub struct CompiledInstruction {
+    pub program_id_index: u8,
+    pub account_indexes: Vec<u8>,
+    pub data: Vec<u8>,
+}
+
+/// Accounts a vault transaction message loads from an address lookup table.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Eq, PartialEq)]
+pub struct AddressTableLookup {
+    pub account_key: Address,
+    pub writable_indexes: Vec<u8>,
+    pub readonly_indexes: Vec<u8>,
+}
+
+/// The message of a vault transaction.
+///
+/// Account keys are ordered as in a transaction message: writable signers,
+/// read-only signers, writable non-signers, then read-only non-signers.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Eq, PartialEq)]
+pub struct VaultTransactionMessage {
+    pub num_signers: u8,
+    pub num_writable_signers: u8,
+    pub num_writable_non_signers: u8,
+    pub account_keys: Vec<Address>,
+    pub instructions: Vec<CompiledInstruction>,
+    pub address_table_lookups: Vec<AddressTableLookup>,
+}
+
+impl VaultTransactionMessage {
+    /// Compiles `instructions` into a message executed with `vault` as
+    /// signer.
+    pub fn compile(vault: &Address, instructions: &[Instruction]) -> Result<Self, MultisigError> {
+        let message = Message::new(instructions, Some(vault));
+        let num_keys = message.account_keys.len();
+        let num_signers = message.header.num_required_signatures;
+        let num_writable_non_signers = num_keys
+            .saturating_sub(usize::from(num_signers))
+            .saturating_sub(usize::from(message.header.num_readonly_unsigned_accounts));
+        Ok(Self {
+            num_signers,
+            num_writable_signers: num_signers
+                .saturating_sub(message.header.num_readonly_signed_accounts),
+            num_writable_non_signers: u8::try_from(num_writable_non_signers)
+                .map_err(|_| MultisigError::TooManyAccounts(num_keys))?,
+            account_keys: message.account_keys,
+            instructions: message
+                .instructions
+                .into_iter()
+                .map(|instruction| CompiledInstruction {
+                    program_id_index: instruction.program_id_index,
+                    account_indexes: instruction.accounts,
+                    data: instruction.data,
+                })
+                .collect(),
+            address_table_lookups: Vec::new(),
+        })
+    }
+
+    /// Resolves the instructions of the message.
+    ///
+    /// Signer and writable flags are those of the message, so an account
+    /// writable in one instruction is writable in all of them.
+    pub fn decompile(&self) -> Result<Vec<Instruction>, MultisigError> {
+        if !self.address_table_lookups.is_empty() {
+            return Err(MultisigError::AddressTableLookups);
+        }
+        let num_signers = usize::from(self.num_signers);
+        let num_writable_signers = usize::from(self.num_writable_signers);
+        let num_writable_non_signers = usize::from(self.num_writable_non_signers);
+        if num_writable_signers > num_signers
+            || num_signers.saturating_add(num_writable_non_signers) > self.account_keys.len()
+        {
+            return Err(MultisigError::InvalidMessage);
+        }
+
+        let account_meta = |index: u8| -> Result<AccountMeta, MultisigError> {
+            let index = usize::from(index);
+            let pubkey = *self
+                .account_keys
+                .get(index)
+                .ok_or(MultisigError::InvalidMessage)?;
+            Ok(if index < num_signers {
+                AccountMeta {
+                    pubkey,
+                    is_signer: true,
+                    is_writable: index < num_writable_signers,
+                }
+            } else {
+                AccountMeta {
+                    pubkey,
+                    is_signer: false,
+                    is_writable: index.saturating_sub(num_signers) < num_writable_non_signers,
+                }
+            })
+        };
+        self.instructions
+            .iter()
+            .map(|instruction| {
+                Ok(Instruction {
+                    program_id: account_meta(instruction.program_id_index)?.pubkey,
+                    accounts: instruction
+                        .account_indexes
+                        .iter()
+                        .map(|index| account_meta(*index))
+                        .collect::<Result<_, _>>()?,
+                    data: instruction.data.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Checks that the message executes exactly `expected` with `vault` as
+    /// signer.
+    pub fn verify(&self, vault: &Address, expected: &[Instruction]) -> Result<(), MultisigError> {
+        let found = self.decompile()?;
+        let expected = Self::compile(vault, expected)?.decompile()?;
+        if found.len() != expected.len() {
+            return Err(MultisigError::InstructionCountMismatch {
+                expected: expected.len(),
+                found: found.len(),
+            });
+        }
+        match found.iter().zip(&expected).position(|(a, b)| a != b) {
+            Some(index) => Err(MultisigError::InstructionMismatch(index)),
+            None => Ok(()),
+        }
+    }
+
+    /// Serializes the message in the compact format the multisig program
+    /// takes when creating a vault transaction, with `u8` length prefixes
+    /// except for instruction data, which has a `u16` prefix.
+    pub fn to_transaction_message(&self) -> Result<Vec<u8>, MultisigError> {
+        let mut bytes = vec![
+            self.num_signers,
+            self.num_writable_signers,
+            self.num_writable_non_signers,
+        ];
+        push_len(&mut bytes, self.account_keys.len())?;
+        for key in &self.account_keys {
+            bytes.extend_from_slice(key.as_ref());
+        }
+        push_len(&mut bytes, self.instructions.len())?;
+        for instruction in &self.instructions {
+            bytes.push(instruction.program_id_index);
+            push_len(&mut bytes, instruction.account_indexes.len())?;
+            bytes.extend_from_slice(&instruction.account_indexes);
+            let data_len = u16::try_from(instruction.data.len())
+                .map_err(|_| MultisigError::InstructionDataTooLarge(instruction.data.len()))?;
+            bytes.extend_from_slice(&data_len.to_le_bytes());
+            bytes.extend_from_slice(&instruction.data);
+        }
+        push_len(&mut bytes, self.address_table_lookups.len())?;
+        for lookup in &self.address_table_lookups {
+            bytes.extend_from_slice(lookup.account_key.as_ref());
+            push_len(&mut bytes, lookup.writable_indexes.len())?;
+            bytes.extend_from_slice(&lookup.writable_indexes);
+            push_len(&mut bytes, lookup.readonly_indexes.len())?;
+            bytes.extend_from_slice(&lookup.readonly_indexes);
+        }
+        Ok(bytes)
+    }
+
+    /// Parses a message in the compact format of
+    /// [`to_transaction_message`](Self::to_transaction_message).
+    pub fn from_transaction_message(bytes: &[u8]) -> Result<Self, MultisigError> {
+        let mut reader = CompactReader(bytes);
+        let num_signers = reader.u8()?;
+        let num_writable_signers = reader.u8()?;
+        let num_writable_non_signers = reader.u8()?;
+        let account_keys = (0..reader.u8()?)
+            .map(|_| reader.address())
+            .collect::<Result<_, _>>()?;
+        let instructions = (0..reader.u8()?)
+            .map(|_| {
+                let program_id_index = reader.u8()?;
+                let len = reader.u8()?;
+                let account_indexes = reader.bytes(usize::from(len))?;
+                let len = reader.u16()?;
+                Ok(CompiledInstruction {
+                    program_id_index,
+                    account_indexes,
+                    data: reader.bytes(usize::from(len))?,
+                })
+            })
+            .collect::<Result<_, MultisigError>>()?;
+        let address_table_lookups = (0..reader.u8()?)
+            .map(|_| {
+                let account_key = reader.address()?;
+                let len = reader.u8()?;
+                let writable_indexes = reader.bytes(usize::from(len))?;
+                let len = reader.u8()?;
+                Ok(AddressTableLookup {
+                    account_key,
+                    writable_indexes,
+                    readonly_indexes: reader.bytes(usize::from(len))?,
+                })
+            })
+            .collect::<Result<_, MultisigError>>()?;
+        if !reader.0.is_empty() {
+            return Err(MultisigError::InvalidMessage);
+        }
+        Ok(Self {
+            num_signers,
+            num_writable_signers,
+            num_writable_non_signers,
+            account_keys,
+            instructions,
+            address_table_lookups,
+        })
+    }
+}
+
+fn push_len(bytes: &mut Vec<u8>, len: usize) -> Result<(), MultisigError> {
+    bytes.push(u8::try_from(len).map_err(|_| MultisigError::TooManyAccounts(len))?);
+    Ok(())
+}
+
+struct CompactReader<'a>(&'a [u8]);
+
+impl CompactReader<'_> {
+    fn bytes(&mut self, len: usize) -> Result<Vec<u8>, MultisigError> {
+        let (bytes, rest) = self
+            .0
+            .split_at_checked(len)
+            .ok_or(MultisigError::InvalidMessage)?;
+        self.0 = rest;
+        Ok(bytes.to_vec())
+    }
+
+    fn u8(&mut self) -> Result<u8, MultisigError> {
+        let (byte, rest) = self.0.split_first().ok_or(MultisigError::InvalidMessage)?;
+        self.0 = rest;
+        Ok(*byte)
+    }
+
+    fn u16(&mut self) -> Result<u16, MultisigError> {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn address(&mut self) -> Result<Address, MultisigError> {
+        let bytes = self.bytes(32)?;
+        Address::try_from(bytes.as_slice()).map_err(|_| MultisigError::InvalidMessage)
+    }
+}
+
+/// A vault transaction account of the multisig program.
+#[derive(BorshDeserialize, BorshSerialize, Clone, Debug, Eq, PartialEq)]
+pub struct VaultTransaction {
+    pub multisig: Address,
+    pub creator: Address,
+    pub index: u64,
+    pub bump: u8,
+    pub vault_index: u8,
+    pub vault_bump: u8,
+    pub ephemeral_signer_bumps: Vec<u8>,
+    pub message: VaultTransactionMessage,
+}
+
+impl VaultTransaction {
+    /// The Anchor discriminator of vault transaction accounts.
+    pub const DISCRIMINATOR: [u8; 8] = [168, 250, 162, 100, 81, 14, 162, 207];
+
+    /// Decodes a vault transaction account.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, MultisigError> {
+        let mut data = data
+            .strip_prefix(&Self::DISCRIMINATOR)
+            .ok_or(MultisigError::InvalidDiscriminator)?;
+        Ok(Self::deserialize(&mut data)?)
+    }
+
+    /// The vault that executes the transaction.
+    pub fn vault(&self) -> Address {
+        find_vault_address(&self.multisig, self.vault_index).0
+    }
+
+    /// Checks that the transaction executes exactly `expected` with its
+    /// vault as signer.
+    pub fn verify(&self, expected: &[Instruction]) -> Result<(), MultisigError> {
+        self.message.verify(&self.vault(), expected)
+    }
+}
+
+/// A proposal to execute instructions from a multisig vault.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultProposal {
+    pub multisig: Address,
+    pub vault_index: u8,
+    /// The index of the new transaction, one past the multisig's current
+    /// transaction index.
+    pub transaction_index: u64,
+    pub message: VaultTransactionMessage,
+    pub memo: Option<String>,
+}
+
+impl VaultProposal {
+    /// A proposal to execute `instructions` from vault `vault_index` of
+    /// `multisig`, as its transaction `transaction_index`.
+    pub fn new(
+        multisig: Address,
+        vault_index: u8,
+        transaction_index: u64,
+        instructions: &[Instruction],
+    ) -> Result<Self, MultisigError> {
+        let (vault, _) = find_vault_address(&multisig, vault_index);
+        Ok(Self {
+            multisig,
+            vault_index,
+            transaction_index,
+            message: VaultTransactionMessage::compile(&vault, instructions)?,
+            memo: None,
+        })
+    }
+
+    /// The vault that executes the transaction.
+    pub fn vault(&self) -> Address {
+        find_vault_address(&self.multisig, self.vault_index).0
+    }
+
+    /// The instructions creating the vault transaction and its proposal,
+    /// proposed by `creator`, a multisig member, with the accounts' rent paid
+    /// by `rent_payer`.
+    pub fn instructions(
+        &self,
+        creator: &Address,
+        rent_payer: &Address,
+    ) -> Result<[Instruction; 2], MultisigError> {
+        let (transaction, _) = find_transaction_address(&self.multisig, self.transaction_index);
+        let (proposal, _) = find_proposal_address(&self.multisig, self.transaction_index);
+
+        let args = (
+            self.vault_index,
+            0u8, // No ephemeral signers.
+            self.message.to_transaction_message()?,
+            &self.memo,
+        );
+        let create_transaction = Instruction {
+            program_id: MULTISIG_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(self.multisig, false),
+                AccountMeta::new(transaction, false),
+                AccountMeta::new_readonly(*creator, true),
+                AccountMeta::new(*rent_payer, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: [
+                &VAULT_TRANSACTION_CREATE_DISCRIMINATOR[..],
+                &borsh::to_vec(&args).unwrap(),
+            ]
+            .concat(),
+        };
+
+        // Not a draft: members can vote right away.
+        let args = (self.transaction_index, false);
+        let create_proposal = Instruction {
+            program_id: MULTISIG_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(self.multisig, false),
+                AccountMeta::new(proposal, false),
+                AccountMeta::new_readonly(*creator, true),
+                AccountMeta::new(*rent_payer, true),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ],
+            data: [
+                &PROPOSAL_CREATE_DISCRIMINATOR[..],
+                &borsh::to_vec(&args).unwrap(),
+            ]
+            .concat(),
+        };
+
+        Ok([create_transaction, create_proposal])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{audit_log::find_audit_log_address, instructions::RevokePendingActivationBuilder},
+        borsh::to_vec,
+        solana_feature_gate_interface::activate_with_lamports,
+        solana_sdk_ids::incinerator,
+    };
+
+    fn revoke(feature_id: Address, recipient: Address) -> Instruction {
+        RevokePendingActivationBuilder::new()
+            .feature(feature_id)
+            .incinerator(recipient)
+            .audit_log(Some(find_audit_log_address().0))
+            .instruction()
+    }
+
+    fn proposal() -> VaultProposal {
+        let multisig = Address::new_unique();
+        let (vault, _) = find_vault_address(&multisig, 1);
+        VaultProposal::new(multisig, 1, 7, &[revoke(vault, incinerator::id())]).unwrap()
+    }
+
+    /// The vault transaction account the multisig program would create from
+    /// `proposal`.
+    fn transaction_account(proposal: &VaultProposal) -> Vec<u8> {
+        let message = VaultTransactionMessage::from_transaction_message(
+            &proposal.message.to_transaction_message().unwrap(),
+        )
+        .unwrap();
+        let transaction = VaultTransaction {
+            multisig: proposal.multisig,
+            creator: Address::new_unique(),
+            index: proposal.transaction_index,
+            bump: 255,
+            vault_index: proposal.vault_index,
+            vault_bump: find_vault_address(&proposal.multisig, proposal.vault_index).1,
+            ephemeral_signer_bumps: Vec::new(),
+            message,
+        };
+        [
+            VaultTransaction::DISCRIMINATOR.to_vec(),
+            to_vec(&transaction).unwrap(),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn test_compile_message() {
+        let proposal = proposal();
+        let vault = proposal.vault();
+        let message = &proposal.message;
+
+        assert_eq!(message.num_signers, 1);
+        assert_eq!(message.num_writable_signers, 1);
+        assert_eq!(message.num_writable_non_signers, 2);
+        assert_eq!(
+            message.account_keys,
+            vec![
+                vault,
+                incinerator::id(),
+                find_audit_log_address().0,
+                system_program::id(),
+                crate::ID
+            ]
+        );
+        assert_eq!(
+            message.decompile().unwrap(),
+            vec![revoke(vault, incinerator::id())]
+        );
+    }
+
+    #[test]
+    fn test_transaction_message_round_trip() {
+        let message = proposal().message;
+        let bytes = message.to_transaction_message().unwrap();
+        // Header, 5 keys, 1 instruction of 4 accounts and 1 data byte, no
+        // lookups.
+        assert_eq!(bytes.len(), 3 + 1 + 5 * 32 + 1 + (1 + 1 + 4 + 2 + 1) + 1);
+        assert_eq!(
+            VaultTransactionMessage::from_transaction_message(&bytes).unwrap(),
+            message
+        );
+
+        assert!(matches!(
+            VaultTransactionMessage::from_transaction_message(&bytes[..bytes.len() - 1]),
+            Err(MultisigError::InvalidMessage)
+        ));
+        assert!(matches!(
+            VaultTransactionMessage::from_transaction_message(&[bytes, vec![0]].concat()),
+            Err(MultisigError::InvalidMessage)
+        ));
+    }
+
+    #[test]
+    fn test_instructions() {
+        let proposal = proposal();
+        let creator = Address::new_unique();
+        let [create_transaction, create_proposal] =
+            proposal.instructions(&creator, &creator).unwrap();
+
+        assert_eq!(
+            create_transaction.accounts[1].pubkey,
+            find_transaction_address(&proposal.multisig, 7).0
+        );
+        let data = &create_transaction.data;
+        assert_eq!(data[..8], VAULT_TRANSACTION_CREATE_DISCRIMINATOR);
+        // Vault index, ephemeral signers, then the message as a Borsh `Vec`.
+        assert_eq!(data[8..10], [1, 0]);
+        let message = proposal.message.to_transaction_message().unwrap();
+        let len = u32::try_from(message.len()).unwrap().to_le_bytes();
+        assert_eq!(data[10..14], len);
+        assert_eq!(data[14..data.len() - 1], message);
+        // No memo.
+        assert_eq!(data[data.len() - 1], 0);
+
+        assert_eq!(
+            create_proposal.accounts[1].pubkey,
+            find_proposal_address(&proposal.multisig, 7).0
+        );
+        assert_eq!(
+            create_proposal.data,
+            [
+                &PROPOSAL_CREATE_DISCRIMINATOR[..],
+                &7u64.to_le_bytes(),
+                &[0]
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let proposal = proposal();
+        let vault = proposal.vault();
+        let data = transaction_account(&proposal);
+        let transaction = VaultTransaction::from_bytes(&data).unwrap();
+        assert_eq!(transaction.vault(), vault);
+
+        transaction
+            .verify(&[revoke(vault, incinerator::id())])
+            .unwrap();
+
+        // Lamports redirected to another account.
+        assert!(matches!(
+            transaction.verify(&[revoke(vault, Address::new_unique())]),
+            Err(MultisigError::InstructionMismatch(0))
+        ));
+        // A different feature.
+        assert!(matches!(
+            transaction.verify(&[revoke(Address::new_unique(), incinerator::id())]),
+            Err(MultisigError::InstructionMismatch(0))
+        ));
+        assert!(matches!(
+            transaction.verify(&[]),
+            Err(MultisigError::InstructionCountMismatch {
+                expected: 0,
+                found: 1
+            })
+        ));
+
+        assert!(matches!(
+            VaultTransaction::from_bytes(&data[8..]),
+            Err(MultisigError::InvalidDiscriminator)
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_extra_instruction() {
+        let multisig = Address::new_unique();
+        let (vault, _) = find_vault_address(&multisig, 0);
+        let intended = revoke(vault, incinerator::id());
+        // Transfers from the vault.
+        let drain = activate_with_lamports(&Address::new_unique(), &vault, 1_000_000).remove(0);
+        let proposal = VaultProposal::new(multisig, 0, 1, &[intended.clone(), drain]).unwrap();
+
+        assert!(matches!(
+            proposal.message.verify(&vault, &[intended]),
+            Err(MultisigError::InstructionCountMismatch {
+                expected: 1,
+                found: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_address_table_lookups() {
+        let mut message = proposal().message;
+        message.address_table_lookups.push(AddressTableLookup {
+            account_key: Address::new_unique(),
+            writable_indexes: vec![0],
+            readonly_indexes: Vec::new(),
+        });
+
+        assert!(matches!(
+            message.verify(&Address::new_unique(), &[]),
+            Err(MultisigError::AddressTableLookups)
+        ));
+    }
+}
@@ -0,0 +1,1761 @@
+//! Parser for Feature Gate instructions found in compiled transactions.
+//!
+//! Mirrors the parsed-instruction facility of `solana-transaction-status`:
+//! given a compiled instruction and the account keys of its enclosing message,
+//! produce a typed description of the instruction that can be serialized to
+//! JSON (with the `serde` feature enabled).
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use {
+    crate::{
+        accounts::CONFIG_SIZE,
+        audit_log::find_audit_log_address,
+        bundle::decode_name,
+        config::{find_config_address, ProgramConfig},
+        instructions::{
+            ExtendAuditLogInstructionArgs, InitializeAuditLogInstructionArgs,
+            InitializeBundleInstructionArgs, InitializeStakeGuardInstructionArgs,
+            ScheduleActivationInstructionArgs, SignalSupportInstructionArgs,
+            EXTEND_AUDIT_LOG_DISCRIMINATOR, INITIALIZE_AUDIT_LOG_DISCRIMINATOR,
+            INITIALIZE_BUNDLE_DISCRIMINATOR, INITIALIZE_CONFIG_DISCRIMINATOR,
+            INITIALIZE_STAKE_GUARD_DISCRIMINATOR, QUEUE_BUNDLE_DISCRIMINATOR,
+            RELEASE_SCHEDULED_ACTIVATION_DISCRIMINATOR, REVOKE_BUNDLE_DISCRIMINATOR,
+            REVOKE_PENDING_ACTIVATION_DISCRIMINATOR, REVOKE_UNSUPPORTED_ACTIVATION_DISCRIMINATOR,
+            SCHEDULE_ACTIVATION_DISCRIMINATOR, SIGNAL_SUPPORT_DISCRIMINATOR,
+            TALLY_SUPPORT_DISCRIMINATOR, UPDATE_CONFIG_DISCRIMINATOR,
+        },
+        ID,
+    },
+    borsh::BorshDeserialize,
+    solana_address::Address,
+    solana_message::{compiled_instruction::CompiledInstruction, AccountKeys, VersionedMessage},
+    solana_sdk_ids::{incinerator, system_program},
+    thiserror::Error,
+};
+
+/// Errors that prevent an instruction from being parsed at all.
+///
+/// Instructions that merely carry unrecognized data are _not_ errors; they
+/// are reported as [`ParsedFeatureGateInstruction::Unknown`].
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum ParseInstructionError {
+    /// The instruction does not target the Feature Gate program.
+    #[error("Instruction does not target the Feature Gate program")]
+    ProgramIdMismatch,
+    /// An account index points outside of the message account keys.
+    #[error("Account index {0} is out of bounds")]
+    AccountIndexOutOfBounds(u8),
+    /// The instruction does not reference enough accounts.
+    #[error("Expected at least {expected} accounts, found {actual}")]
+    NotEnoughAccounts {
+        /// Number of accounts the instruction requires.
+        expected: usize,
+        /// Number of accounts the instruction references.
+        actual: usize,
+    },
+}
+
+/// Signer and writable privileges granted to message accounts.
+pub trait AccountRoles {
+    /// Whether the account at `index` signed the message.
+    fn is_signer(&self, index: usize) -> bool;
+    /// Whether the account at `index` was requested as writable.
+    fn is_writable(&self, index: usize) -> bool;
+}
+
+impl AccountRoles for VersionedMessage {
+    fn is_signer(&self, index: usize) -> bool {
+        VersionedMessage::is_signer(self, index)
+    }
+
+    fn is_writable(&self, index: usize) -> bool {
+        self.is_maybe_writable(index, None)
+    }
+}
+
+impl AccountRoles for solana_message::Message {
+    fn is_signer(&self, index: usize) -> bool {
+        solana_message::Message::is_signer(self, index)
+    }
+
+    fn is_writable(&self, index: usize) -> bool {
+        self.is_maybe_writable(index, None)
+    }
+}
+
+/// An account referenced by a parsed instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedAccount {
+    /// The account address.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub address: Address,
+    /// Whether the account signed the message.
+    pub signer: bool,
+    /// Whether the account is writable in the message.
+    pub writable: bool,
+}
+
+/// A discrepancy between the accounts an instruction was given and what the
+/// program expects.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase", tag = "kind")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccountRoleIssue {
+    /// The account must sign the transaction but does not.
+    MissingSigner {
+        /// Name of the instruction account.
+        account: String,
+    },
+    /// The account must be writable but is read-only.
+    NotWritable {
+        /// Name of the instruction account.
+        account: String,
+    },
+    /// The account address is not the one the program expects.
+    UnexpectedAddress {
+        /// Name of the instruction account.
+        account: String,
+        /// Address the program expects.
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        expected: Address,
+    },
+    /// The instruction does not take this account.
+    UnexpectedAccount {
+        /// Address of the account.
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        address: Address,
+    },
+}
+
+/// Parsed `RevokePendingActivation` instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedRevokePendingActivation {
+    /// The feature account being revoked.
+    pub feature: ParsedAccount,
+    /// The incinerator, or the refund recipient, receiving the feature
+    /// account's lamports.
+    pub incinerator: ParsedAccount,
+    /// The system program.
+    pub system_program: ParsedAccount,
+    /// The audit log, without which no entry is recorded.
+    pub audit_log: Option<ParsedAccount>,
+    /// The program config, required to refund to a recipient other than the
+    /// incinerator.
+    pub config: Option<ParsedAccount>,
+    /// Account role problems that would cause the program to reject the
+    /// instruction.
+    pub issues: Vec<AccountRoleIssue>,
+}
+
+/// Parsed `SignalSupport` instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedSignalSupport {
+    /// The support signal account being created or updated.
+    pub support_signal: ParsedAccount,
+    /// The pending feature being signaled for.
+    pub feature: ParsedAccount,
+    /// The signaling validator's vote account.
+    pub vote_account: ParsedAccount,
+    /// The vote account's node identity or authorized withdrawer.
+    pub authority: ParsedAccount,
+    /// The account paying for the support signal account.
+    pub payer: ParsedAccount,
+    /// The system program.
+    pub system_program: ParsedAccount,
+    /// The signaled software version, as `major.minor.patch`.
+    pub version: String,
+    /// Account role problems that would cause the program to reject the
+    /// instruction.
+    pub issues: Vec<AccountRoleIssue>,
+}
+
+/// Parsed `InitializeStakeGuard` instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedInitializeStakeGuard {
+    /// The stake guard account being created.
+    pub stake_guard: ParsedAccount,
+    /// The pending feature being guarded.
+    pub feature: ParsedAccount,
+    /// The account paying for the stake guard account.
+    pub payer: ParsedAccount,
+    /// The system program.
+    pub system_program: ParsedAccount,
+    /// The required share of the total stake, in basis points.
+    pub threshold_bps: u16,
+    /// Account role problems that would cause the program to reject the
+    /// instruction.
+    pub issues: Vec<AccountRoleIssue>,
+}
+
+/// Parsed `TallySupport` instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedTallySupport {
+    /// The stake guard account being updated.
+    pub stake_guard: ParsedAccount,
+    /// The support signal accounts being tallied.
+    pub support_signals: Vec<ParsedAccount>,
+    /// Account role problems that would cause the program to reject the
+    /// instruction.
+    pub issues: Vec<AccountRoleIssue>,
+}
+
+/// Parsed `RevokeUnsupportedActivation` instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedRevokeUnsupportedActivation {
+    /// The feature account being revoked.
+    pub feature: ParsedAccount,
+    /// The feature's stake guard account.
+    pub stake_guard: ParsedAccount,
+    /// The incinerator receiving the revoked accounts' lamports.
+    pub incinerator: ParsedAccount,
+    /// The program config.
+    pub config: ParsedAccount,
+    /// The audit log.
+    pub audit_log: ParsedAccount,
+    /// Account role problems that would cause the program to reject the
+    /// instruction.
+    pub issues: Vec<AccountRoleIssue>,
+}
+
+/// Parsed `InitializeConfig` or `UpdateConfig` instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedConfigChange {
+    /// The program config account.
+    pub config: ParsedAccount,
+    /// The program upgrade authority when initializing, or the current
+    /// config authority when updating.
+    pub authority: ParsedAccount,
+    /// The audit log.
+    pub audit_log: ParsedAccount,
+    /// The config being stored.
+    pub new_config: ProgramConfig,
+    /// Account role problems that would cause the program to reject the
+    /// instruction.
+    pub issues: Vec<AccountRoleIssue>,
+}
+
+/// Parsed `ScheduleActivation` instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedScheduleActivation {
+    /// The feature account being created.
+    pub feature: ParsedAccount,
+    /// The account paying for the feature account.
+    pub payer: ParsedAccount,
+    /// The system program.
+    pub system_program: ParsedAccount,
+    /// The audit log.
+    pub audit_log: ParsedAccount,
+    /// The earliest epoch in which the feature may activate.
+    pub not_before_epoch: u64,
+    /// Account role problems that would cause the program to reject the
+    /// instruction.
+    pub issues: Vec<AccountRoleIssue>,
+}
+
+/// Parsed `ReleaseScheduledActivation` instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedReleaseScheduledActivation {
+    /// The scheduled feature account being released.
+    pub feature: ParsedAccount,
+    /// Account role problems that would cause the program to reject the
+    /// instruction.
+    pub issues: Vec<AccountRoleIssue>,
+}
+
+/// Parsed `InitializeAuditLog` or `ExtendAuditLog` instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedAuditLogAllocation {
+    /// The audit log account.
+    pub audit_log: ParsedAccount,
+    /// The account paying for the allocated space.
+    pub payer: ParsedAccount,
+    /// The system program.
+    pub system_program: ParsedAccount,
+    /// The number of entries allocated: the capacity when initializing, or
+    /// the additional entries when extending.
+    pub entries: u32,
+    /// Account role problems that would cause the program to reject the
+    /// instruction.
+    pub issues: Vec<AccountRoleIssue>,
+}
+
+/// Parsed `InitializeBundle` instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedInitializeBundle {
+    /// The bundle account being created.
+    pub bundle: ParsedAccount,
+    /// The bundle authority.
+    pub authority: ParsedAccount,
+    /// The account paying for the bundle account.
+    pub payer: ParsedAccount,
+    /// The system program.
+    pub system_program: ParsedAccount,
+    /// The bundle name.
+    pub name: String,
+    /// The member feature accounts.
+    pub members: Vec<ParsedAccount>,
+    /// Account role problems that would cause the program to reject the
+    /// instruction.
+    pub issues: Vec<AccountRoleIssue>,
+}
+
+/// Parsed `QueueBundle` or `RevokeBundle` instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedBundleAction {
+    /// The bundle account.
+    pub bundle: ParsedAccount,
+    /// The payer when queueing, or the incinerator when revoking.
+    pub funds: ParsedAccount,
+    /// The system program.
+    pub system_program: ParsedAccount,
+    /// The audit log.
+    pub audit_log: ParsedAccount,
+    /// The member feature accounts.
+    pub members: Vec<ParsedAccount>,
+    /// Account role problems that would cause the program to reject the
+    /// instruction.
+    pub issues: Vec<AccountRoleIssue>,
+}
+
+/// Instruction data that does not decode to a known instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedUnknownInstruction {
+    /// The leading byte of the instruction data, if any.
+    pub discriminator: Option<u8>,
+    /// The raw instruction data.
+    pub data: Vec<u8>,
+    /// The accounts referenced by the instruction.
+    pub accounts: Vec<ParsedAccount>,
+}
+
+/// A Feature Gate instruction decoded from a compiled instruction.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase", tag = "type", content = "info")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParsedFeatureGateInstruction {
+    /// `RevokePendingActivation`
+    RevokePendingActivation(ParsedRevokePendingActivation),
+    /// `SignalSupport`
+    SignalSupport(ParsedSignalSupport),
+    /// `InitializeStakeGuard`
+    InitializeStakeGuard(ParsedInitializeStakeGuard),
+    /// `TallySupport`
+    TallySupport(ParsedTallySupport),
+    /// `RevokeUnsupportedActivation`
+    RevokeUnsupportedActivation(ParsedRevokeUnsupportedActivation),
+    /// `InitializeConfig`
+    InitializeConfig(ParsedConfigChange),
+    /// `UpdateConfig`
+    UpdateConfig(ParsedConfigChange),
+    /// `ScheduleActivation`
+    ScheduleActivation(ParsedScheduleActivation),
+    /// `ReleaseScheduledActivation`
+    ReleaseScheduledActivation(ParsedReleaseScheduledActivation),
+    /// `InitializeAuditLog`
+    InitializeAuditLog(ParsedAuditLogAllocation),
+    /// `ExtendAuditLog`
+    ExtendAuditLog(ParsedAuditLogAllocation),
+    /// `InitializeBundle`
+    InitializeBundle(ParsedInitializeBundle),
+    /// `QueueBundle`
+    QueueBundle(ParsedBundleAction),
+    /// `RevokeBundle`
+    RevokeBundle(ParsedBundleAction),
+    /// Instruction data the parser does not recognize.
+    Unknown(ParsedUnknownInstruction),
+}
+
+impl ParsedFeatureGateInstruction {
+    /// The instruction name, or `"unknown"` for unrecognized data.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::RevokePendingActivation(_) => "revokePendingActivation",
+            Self::SignalSupport(_) => "signalSupport",
+            Self::InitializeStakeGuard(_) => "initializeStakeGuard",
+            Self::TallySupport(_) => "tallySupport",
+            Self::RevokeUnsupportedActivation(_) => "revokeUnsupportedActivation",
+            Self::InitializeConfig(_) => "initializeConfig",
+            Self::UpdateConfig(_) => "updateConfig",
+            Self::ScheduleActivation(_) => "scheduleActivation",
+            Self::ReleaseScheduledActivation(_) => "releaseScheduledActivation",
+            Self::InitializeAuditLog(_) => "initializeAuditLog",
+            Self::ExtendAuditLog(_) => "extendAuditLog",
+            Self::InitializeBundle(_) => "initializeBundle",
+            Self::QueueBundle(_) => "queueBundle",
+            Self::RevokeBundle(_) => "revokeBundle",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+
+    /// The feature ID the instruction operates on, if any.
+    pub fn feature_id(&self) -> Option<&Address> {
+        match self {
+            Self::RevokePendingActivation(parsed) => Some(&parsed.feature.address),
+            Self::SignalSupport(parsed) => Some(&parsed.feature.address),
+            Self::InitializeStakeGuard(parsed) => Some(&parsed.feature.address),
+            Self::RevokeUnsupportedActivation(parsed) => Some(&parsed.feature.address),
+            Self::ScheduleActivation(parsed) => Some(&parsed.feature.address),
+            Self::ReleaseScheduledActivation(parsed) => Some(&parsed.feature.address),
+            Self::TallySupport(_)
+            | Self::InitializeConfig(_)
+            | Self::UpdateConfig(_)
+            | Self::InitializeAuditLog(_)
+            | Self::ExtendAuditLog(_)
+            | Self::InitializeBundle(_)
+            | Self::QueueBundle(_)
+            | Self::RevokeBundle(_)
+            | Self::Unknown(_) => None,
+        }
+    }
+
+    /// Account role problems detected while parsing.
+    pub fn issues(&self) -> &[AccountRoleIssue] {
+        match self {
+            Self::RevokePendingActivation(parsed) => &parsed.issues,
+            Self::SignalSupport(parsed) => &parsed.issues,
+            Self::InitializeStakeGuard(parsed) => &parsed.issues,
+            Self::TallySupport(parsed) => &parsed.issues,
+            Self::RevokeUnsupportedActivation(parsed) => &parsed.issues,
+            Self::InitializeConfig(parsed) | Self::UpdateConfig(parsed) => &parsed.issues,
+            Self::ScheduleActivation(parsed) => &parsed.issues,
+            Self::ReleaseScheduledActivation(parsed) => &parsed.issues,
+            Self::InitializeAuditLog(parsed) | Self::ExtendAuditLog(parsed) => &parsed.issues,
+            Self::InitializeBundle(parsed) => &parsed.issues,
+            Self::QueueBundle(parsed) | Self::RevokeBundle(parsed) => &parsed.issues,
+            Self::Unknown(_) => &[],
+        }
+    }
+}
+
+/// Parses a compiled instruction targeting the Feature Gate program.
+///
+/// `account_keys` resolves the instruction's account indices, while `roles`
+/// provides the signer and writable privileges declared by the message.
+pub fn parse_instruction(
+    instruction: &CompiledInstruction,
+    account_keys: &AccountKeys,
+    roles: &impl AccountRoles,
+) -> Result<ParsedFeatureGateInstruction, ParseInstructionError> {
+    let program_id = account_keys
+        .get(usize::from(instruction.program_id_index))
+        .ok_or(ParseInstructionError::AccountIndexOutOfBounds(
+            instruction.program_id_index,
+        ))?;
+    if program_id != &ID {
+        return Err(ParseInstructionError::ProgramIdMismatch);
+    }
+
+    let accounts = instruction
+        .accounts
+        .iter()
+        .map(|&index| {
+            let address = account_keys
+                .get(usize::from(index))
+                .ok_or(ParseInstructionError::AccountIndexOutOfBounds(index))?;
+            Ok(ParsedAccount {
+                address: *address,
+                signer: roles.is_signer(usize::from(index)),
+                writable: roles.is_writable(usize::from(index)),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let data = instruction.data.as_slice();
+    match data {
+        [REVOKE_PENDING_ACTIVATION_DISCRIMINATOR] => {
+            return parse_revoke_pending_activation(accounts).map(Into::into);
+        }
+        [SIGNAL_SUPPORT_DISCRIMINATOR, args @ ..] => {
+            if let Ok(args) = SignalSupportInstructionArgs::try_from_slice(args) {
+                return parse_signal_support(accounts, &args).map(Into::into);
+            }
+        }
+        [INITIALIZE_STAKE_GUARD_DISCRIMINATOR, args @ ..] => {
+            if let Ok(args) = InitializeStakeGuardInstructionArgs::try_from_slice(args) {
+                return parse_initialize_stake_guard(accounts, &args).map(Into::into);
+            }
+        }
+        [TALLY_SUPPORT_DISCRIMINATOR] => {
+            return parse_tally_support(accounts).map(Into::into);
+        }
+        [REVOKE_UNSUPPORTED_ACTIVATION_DISCRIMINATOR] => {
+            return parse_revoke_unsupported_activation(accounts).map(Into::into);
+        }
+        [INITIALIZE_CONFIG_DISCRIMINATOR, args @ ..] if args.len() == CONFIG_SIZE - 1 => {
+            if let Ok(new_config) = ProgramConfig::from_instruction_args(args) {
+                return parse_initialize_config(accounts, new_config)
+                    .map(ParsedFeatureGateInstruction::InitializeConfig);
+            }
+        }
+        [UPDATE_CONFIG_DISCRIMINATOR, args @ ..] if args.len() == CONFIG_SIZE - 1 => {
+            if let Ok(new_config) = ProgramConfig::from_instruction_args(args) {
+                return parse_update_config(accounts, new_config)
+                    .map(ParsedFeatureGateInstruction::UpdateConfig);
+            }
+        }
+        [SCHEDULE_ACTIVATION_DISCRIMINATOR, args @ ..] => {
+            if let Ok(args) = ScheduleActivationInstructionArgs::try_from_slice(args) {
+                return parse_schedule_activation(accounts, &args).map(Into::into);
+            }
+        }
+        [RELEASE_SCHEDULED_ACTIVATION_DISCRIMINATOR] => {
+            return parse_release_scheduled_activation(accounts).map(Into::into);
+        }
+        [INITIALIZE_AUDIT_LOG_DISCRIMINATOR, args @ ..] => {
+            if let Ok(args) = InitializeAuditLogInstructionArgs::try_from_slice(args) {
+                return parse_audit_log_allocation(accounts, args.capacity)
+                    .map(ParsedFeatureGateInstruction::InitializeAuditLog);
+            }
+        }
+        [EXTEND_AUDIT_LOG_DISCRIMINATOR, args @ ..] => {
+            if let Ok(args) = ExtendAuditLogInstructionArgs::try_from_slice(args) {
+                return parse_audit_log_allocation(accounts, args.additional_entries)
+                    .map(ParsedFeatureGateInstruction::ExtendAuditLog);
+            }
+        }
+        [INITIALIZE_BUNDLE_DISCRIMINATOR, args @ ..] => {
+            if let Ok(args) = InitializeBundleInstructionArgs::try_from_slice(args) {
+                return parse_initialize_bundle(accounts, &args).map(Into::into);
+            }
+        }
+        [QUEUE_BUNDLE_DISCRIMINATOR] => {
+            return parse_bundle_action(accounts, false)
+                .map(ParsedFeatureGateInstruction::QueueBundle);
+        }
+        [REVOKE_BUNDLE_DISCRIMINATOR] => {
+            return parse_bundle_action(accounts, true)
+                .map(ParsedFeatureGateInstruction::RevokeBundle);
+        }
+        _ => {}
+    }
+    Ok(ParsedFeatureGateInstruction::Unknown(
+        ParsedUnknownInstruction {
+            discriminator: data.first().copied(),
+            data: data.to_vec(),
+            accounts,
+        },
+    ))
+}
+
+/// Parses every top-level Feature Gate instruction in a message.
+///
+/// Returns the index of each matching instruction alongside its parse result.
+/// `account_keys` must include any addresses loaded from lookup tables.
+pub fn parse_message(
+    message: &VersionedMessage,
+    account_keys: &AccountKeys,
+) -> Vec<(
+    usize,
+    Result<ParsedFeatureGateInstruction, ParseInstructionError>,
+)> {
+    message
+        .instructions()
+        .iter()
+        .enumerate()
+        .filter(|(_, instruction)| {
+            account_keys.get(usize::from(instruction.program_id_index)) == Some(&ID)
+        })
+        .map(|(index, instruction)| (index, parse_instruction(instruction, account_keys, message)))
+        .collect()
+}
+
+impl From<ParsedRevokePendingActivation> for ParsedFeatureGateInstruction {
+    fn from(parsed: ParsedRevokePendingActivation) -> Self {
+        Self::RevokePendingActivation(parsed)
+    }
+}
+
+impl From<ParsedSignalSupport> for ParsedFeatureGateInstruction {
+    fn from(parsed: ParsedSignalSupport) -> Self {
+        Self::SignalSupport(parsed)
+    }
+}
+
+impl From<ParsedInitializeStakeGuard> for ParsedFeatureGateInstruction {
+    fn from(parsed: ParsedInitializeStakeGuard) -> Self {
+        Self::InitializeStakeGuard(parsed)
+    }
+}
+
+impl From<ParsedTallySupport> for ParsedFeatureGateInstruction {
+    fn from(parsed: ParsedTallySupport) -> Self {
+        Self::TallySupport(parsed)
+    }
+}
+
+impl From<ParsedScheduleActivation> for ParsedFeatureGateInstruction {
+    fn from(parsed: ParsedScheduleActivation) -> Self {
+        Self::ScheduleActivation(parsed)
+    }
+}
+
+impl From<ParsedReleaseScheduledActivation> for ParsedFeatureGateInstruction {
+    fn from(parsed: ParsedReleaseScheduledActivation) -> Self {
+        Self::ReleaseScheduledActivation(parsed)
+    }
+}
+
+impl From<ParsedInitializeBundle> for ParsedFeatureGateInstruction {
+    fn from(parsed: ParsedInitializeBundle) -> Self {
+        Self::InitializeBundle(parsed)
+    }
+}
+
+impl From<ParsedRevokeUnsupportedActivation> for ParsedFeatureGateInstruction {
+    fn from(parsed: ParsedRevokeUnsupportedActivation) -> Self {
+        Self::RevokeUnsupportedActivation(parsed)
+    }
+}
+
+fn parse_revoke_pending_activation(
+    accounts: Vec<ParsedAccount>,
+) -> Result<ParsedRevokePendingActivation, ParseInstructionError> {
+    let [feature, incinerator, system_program, rest @ ..] = accounts.as_slice() else {
+        return Err(ParseInstructionError::NotEnoughAccounts {
+            expected: 3,
+            actual: accounts.len(),
+        });
+    };
+    // The optional audit log and config are identified by address, and the
+    // program ID stands in for either when omitted.
+    let (config_address, _) = find_config_address();
+    let (audit_log_address, _) = find_audit_log_address();
+    let config = rest
+        .iter()
+        .find(|account| account.address == config_address);
+    let audit_log = rest
+        .iter()
+        .find(|account| account.address == audit_log_address);
+    // Lamports go to the incinerator unless it is replaced by a refund
+    // recipient.
+    let recipient_name = if incinerator.address == incinerator::id() {
+        "incinerator"
+    } else {
+        "refundRecipient"
+    };
+
+    let mut issues = Vec::new();
+    issues.extend(
+        rest.iter()
+            .filter(|account| {
+                ![config_address, audit_log_address, crate::ID].contains(&account.address)
+            })
+            .map(|account| AccountRoleIssue::UnexpectedAccount {
+                address: account.address,
+            }),
+    );
+    if !feature.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "feature".to_string(),
+        });
+    }
+    if !feature.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "feature".to_string(),
+        });
+    }
+    // Whether a refund recipient is allowed depends on the config's contents,
+    // so only a missing config is reported.
+    if incinerator.address != incinerator::id() && config.is_none() {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: recipient_name.to_string(),
+            expected: incinerator::id(),
+        });
+    }
+    if !incinerator.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: recipient_name.to_string(),
+        });
+    }
+    if system_program.address != system_program::id() {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: "systemProgram".to_string(),
+            expected: system_program::id(),
+        });
+    }
+    if let Some(audit_log) = audit_log {
+        check_audit_log(audit_log, &mut issues);
+    }
+
+    Ok(ParsedRevokePendingActivation {
+        feature: feature.clone(),
+        incinerator: incinerator.clone(),
+        system_program: system_program.clone(),
+        audit_log: audit_log.cloned(),
+        config: config.cloned(),
+        issues,
+    })
+}
+
+fn parse_signal_support(
+    accounts: Vec<ParsedAccount>,
+    args: &SignalSupportInstructionArgs,
+) -> Result<ParsedSignalSupport, ParseInstructionError> {
+    let [support_signal, feature, vote_account, authority, payer, system_program, ..] =
+        accounts.as_slice()
+    else {
+        return Err(ParseInstructionError::NotEnoughAccounts {
+            expected: 6,
+            actual: accounts.len(),
+        });
+    };
+
+    let mut issues = Vec::new();
+    if !support_signal.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "supportSignal".to_string(),
+        });
+    }
+    if !authority.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "authority".to_string(),
+        });
+    }
+    if !payer.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "payer".to_string(),
+        });
+    }
+    if !payer.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "payer".to_string(),
+        });
+    }
+    if system_program.address != system_program::id() {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: "systemProgram".to_string(),
+            expected: system_program::id(),
+        });
+    }
+
+    Ok(ParsedSignalSupport {
+        support_signal: support_signal.clone(),
+        feature: feature.clone(),
+        vote_account: vote_account.clone(),
+        authority: authority.clone(),
+        payer: payer.clone(),
+        system_program: system_program.clone(),
+        version: format!("{}.{}.{}", args.major, args.minor, args.patch),
+        issues,
+    })
+}
+
+fn parse_initialize_stake_guard(
+    accounts: Vec<ParsedAccount>,
+    args: &InitializeStakeGuardInstructionArgs,
+) -> Result<ParsedInitializeStakeGuard, ParseInstructionError> {
+    let [stake_guard, feature, payer, system_program, ..] = accounts.as_slice() else {
+        return Err(ParseInstructionError::NotEnoughAccounts {
+            expected: 4,
+            actual: accounts.len(),
+        });
+    };
+
+    let mut issues = Vec::new();
+    if !stake_guard.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "stakeGuard".to_string(),
+        });
+    }
+    if !feature.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "feature".to_string(),
+        });
+    }
+    if !payer.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "payer".to_string(),
+        });
+    }
+    if !payer.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "payer".to_string(),
+        });
+    }
+    if system_program.address != system_program::id() {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: "systemProgram".to_string(),
+            expected: system_program::id(),
+        });
+    }
+
+    Ok(ParsedInitializeStakeGuard {
+        stake_guard: stake_guard.clone(),
+        feature: feature.clone(),
+        payer: payer.clone(),
+        system_program: system_program.clone(),
+        threshold_bps: args.threshold_bps,
+        issues,
+    })
+}
+
+fn parse_tally_support(
+    accounts: Vec<ParsedAccount>,
+) -> Result<ParsedTallySupport, ParseInstructionError> {
+    let [stake_guard, support_signals @ ..] = accounts.as_slice() else {
+        return Err(ParseInstructionError::NotEnoughAccounts {
+            expected: 1,
+            actual: accounts.len(),
+        });
+    };
+
+    let mut issues = Vec::new();
+    if !stake_guard.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "stakeGuard".to_string(),
+        });
+    }
+    for (index, support_signal) in support_signals.iter().enumerate() {
+        if !support_signal.writable {
+            issues.push(AccountRoleIssue::NotWritable {
+                account: format!("supportSignals[{index}]"),
+            });
+        }
+    }
+
+    Ok(ParsedTallySupport {
+        stake_guard: stake_guard.clone(),
+        support_signals: support_signals.to_vec(),
+        issues,
+    })
+}
+
+fn parse_revoke_unsupported_activation(
+    accounts: Vec<ParsedAccount>,
+) -> Result<ParsedRevokeUnsupportedActivation, ParseInstructionError> {
+    let [feature, stake_guard, incinerator, config, audit_log, ..] = accounts.as_slice() else {
+        return Err(ParseInstructionError::NotEnoughAccounts {
+            expected: 5,
+            actual: accounts.len(),
+        });
+    };
+
+    let mut issues = Vec::new();
+    if !feature.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "feature".to_string(),
+        });
+    }
+    if !stake_guard.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "stakeGuard".to_string(),
+        });
+    }
+    if incinerator.address != incinerator::id() {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: "incinerator".to_string(),
+            expected: incinerator::id(),
+        });
+    }
+    if !incinerator.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "incinerator".to_string(),
+        });
+    }
+
+    check_config_address(config, &mut issues);
+    check_audit_log(audit_log, &mut issues);
+
+    Ok(ParsedRevokeUnsupportedActivation {
+        feature: feature.clone(),
+        stake_guard: stake_guard.clone(),
+        incinerator: incinerator.clone(),
+        config: config.clone(),
+        audit_log: audit_log.clone(),
+        issues,
+    })
+}
+
+fn parse_initialize_config(
+    accounts: Vec<ParsedAccount>,
+    new_config: ProgramConfig,
+) -> Result<ParsedConfigChange, ParseInstructionError> {
+    let [config, upgrade_authority, _program_data, payer, system_program, audit_log, ..] =
+        accounts.as_slice()
+    else {
+        return Err(ParseInstructionError::NotEnoughAccounts {
+            expected: 6,
+            actual: accounts.len(),
+        });
+    };
+
+    let mut issues = Vec::new();
+    check_config_address(config, &mut issues);
+    if !config.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "config".to_string(),
+        });
+    }
+    if !upgrade_authority.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "upgradeAuthority".to_string(),
+        });
+    }
+    if !payer.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "payer".to_string(),
+        });
+    }
+    if !payer.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "payer".to_string(),
+        });
+    }
+    if system_program.address != system_program::id() {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: "systemProgram".to_string(),
+            expected: system_program::id(),
+        });
+    }
+    check_audit_log(audit_log, &mut issues);
+
+    Ok(ParsedConfigChange {
+        config: config.clone(),
+        authority: upgrade_authority.clone(),
+        audit_log: audit_log.clone(),
+        new_config,
+        issues,
+    })
+}
+
+fn parse_update_config(
+    accounts: Vec<ParsedAccount>,
+    new_config: ProgramConfig,
+) -> Result<ParsedConfigChange, ParseInstructionError> {
+    let [config, config_authority, audit_log, ..] = accounts.as_slice() else {
+        return Err(ParseInstructionError::NotEnoughAccounts {
+            expected: 3,
+            actual: accounts.len(),
+        });
+    };
+
+    let mut issues = Vec::new();
+    check_config_address(config, &mut issues);
+    if !config.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "config".to_string(),
+        });
+    }
+    if !config_authority.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "configAuthority".to_string(),
+        });
+    }
+    check_audit_log(audit_log, &mut issues);
+
+    Ok(ParsedConfigChange {
+        config: config.clone(),
+        authority: config_authority.clone(),
+        audit_log: audit_log.clone(),
+        new_config,
+        issues,
+    })
+}
+
+fn parse_schedule_activation(
+    accounts: Vec<ParsedAccount>,
+    args: &ScheduleActivationInstructionArgs,
+) -> Result<ParsedScheduleActivation, ParseInstructionError> {
+    let [feature, payer, system_program, audit_log, ..] = accounts.as_slice() else {
+        return Err(ParseInstructionError::NotEnoughAccounts {
+            expected: 4,
+            actual: accounts.len(),
+        });
+    };
+
+    let mut issues = Vec::new();
+    if !feature.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "feature".to_string(),
+        });
+    }
+    if !feature.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "feature".to_string(),
+        });
+    }
+    if !payer.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "payer".to_string(),
+        });
+    }
+    if !payer.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "payer".to_string(),
+        });
+    }
+    if system_program.address != system_program::id() {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: "systemProgram".to_string(),
+            expected: system_program::id(),
+        });
+    }
+    check_audit_log(audit_log, &mut issues);
+
+    Ok(ParsedScheduleActivation {
+        feature: feature.clone(),
+        payer: payer.clone(),
+        system_program: system_program.clone(),
+        audit_log: audit_log.clone(),
+        not_before_epoch: args.not_before_epoch,
+        issues,
+    })
+}
+
+fn parse_release_scheduled_activation(
+    accounts: Vec<ParsedAccount>,
+) -> Result<ParsedReleaseScheduledActivation, ParseInstructionError> {
+    let [feature, ..] = accounts.as_slice() else {
+        return Err(ParseInstructionError::NotEnoughAccounts {
+            expected: 1,
+            actual: accounts.len(),
+        });
+    };
+
+    let mut issues = Vec::new();
+    if !feature.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "feature".to_string(),
+        });
+    }
+
+    Ok(ParsedReleaseScheduledActivation {
+        feature: feature.clone(),
+        issues,
+    })
+}
+
+fn parse_audit_log_allocation(
+    accounts: Vec<ParsedAccount>,
+    entries: u32,
+) -> Result<ParsedAuditLogAllocation, ParseInstructionError> {
+    let [audit_log, payer, system_program, ..] = accounts.as_slice() else {
+        return Err(ParseInstructionError::NotEnoughAccounts {
+            expected: 3,
+            actual: accounts.len(),
+        });
+    };
+
+    let mut issues = Vec::new();
+    check_audit_log(audit_log, &mut issues);
+    if !payer.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "payer".to_string(),
+        });
+    }
+    if !payer.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "payer".to_string(),
+        });
+    }
+    if system_program.address != system_program::id() {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: "systemProgram".to_string(),
+            expected: system_program::id(),
+        });
+    }
+
+    Ok(ParsedAuditLogAllocation {
+        audit_log: audit_log.clone(),
+        payer: payer.clone(),
+        system_program: system_program.clone(),
+        entries,
+        issues,
+    })
+}
+
+fn parse_initialize_bundle(
+    accounts: Vec<ParsedAccount>,
+    args: &InitializeBundleInstructionArgs,
+) -> Result<ParsedInitializeBundle, ParseInstructionError> {
+    let [bundle, authority, payer, system_program, members @ ..] = accounts.as_slice() else {
+        return Err(ParseInstructionError::NotEnoughAccounts {
+            expected: 4,
+            actual: accounts.len(),
+        });
+    };
+
+    let mut issues = Vec::new();
+    if !bundle.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "bundle".to_string(),
+        });
+    }
+    if !authority.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "authority".to_string(),
+        });
+    }
+    if !payer.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: "payer".to_string(),
+        });
+    }
+    if !payer.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "payer".to_string(),
+        });
+    }
+    if system_program.address != system_program::id() {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: "systemProgram".to_string(),
+            expected: system_program::id(),
+        });
+    }
+
+    Ok(ParsedInitializeBundle {
+        bundle: bundle.clone(),
+        authority: authority.clone(),
+        payer: payer.clone(),
+        system_program: system_program.clone(),
+        name: decode_name(&args.name),
+        members: members.to_vec(),
+        issues,
+    })
+}
+
+/// Parses a `QueueBundle` or, if `revoke` is set, a `RevokeBundle`
+/// instruction.
+fn parse_bundle_action(
+    accounts: Vec<ParsedAccount>,
+    revoke: bool,
+) -> Result<ParsedBundleAction, ParseInstructionError> {
+    let [bundle, funds_account, system_program, audit_log, members @ ..] = accounts.as_slice()
+    else {
+        return Err(ParseInstructionError::NotEnoughAccounts {
+            expected: 4,
+            actual: accounts.len(),
+        });
+    };
+
+    let funds = if revoke { "incinerator" } else { "payer" };
+    let mut issues = Vec::new();
+    if !funds_account.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: funds.to_string(),
+        });
+    }
+    if !revoke && !funds_account.signer {
+        issues.push(AccountRoleIssue::MissingSigner {
+            account: funds.to_string(),
+        });
+    }
+    if revoke && funds_account.address != incinerator::id() {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: funds.to_string(),
+            expected: incinerator::id(),
+        });
+    }
+    if system_program.address != system_program::id() {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: "systemProgram".to_string(),
+            expected: system_program::id(),
+        });
+    }
+    check_audit_log(audit_log, &mut issues);
+    for (index, member) in members.iter().enumerate() {
+        if !member.signer {
+            issues.push(AccountRoleIssue::MissingSigner {
+                account: format!("members[{index}]"),
+            });
+        }
+        if !member.writable {
+            issues.push(AccountRoleIssue::NotWritable {
+                account: format!("members[{index}]"),
+            });
+        }
+    }
+
+    Ok(ParsedBundleAction {
+        bundle: bundle.clone(),
+        funds: funds_account.clone(),
+        system_program: system_program.clone(),
+        audit_log: audit_log.clone(),
+        members: members.to_vec(),
+        issues,
+    })
+}
+
+fn check_config_address(config: &ParsedAccount, issues: &mut Vec<AccountRoleIssue>) {
+    let (expected, _) = find_config_address();
+    if config.address != expected {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: "config".to_string(),
+            expected,
+        });
+    }
+}
+
+fn check_audit_log(audit_log: &ParsedAccount, issues: &mut Vec<AccountRoleIssue>) {
+    let (expected, _) = find_audit_log_address();
+    if audit_log.address != expected {
+        issues.push(AccountRoleIssue::UnexpectedAddress {
+            account: "auditLog".to_string(),
+            expected,
+        });
+    }
+    if !audit_log.writable {
+        issues.push(AccountRoleIssue::NotWritable {
+            account: "auditLog".to_string(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::instructions::{
+            InitializeStakeGuardBuilder, RevokePendingActivationBuilder,
+            RevokeUnsupportedActivationBuilder, SignalSupportBuilder, TallySupportBuilder,
+        },
+        solana_message::{Message, VersionedMessage},
+    };
+
+    fn compile(feature: &Address, payer: &Address) -> VersionedMessage {
+        let instruction = RevokePendingActivationBuilder::new()
+            .feature(*feature)
+            .incinerator(incinerator::id())
+            .audit_log(Some(find_audit_log_address().0))
+            .instruction();
+        VersionedMessage::Legacy(Message::new(&[instruction], Some(payer)))
+    }
+
+    #[test]
+    fn test_parse_revoke_pending_activation() {
+        let feature = Address::new_unique();
+        let payer = Address::new_unique();
+        let message = compile(&feature, &payer);
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let parsed = parse_message(&message, &account_keys);
+        assert_eq!(parsed.len(), 1);
+        let (index, parsed) = &parsed[0];
+        assert_eq!(*index, 0);
+        let parsed = parsed.as_ref().unwrap();
+
+        assert_eq!(parsed.name(), "revokePendingActivation");
+        assert_eq!(parsed.feature_id(), Some(&feature));
+        assert!(parsed.issues().is_empty());
+        let ParsedFeatureGateInstruction::RevokePendingActivation(revoke) = parsed else {
+            panic!("expected `RevokePendingActivation`");
+        };
+        assert_eq!(revoke.incinerator.address, incinerator::id());
+        assert!(revoke.feature.signer);
+        assert!(revoke.incinerator.writable);
+    }
+
+    #[test]
+    fn test_parse_reports_role_issues() {
+        let feature = Address::new_unique();
+        let payer = Address::new_unique();
+        let wrong_incinerator = Address::new_unique();
+        let mut instruction = RevokePendingActivationBuilder::new()
+            .feature(feature)
+            .incinerator(wrong_incinerator)
+            .audit_log(Some(find_audit_log_address().0))
+            .instruction();
+        instruction.accounts[0].is_signer = false;
+        let message = VersionedMessage::Legacy(Message::new(&[instruction], Some(&payer)));
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let parsed =
+            parse_instruction(&message.instructions()[0], &account_keys, &message).unwrap();
+        assert_eq!(
+            parsed.issues(),
+            &[
+                AccountRoleIssue::MissingSigner {
+                    account: "feature".to_string(),
+                },
+                AccountRoleIssue::UnexpectedAddress {
+                    account: "refundRecipient".to_string(),
+                    expected: incinerator::id(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_revoke_without_audit_log() {
+        let feature = Address::new_unique();
+        let mut instruction = RevokePendingActivationBuilder::new()
+            .feature(feature)
+            .incinerator(incinerator::id())
+            .instruction();
+        instruction.accounts.truncate(3);
+        let message =
+            VersionedMessage::Legacy(Message::new(&[instruction.clone()], Some(&feature)));
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let parsed =
+            parse_instruction(&message.instructions()[0], &account_keys, &message).unwrap();
+        assert!(parsed.issues().is_empty());
+        let ParsedFeatureGateInstruction::RevokePendingActivation(revoke) = parsed else {
+            panic!("expected `RevokePendingActivation`");
+        };
+        assert_eq!(revoke.audit_log, None);
+        assert_eq!(revoke.config, None);
+
+        // Accounts the instruction does not take are reported by address, and
+        // the incinerator keeps its name.
+        let extra = Address::new_unique();
+        instruction.accounts[1].is_writable = false;
+        instruction
+            .accounts
+            .push(solana_instruction::AccountMeta::new_readonly(extra, false));
+        let message = VersionedMessage::Legacy(Message::new(&[instruction], Some(&feature)));
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+        let parsed =
+            parse_instruction(&message.instructions()[0], &account_keys, &message).unwrap();
+        assert_eq!(
+            parsed.issues(),
+            &[
+                AccountRoleIssue::UnexpectedAccount { address: extra },
+                AccountRoleIssue::NotWritable {
+                    account: "incinerator".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_signal_support() {
+        let feature = Address::new_unique();
+        let vote_account = Address::new_unique();
+        let authority = Address::new_unique();
+        let payer = Address::new_unique();
+        let instruction = SignalSupportBuilder::new()
+            .support_signal(Address::new_unique())
+            .feature(feature)
+            .vote_account(vote_account)
+            .authority(authority)
+            .payer(payer)
+            .major(3)
+            .minor(1)
+            .patch(8)
+            .instruction();
+        let message = VersionedMessage::Legacy(Message::new(&[instruction], Some(&payer)));
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let parsed =
+            parse_instruction(&message.instructions()[0], &account_keys, &message).unwrap();
+        assert_eq!(parsed.name(), "signalSupport");
+        assert_eq!(parsed.feature_id(), Some(&feature));
+        assert!(parsed.issues().is_empty());
+        let ParsedFeatureGateInstruction::SignalSupport(signal) = parsed else {
+            panic!("expected `SignalSupport`");
+        };
+        assert_eq!(signal.vote_account.address, vote_account);
+        assert!(signal.authority.signer);
+        assert_eq!(signal.version, "3.1.8");
+
+        // Truncated arguments are not a `SignalSupport` instruction.
+        let mut instruction = message.instructions()[0].clone();
+        instruction.data.pop();
+        let parsed = parse_instruction(&instruction, &account_keys, &message).unwrap();
+        assert_eq!(parsed.name(), "unknown");
+    }
+
+    #[test]
+    fn test_parse_stake_guard_instructions() {
+        let feature = Address::new_unique();
+        let stake_guard = Address::new_unique();
+        let payer = Address::new_unique();
+        let support_signals = [Address::new_unique(), Address::new_unique()];
+        let instructions = [
+            InitializeStakeGuardBuilder::new()
+                .stake_guard(stake_guard)
+                .feature(feature)
+                .payer(payer)
+                .threshold_bps(6_667)
+                .instruction(),
+            TallySupportBuilder::new()
+                .stake_guard(stake_guard)
+                .add_remaining_accounts(
+                    &support_signals
+                        .map(|address| solana_instruction::AccountMeta::new(address, false)),
+                )
+                .instruction(),
+            RevokeUnsupportedActivationBuilder::new()
+                .feature(feature)
+                .stake_guard(stake_guard)
+                .incinerator(incinerator::id())
+                .config(find_config_address().0)
+                .audit_log(find_audit_log_address().0)
+                .instruction(),
+        ];
+        let message = VersionedMessage::Legacy(Message::new(&instructions, Some(&payer)));
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let parsed = parse_message(&message, &account_keys)
+            .into_iter()
+            .map(|(_, parsed)| parsed.unwrap())
+            .collect::<Vec<_>>();
+        assert!(parsed.iter().all(|parsed| parsed.issues().is_empty()));
+        let [ParsedFeatureGateInstruction::InitializeStakeGuard(initialize), ParsedFeatureGateInstruction::TallySupport(tally), ParsedFeatureGateInstruction::RevokeUnsupportedActivation(revoke)] =
+            parsed.as_slice()
+        else {
+            panic!("expected stake guard instructions, got {parsed:?}");
+        };
+        assert_eq!(initialize.threshold_bps, 6_667);
+        assert!(initialize.feature.signer);
+        assert_eq!(
+            tally
+                .support_signals
+                .iter()
+                .map(|account| account.address)
+                .collect::<Vec<_>>(),
+            support_signals
+        );
+        assert_eq!(parsed[1].feature_id(), None);
+        assert_eq!(revoke.stake_guard.address, stake_guard);
+        assert_eq!(parsed[2].feature_id(), Some(&feature));
+    }
+
+    #[test]
+    fn test_parse_revoke_with_refund() {
+        let feature = Address::new_unique();
+        let recipient = Address::new_unique();
+        let mut instruction = RevokePendingActivationBuilder::new()
+            .feature(feature)
+            .incinerator(recipient)
+            .add_remaining_account(solana_instruction::AccountMeta::new_readonly(
+                find_config_address().0,
+                false,
+            ))
+            .audit_log(Some(find_audit_log_address().0))
+            .instruction();
+        let message =
+            VersionedMessage::Legacy(Message::new(&[instruction.clone()], Some(&feature)));
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let parsed =
+            parse_instruction(&message.instructions()[0], &account_keys, &message).unwrap();
+        assert!(parsed.issues().is_empty());
+        let ParsedFeatureGateInstruction::RevokePendingActivation(revoke) = parsed else {
+            panic!("expected `RevokePendingActivation`");
+        };
+        assert_eq!(revoke.incinerator.address, recipient);
+        assert_eq!(
+            revoke.config.map(|config| config.address),
+            Some(find_config_address().0)
+        );
+
+        // The config is found by address before the audit log.
+        let mut reordered = instruction.clone();
+        reordered.accounts.swap(3, 4);
+        let message = VersionedMessage::Legacy(Message::new(&[reordered], Some(&feature)));
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+        let parsed =
+            parse_instruction(&message.instructions()[0], &account_keys, &message).unwrap();
+        assert!(parsed.issues().is_empty());
+        let ParsedFeatureGateInstruction::RevokePendingActivation(revoke) = parsed else {
+            panic!("expected `RevokePendingActivation`");
+        };
+        assert_eq!(
+            revoke.config.map(|config| config.address),
+            Some(find_config_address().0)
+        );
+
+        // Without the config, the recipient is reported.
+        instruction.accounts.pop();
+        let message = VersionedMessage::Legacy(Message::new(&[instruction], Some(&feature)));
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+        let parsed =
+            parse_instruction(&message.instructions()[0], &account_keys, &message).unwrap();
+        assert_eq!(
+            parsed.issues(),
+            &[AccountRoleIssue::UnexpectedAddress {
+                account: "refundRecipient".to_string(),
+                expected: incinerator::id(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_config_instructions() {
+        let authority = Address::new_unique();
+        let upgrade_authority = Address::new_unique();
+        let new_config = ProgramConfig {
+            authority: Some(authority),
+            refund_recipients: vec![Address::new_unique()],
+            ..ProgramConfig::default()
+        };
+        let instructions = [
+            new_config
+                .initialize_instruction(&authority, &upgrade_authority, &upgrade_authority)
+                .unwrap(),
+            new_config
+                .update_instruction(&authority, &authority)
+                .unwrap(),
+        ];
+        let message =
+            VersionedMessage::Legacy(Message::new(&instructions, Some(&upgrade_authority)));
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let parsed = parse_message(&message, &account_keys)
+            .into_iter()
+            .map(|(_, parsed)| parsed.unwrap())
+            .collect::<Vec<_>>();
+        let [ParsedFeatureGateInstruction::InitializeConfig(initialize), ParsedFeatureGateInstruction::UpdateConfig(update)] =
+            parsed.as_slice()
+        else {
+            panic!("expected config instructions, got {parsed:?}");
+        };
+        assert!(initialize.issues.is_empty());
+        assert_eq!(initialize.authority.address, upgrade_authority);
+        assert_eq!(initialize.new_config, new_config);
+        assert!(update.issues.is_empty());
+        assert_eq!(update.authority.address, authority);
+        assert_eq!(parsed[1].name(), "updateConfig");
+    }
+
+    #[test]
+    fn test_parse_scheduled_activation_instructions() {
+        let feature = Address::new_unique();
+        let payer = Address::new_unique();
+        let instructions = [
+            crate::schedule::schedule_activation_instruction(&feature, &payer, 42),
+            crate::schedule::release_scheduled_activation_instruction(&feature),
+        ];
+        let message = VersionedMessage::Legacy(Message::new(&instructions, Some(&payer)));
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let parsed = parse_message(&message, &account_keys)
+            .into_iter()
+            .map(|(_, parsed)| parsed.unwrap())
+            .collect::<Vec<_>>();
+        assert!(parsed.iter().all(|parsed| parsed.issues().is_empty()));
+        let [ParsedFeatureGateInstruction::ScheduleActivation(schedule), ParsedFeatureGateInstruction::ReleaseScheduledActivation(_)] =
+            parsed.as_slice()
+        else {
+            panic!("expected scheduled activation instructions, got {parsed:?}");
+        };
+        assert_eq!(schedule.not_before_epoch, 42);
+        assert!(schedule.feature.signer);
+        assert_eq!(parsed[1].name(), "releaseScheduledActivation");
+        assert_eq!(parsed[1].feature_id(), Some(&feature));
+    }
+
+    #[test]
+    fn test_parse_audit_log_instructions() {
+        let payer = Address::new_unique();
+        let instructions = [
+            crate::audit_log::initialize_instruction(&payer, 64),
+            crate::audit_log::extend_instruction(&payer, 16),
+        ];
+        let message = VersionedMessage::Legacy(Message::new(&instructions, Some(&payer)));
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let parsed = parse_message(&message, &account_keys)
+            .into_iter()
+            .map(|(_, parsed)| parsed.unwrap())
+            .collect::<Vec<_>>();
+        assert!(parsed.iter().all(|parsed| parsed.issues().is_empty()));
+        let [ParsedFeatureGateInstruction::InitializeAuditLog(initialize), ParsedFeatureGateInstruction::ExtendAuditLog(extend)] =
+            parsed.as_slice()
+        else {
+            panic!("expected audit log instructions, got {parsed:?}");
+        };
+        assert_eq!(initialize.entries, 64);
+        assert_eq!(extend.entries, 16);
+        assert_eq!(
+            initialize.audit_log.address,
+            crate::audit_log::find_audit_log_address().0
+        );
+        assert_eq!(parsed[1].name(), "extendAuditLog");
+        assert_eq!(parsed[1].feature_id(), None);
+    }
+
+    #[test]
+    fn test_parse_bundle_instructions() {
+        let authority = Address::new_unique();
+        let members = vec![Address::new_unique(), Address::new_unique()];
+        let bundle = crate::bundle::FeatureBundle {
+            address: crate::bundle::find_bundle_address(&authority, "core-bpf")
+                .unwrap()
+                .0,
+            authority,
+            name: "core-bpf".to_string(),
+            members: members.clone(),
+        };
+        let instructions = [
+            crate::bundle::initialize_bundle_instruction(
+                &authority, &authority, "core-bpf", &members,
+            )
+            .unwrap(),
+            bundle.queue_instruction(&authority),
+            bundle.revoke_instruction(),
+        ];
+        let message = VersionedMessage::Legacy(Message::new(&instructions, Some(&authority)));
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let parsed = parse_message(&message, &account_keys)
+            .into_iter()
+            .map(|(_, parsed)| parsed.unwrap())
+            .collect::<Vec<_>>();
+        assert!(parsed.iter().all(|parsed| parsed.issues().is_empty()));
+        let [ParsedFeatureGateInstruction::InitializeBundle(initialize), ParsedFeatureGateInstruction::QueueBundle(queue), ParsedFeatureGateInstruction::RevokeBundle(revoke)] =
+            parsed.as_slice()
+        else {
+            panic!("expected bundle instructions, got {parsed:?}");
+        };
+        assert_eq!(initialize.name, "core-bpf");
+        assert_eq!(initialize.members.len(), 2);
+        assert_eq!(queue.members.len(), 2);
+        assert_eq!(queue.audit_log.address, find_audit_log_address().0);
+        assert_eq!(revoke.members.len(), 2);
+        assert_eq!(revoke.funds.address, incinerator::id());
+        assert_eq!(revoke.audit_log.address, find_audit_log_address().0);
+        assert_eq!(parsed[2].name(), "revokeBundle");
+    }
+
+    #[test]
+    fn test_parse_unknown_discriminator() {
+        let feature = Address::new_unique();
+        let payer = Address::new_unique();
+        let message = compile(&feature, &payer);
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let mut instruction = message.instructions()[0].clone();
+        instruction.data = vec![42, 1];
+        let parsed = parse_instruction(&instruction, &account_keys, &message).unwrap();
+        assert_eq!(parsed.name(), "unknown");
+        let ParsedFeatureGateInstruction::Unknown(unknown) = parsed else {
+            panic!("expected `Unknown`");
+        };
+        assert_eq!(unknown.discriminator, Some(42));
+        assert_eq!(unknown.accounts.len(), 4);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let feature = Address::new_unique();
+        let payer = Address::new_unique();
+        let message = compile(&feature, &payer);
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let mut instruction = message.instructions()[0].clone();
+        instruction.accounts.truncate(2);
+        assert_eq!(
+            parse_instruction(&instruction, &account_keys, &message),
+            Err(ParseInstructionError::NotEnoughAccounts {
+                expected: 3,
+                actual: 2,
+            })
+        );
+
+        instruction.accounts.push(200);
+        assert_eq!(
+            parse_instruction(&instruction, &account_keys, &message),
+            Err(ParseInstructionError::AccountIndexOutOfBounds(200))
+        );
+
+        let mut instruction = message.instructions()[0].clone();
+        instruction.program_id_index = 0;
+        assert_eq!(
+            parse_instruction(&instruction, &account_keys, &message),
+            Err(ParseInstructionError::ProgramIdMismatch)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parse_serializes_to_json() {
+        let feature = Address::new_unique();
+        let payer = Address::new_unique();
+        let message = compile(&feature, &payer);
+        let account_keys = AccountKeys::new(message.static_account_keys(), None);
+
+        let parsed =
+            parse_instruction(&message.instructions()[0], &account_keys, &message).unwrap();
+        let json = serde_json::to_value(&parsed).unwrap();
+        assert_eq!(json["type"], "revokePendingActivation");
+        assert_eq!(json["info"]["feature"]["address"], feature.to_string());
+        assert_eq!(
+            json["info"]["incinerator"]["address"],
+            incinerator::id().to_string()
+        );
+    }
+}
@@ -0,0 +1,614 @@
+//! Validator readiness for pending features.
+//!
+//! Validators signal that they run software supporting a pending feature with
+//! the `SignalSupport` instruction, which records their software version in a
+//! support signal account derived from the feature and their vote account.
+//! [`FeatureReadiness`] sums the stake delegated to the signaling vote
+//! accounts and compares it with the cluster's total stake, so that
+//! governance can judge whether a feature is safe to activate before the
+//! epoch boundary.
+//!
+//! Stake is the effective stake of each delegation in the current epoch,
+//! computed from the stake history with the same warmup and cooldown rules
+//! the runtime applies.
+//!
+//! Features guarded with `InitializeStakeGuard` can be revoked by anyone near
+//! the end of an epoch unless enough supporting stake was tallied on-chain
+//! that epoch. [`FeatureReadiness::tally_support_instructions`] builds the
+//! `TallySupport` instructions that record it.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use solana_feature_gate_program_interface::state::{
+    find_stake_guard_address, find_support_signal_address, STAKE_GUARD_SEED, STAKE_GUARD_TAG,
+    SUPPORT_SIGNAL_SEED, SUPPORT_SIGNAL_TAG,
+};
+use {
+    crate::{accounts::SupportSignal, instructions::TallySupportBuilder},
+    solana_address::Address,
+    solana_clock::Epoch,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_stake_interface::{stake_history::StakeHistory, state::Delegation},
+    std::collections::HashMap,
+};
+#[cfg(feature = "fetch")]
+use {
+    solana_clock::Slot,
+    solana_feature_gate_interface::from_account,
+    solana_rpc_client::{
+        api::{
+            client_error::Error as ClientError,
+            config::RpcProgramAccountsConfig,
+            filter::{Memcmp, RpcFilterType},
+        },
+        rpc_client::RpcClient,
+    },
+    solana_stake_interface::state::StakeStateV2,
+    thiserror::Error,
+};
+
+/// The feature that lowered the stake warmup and cooldown rate, which
+/// changes how much of a delegation is effective in a given epoch.
+pub const REDUCE_STAKE_WARMUP_COOLDOWN: Address =
+    solana_address::address!("GwtDQBghCTBgmX2cpEGNPxTEBUTQRaDMGTr5qychdGMj");
+
+/// Errors that can occur while fetching readiness from a cluster.
+#[cfg(feature = "fetch")]
+#[derive(Debug, Error)]
+pub enum ReadinessError {
+    /// The RPC request failed.
+    #[error("RPC error: {0}")]
+    Rpc(#[from] Box<ClientError>),
+    /// The stake history sysvar could not be decoded.
+    #[error("Invalid stake history: {0}")]
+    InvalidStakeHistory(#[from] bincode::Error),
+}
+
+/// The effective stake delegated to each vote account in an epoch.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VoteAccountStakes {
+    epoch: Epoch,
+    stakes: HashMap<Address, u64>,
+    total: u64,
+}
+
+impl VoteAccountStakes {
+    /// Sums the effective stake of `delegations` in `epoch` by vote account.
+    ///
+    /// `new_rate_activation_epoch` is the epoch in which
+    /// [`REDUCE_STAKE_WARMUP_COOLDOWN`] activated, if it has.
+    pub fn from_delegations<'a>(
+        delegations: impl IntoIterator<Item = &'a Delegation>,
+        epoch: Epoch,
+        stake_history: &StakeHistory,
+        new_rate_activation_epoch: Option<Epoch>,
+    ) -> Self {
+        let mut stakes = Self {
+            epoch,
+            ..Self::default()
+        };
+        for delegation in delegations {
+            let effective = delegation
+                .stake_activating_and_deactivating(epoch, stake_history, new_rate_activation_epoch)
+                .effective;
+            if effective == 0 {
+                continue;
+            }
+            let stake = stakes.stakes.entry(delegation.voter_pubkey).or_default();
+            *stake = stake.saturating_add(effective);
+            stakes.total = stakes.total.saturating_add(effective);
+        }
+        stakes
+    }
+
+    /// Fetches every stake delegation and the stake history, and sums the
+    /// effective stake by vote account in the current epoch.
+    #[cfg(feature = "fetch")]
+    pub fn fetch(rpc_client: &RpcClient) -> Result<Self, ReadinessError> {
+        let epoch = rpc_client.get_epoch_info().map_err(Box::new)?.epoch;
+        let stake_history = bincode::deserialize(
+            &rpc_client
+                .get_account_data(&solana_sdk_ids::sysvar::stake_history::id())
+                .map_err(Box::new)?,
+        )?;
+        let new_rate_activation_epoch = fetch_activation_slot(rpc_client)?
+            .map(|slot| {
+                rpc_client
+                    .get_epoch_schedule()
+                    .map(|schedule| schedule.get_epoch(slot))
+            })
+            .transpose()
+            .map_err(Box::new)?;
+
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize(StakeStateV2::size_of() as u64),
+            ]),
+            ..RpcProgramAccountsConfig::default()
+        };
+        let delegations = rpc_client
+            .get_program_ui_accounts_with_config(&solana_sdk_ids::stake::id(), config)
+            .map_err(Box::new)?
+            .into_iter()
+            .filter_map(|(_, account)| {
+                bincode::deserialize::<StakeStateV2>(&account.data.decode()?)
+                    .ok()?
+                    .delegation()
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Self::from_delegations(
+            &delegations,
+            epoch,
+            &stake_history,
+            new_rate_activation_epoch,
+        ))
+    }
+
+    /// The epoch the stakes are effective in.
+    pub fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// The effective stake delegated to `vote_account`.
+    pub fn get(&self, vote_account: &Address) -> u64 {
+        self.stakes.get(vote_account).copied().unwrap_or_default()
+    }
+
+    /// The cluster's total effective stake.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+/// A validator that signaled support for a feature.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidatorSupport {
+    /// The validator's vote account.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vote_account: Address,
+    /// The signaled software version, as `major.minor.patch`.
+    pub version: String,
+    /// The slot of the validator's most recent signal.
+    pub signaled_at: u64,
+    /// The effective stake delegated to the vote account.
+    pub stake: u64,
+}
+
+/// The stake signaling support for a feature.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeatureReadiness {
+    /// The feature ID.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub feature_id: Address,
+    /// The epoch the stakes are effective in.
+    pub epoch: Epoch,
+    /// The cluster's total effective stake.
+    pub total_stake: u64,
+    /// The effective stake of the validators signaling support.
+    pub supporting_stake: u64,
+    /// The validators signaling support, by descending stake.
+    pub validators: Vec<ValidatorSupport>,
+}
+
+impl FeatureReadiness {
+    /// Aggregates the support `signals` for `feature_id`, ignoring signals
+    /// for other features.
+    pub fn new(
+        feature_id: Address,
+        signals: impl IntoIterator<Item = SupportSignal>,
+        stakes: &VoteAccountStakes,
+    ) -> Self {
+        let mut validators = signals
+            .into_iter()
+            .filter(|signal| signal.feature_id == feature_id)
+            .map(|signal| ValidatorSupport {
+                stake: stakes.get(&signal.vote_account),
+                vote_account: signal.vote_account,
+                version: format!("{}.{}.{}", signal.major, signal.minor, signal.patch),
+                signaled_at: signal.signaled_at,
+            })
+            .collect::<Vec<_>>();
+        validators.sort_by(|a, b| {
+            b.stake
+                .cmp(&a.stake)
+                .then_with(|| a.vote_account.cmp(&b.vote_account))
+        });
+        validators.dedup_by_key(|validator| validator.vote_account);
+
+        Self {
+            feature_id,
+            epoch: stakes.epoch(),
+            total_stake: stakes.total(),
+            supporting_stake: validators
+                .iter()
+                .fold(0, |sum, validator| sum.saturating_add(validator.stake)),
+            validators,
+        }
+    }
+
+    /// Fetches the support signals for `feature_id` and the cluster's stakes.
+    #[cfg(feature = "fetch")]
+    pub fn fetch(rpc_client: &RpcClient, feature_id: &Address) -> Result<Self, ReadinessError> {
+        let signals = fetch_support_signals(rpc_client, feature_id)?;
+        let stakes = VoteAccountStakes::fetch(rpc_client)?;
+        Ok(Self::new(*feature_id, signals, &stakes))
+    }
+
+    /// The share of the total stake signaling support, in percent.
+    pub fn supporting_stake_percent(&self) -> f64 {
+        if self.total_stake == 0 {
+            return 0.0;
+        }
+        self.supporting_stake as f64 * 100.0 / self.total_stake as f64
+    }
+
+    /// Whether the supporting stake meets a stake guard threshold given in
+    /// basis points, using the same rule as the program.
+    pub fn meets_threshold(&self, threshold_bps: u16) -> bool {
+        u128::from(self.supporting_stake).saturating_mul(10_000)
+            >= u128::from(self.total_stake).saturating_mul(u128::from(threshold_bps))
+    }
+
+    /// Builds `TallySupport` instructions for the feature's stake guard that
+    /// together count the signals of every staked supporter, best-staked
+    /// first, with at most `max_signals` signals per instruction to bound
+    /// each transaction's account list.
+    ///
+    /// The program adds up tallies within an epoch and counts each signal
+    /// once, so the instructions may be sent in any number of transactions.
+    pub fn tally_support_instructions(&self, max_signals: usize) -> Vec<Instruction> {
+        let vote_accounts = self
+            .validators
+            .iter()
+            .filter(|validator| validator.stake > 0)
+            .map(|validator| validator.vote_account)
+            .collect::<Vec<_>>();
+
+        vote_accounts
+            .chunks(max_signals.max(1))
+            .map(|chunk| {
+                let mut vote_accounts = chunk.to_vec();
+                // The program requires strictly ascending vote accounts.
+                vote_accounts.sort();
+
+                TallySupportBuilder::new()
+                    .stake_guard(find_stake_guard_address(&self.feature_id).0)
+                    .add_remaining_accounts(
+                        &vote_accounts
+                            .iter()
+                            .map(|vote_account| {
+                                AccountMeta::new(
+                                    find_support_signal_address(&self.feature_id, vote_account).0,
+                                    false,
+                                )
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                    .instruction()
+            })
+            .collect()
+    }
+}
+
+/// Fetches every support signal recorded for `feature_id`.
+#[cfg(feature = "fetch")]
+pub fn fetch_support_signals(
+    rpc_client: &RpcClient,
+    feature_id: &Address,
+) -> Result<Vec<SupportSignal>, ReadinessError> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![
+            RpcFilterType::DataSize(crate::accounts::SUPPORT_SIGNAL_SIZE as u64),
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, vec![SUPPORT_SIGNAL_TAG])),
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(1, feature_id.to_bytes().to_vec())),
+        ]),
+        ..RpcProgramAccountsConfig::default()
+    };
+    Ok(rpc_client
+        .get_program_ui_accounts_with_config(&crate::ID, config)
+        .map_err(Box::new)?
+        .into_iter()
+        .filter_map(|(_, account)| SupportSignal::from_bytes(&account.data.decode()?).ok())
+        .collect())
+}
+
+/// The slot at which [`REDUCE_STAKE_WARMUP_COOLDOWN`] activated, if it has.
+#[cfg(feature = "fetch")]
+fn fetch_activation_slot(rpc_client: &RpcClient) -> Result<Option<Slot>, ReadinessError> {
+    let response = rpc_client
+        .get_account_with_commitment(&REDUCE_STAKE_WARMUP_COOLDOWN, rpc_client.commitment())
+        .map_err(Box::new)?;
+    Ok(response
+        .value
+        .as_ref()
+        .and_then(from_account)
+        .and_then(|feature| feature.activated_at))
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_stake_interface::stake_history::StakeHistoryEntry};
+
+    fn delegation(voter_pubkey: Address, stake: u64, activation_epoch: Epoch) -> Delegation {
+        Delegation {
+            voter_pubkey,
+            stake,
+            activation_epoch,
+            ..Delegation::default()
+        }
+    }
+
+    fn signal(feature_id: Address, vote_account: Address, signaled_at: u64) -> SupportSignal {
+        SupportSignal {
+            discriminator: SUPPORT_SIGNAL_TAG,
+            feature_id,
+            vote_account,
+            major: 3,
+            minor: 1,
+            patch: 8,
+            signaled_at,
+            tallied_epoch: u64::MAX,
+        }
+    }
+
+    #[test]
+    fn test_vote_account_stakes() {
+        let (a, b) = (Address::new_unique(), Address::new_unique());
+        // Epoch 9 had 1000 effective and 100 activating stake cluster-wide, so
+        // a delegation activated in epoch 9 warms up by at most 9% (the
+        // warmup rate after `REDUCE_STAKE_WARMUP_COOLDOWN`) of 1000.
+        let mut history = StakeHistory::default();
+        history.add(
+            9,
+            StakeHistoryEntry {
+                effective: 1_000,
+                activating: 100,
+                deactivating: 0,
+            },
+        );
+        let delegations = [
+            delegation(a, 600, 0),
+            delegation(a, 400, 0),
+            delegation(b, 100, 9),
+            delegation(b, 500, 10),
+        ];
+
+        let stakes = VoteAccountStakes::from_delegations(&delegations, 10, &history, Some(0));
+        assert_eq!(stakes.epoch(), 10);
+        assert_eq!(stakes.get(&a), 1_000);
+        assert_eq!(stakes.get(&b), 90);
+        assert_eq!(stakes.get(&Address::new_unique()), 0);
+        assert_eq!(stakes.total(), 1_090);
+    }
+
+    #[test]
+    fn test_feature_readiness() {
+        let feature_id = Address::new_unique();
+        let (a, b, c) = (
+            Address::new_unique(),
+            Address::new_unique(),
+            Address::new_unique(),
+        );
+        let stakes = VoteAccountStakes::from_delegations(
+            &[
+                delegation(a, 100, 0),
+                delegation(b, 300, 0),
+                delegation(c, 600, 0),
+            ],
+            10,
+            &StakeHistory::default(),
+            None,
+        );
+
+        let readiness = FeatureReadiness::new(
+            feature_id,
+            [
+                signal(feature_id, a, 5),
+                signal(feature_id, b, 6),
+                signal(Address::new_unique(), c, 7),
+                signal(feature_id, Address::new_unique(), 8),
+            ],
+            &stakes,
+        );
+        assert_eq!(readiness.epoch, 10);
+        assert_eq!(readiness.total_stake, 1_000);
+        assert_eq!(readiness.supporting_stake, 400);
+        assert_eq!(readiness.supporting_stake_percent(), 40.0);
+        assert_eq!(
+            readiness
+                .validators
+                .iter()
+                .map(|validator| (validator.vote_account, validator.stake))
+                .collect::<Vec<_>>()[..2],
+            [(b, 300), (a, 100)]
+        );
+        assert_eq!(readiness.validators[0].version, "3.1.8");
+        assert_eq!(readiness.validators.len(), 3);
+
+        let empty = FeatureReadiness::new(feature_id, [], &VoteAccountStakes::default());
+        assert_eq!(empty.supporting_stake_percent(), 0.0);
+        assert!(empty.meets_threshold(10_000));
+    }
+
+    #[test]
+    fn test_tally_support_instruction() {
+        let feature_id = Address::new_unique();
+        let (a, b, c) = (
+            Address::new_unique(),
+            Address::new_unique(),
+            Address::new_unique(),
+        );
+        let stakes = VoteAccountStakes::from_delegations(
+            &[
+                delegation(a, 100, 0),
+                delegation(b, 300, 0),
+                delegation(c, 600, 0),
+            ],
+            10,
+            &StakeHistory::default(),
+            None,
+        );
+        let readiness = FeatureReadiness::new(
+            feature_id,
+            [
+                signal(feature_id, a, 5),
+                signal(feature_id, b, 6),
+                signal(feature_id, c, 7),
+                signal(feature_id, Address::new_unique(), 8),
+            ],
+            &stakes,
+        );
+        assert!(readiness.meets_threshold(10_000));
+
+        let instructions = readiness.tally_support_instructions(2);
+        assert_eq!(instructions.len(), 2);
+        let mut expected = [b, c];
+        expected.sort();
+        for (instruction, expected) in instructions.iter().zip([&expected[..], &[a]]) {
+            assert_eq!(
+                instruction.accounts[0].pubkey,
+                find_stake_guard_address(&feature_id).0
+            );
+            assert!(instruction
+                .accounts
+                .iter()
+                .all(|meta| meta.is_writable && !meta.is_signer));
+            assert_eq!(
+                instruction.accounts[1..]
+                    .iter()
+                    .map(|meta| meta.pubkey)
+                    .collect::<Vec<_>>(),
+                expected
+                    .iter()
+                    .map(|vote_account| find_support_signal_address(&feature_id, vote_account).0)
+                    .collect::<Vec<_>>()
+            );
+        }
+
+        // Supporters without stake are never tallied.
+        let instructions = readiness.tally_support_instructions(10);
+        assert_eq!(instructions.len(), 1);
+        assert_eq!(instructions[0].accounts.len(), 4);
+    }
+
+    #[cfg(all(feature = "fetch", feature = "serde"))]
+    #[test]
+    fn test_fetch_with_mock_rpc() {
+        use {
+            base64::{prelude::BASE64_STANDARD, Engine},
+            borsh::to_vec,
+            solana_epoch_schedule::EpochSchedule,
+            solana_rpc_client::{api::request::RpcRequest, mock_sender::MocksMap},
+            solana_stake_interface::{
+                stake_flags::StakeFlags,
+                state::{Meta, Stake},
+            },
+        };
+
+        let ui_account = |owner: &Address, data: &[u8]| {
+            serde_json::json!({
+                "lamports": 1_000_000,
+                "data": [BASE64_STANDARD.encode(data), "base64"],
+                "owner": owner.to_string(),
+                "executable": false,
+                "rentEpoch": 0,
+                "space": data.len(),
+            })
+        };
+        let keyed = |owner: &Address, data: &[u8]| {
+            serde_json::json!({
+                "pubkey": Address::new_unique().to_string(),
+                "account": ui_account(owner, data),
+            })
+        };
+        let account_info = |value: serde_json::Value| serde_json::json!({ "context": { "slot": 1 }, "value": value });
+
+        let feature_id = Address::new_unique();
+        let (a, b) = (Address::new_unique(), Address::new_unique());
+        let stake_account = |voter_pubkey| {
+            bincode::serialize(&StakeStateV2::Stake(
+                Meta::default(),
+                Stake {
+                    delegation: delegation(voter_pubkey, 250, 0),
+                    credits_observed: 0,
+                },
+                StakeFlags::empty(),
+            ))
+            .unwrap()
+        };
+
+        let mut mocks = MocksMap::default();
+        mocks.insert(
+            RpcRequest::GetProgramAccounts,
+            serde_json::json!([keyed(
+                &crate::ID,
+                &to_vec(&signal(feature_id, a, 42)).unwrap()
+            )]),
+        );
+        mocks.insert(
+            RpcRequest::GetEpochInfo,
+            serde_json::json!({
+                "absoluteSlot": 4_320_000,
+                "blockHeight": 4_320_000,
+                "epoch": 10,
+                "slotIndex": 0,
+                "slotsInEpoch": 432_000,
+            }),
+        );
+        mocks.insert(
+            RpcRequest::GetAccountInfo,
+            account_info(ui_account(
+                &solana_sdk_ids::sysvar::id(),
+                &bincode::serialize(&StakeHistory::default()).unwrap(),
+            )),
+        );
+        mocks.insert(
+            RpcRequest::GetAccountInfo,
+            account_info(serde_json::Value::Null),
+        );
+        mocks.insert(
+            RpcRequest::GetEpochSchedule,
+            serde_json::to_value(EpochSchedule::default()).unwrap(),
+        );
+        mocks.insert(
+            RpcRequest::GetProgramAccounts,
+            serde_json::json!([
+                keyed(&solana_sdk_ids::stake::id(), &stake_account(a)),
+                keyed(&solana_sdk_ids::stake::id(), &stake_account(b)),
+                keyed(&solana_sdk_ids::stake::id(), &stake_account(b)),
+            ]),
+        );
+
+        let rpc_client = RpcClient::new_mock_with_mocks_map("succeeds", mocks);
+        let readiness = FeatureReadiness::fetch(&rpc_client, &feature_id).unwrap();
+        assert_eq!(readiness.epoch, 10);
+        assert_eq!(readiness.total_stake, 750);
+        assert_eq!(readiness.supporting_stake, 250);
+        assert_eq!(
+            readiness.validators,
+            vec![ValidatorSupport {
+                vote_account: a,
+                version: "3.1.8".to_string(),
+                signaled_at: 42,
+                stake: 250,
+            }]
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! Activation scheduled for a future epoch.
+//!
+//! A feature queued with `ScheduleActivation` holds a scheduled activation
+//! instead of a pending feature, which the runtime ignores. From the epoch
+//! before its target, anyone can release it with
+//! `ReleaseScheduledActivation`, after which the runtime activates it at the
+//! next epoch boundary as usual. This module picks the target epoch for a
+//! desired date, decodes scheduled feature accounts and builds both
+//! instructions.
+
+pub use solana_feature_gate_program_interface::state::{
+    ScheduledActivation, SCHEDULED_ACTIVATION_TAG,
+};
+use {
+    crate::{
+        activation::ActivationEstimateConfig,
+        audit_log::find_audit_log_address,
+        instructions::{ReleaseScheduledActivationBuilder, ScheduleActivationBuilder},
+    },
+    solana_address::Address,
+    solana_clock::{Epoch, Slot, UnixTimestamp},
+    solana_epoch_schedule::EpochSchedule,
+    solana_instruction::Instruction,
+};
+
+/// Estimates the Unix timestamp at which `epoch` begins.
+///
+/// `now` is the current Unix timestamp. Epochs that have already begun are
+/// estimated to begin `now`.
+pub fn estimate_epoch_start(
+    epoch: Epoch,
+    epoch_schedule: &EpochSchedule,
+    current_slot: Slot,
+    now: UnixTimestamp,
+    config: &ActivationEstimateConfig,
+) -> UnixTimestamp {
+    let slots = epoch_schedule
+        .get_first_slot_in_epoch(epoch)
+        .saturating_sub(current_slot);
+    now.saturating_add(
+        UnixTimestamp::try_from(config.duration_of(slots).as_secs()).unwrap_or(UnixTimestamp::MAX),
+    )
+}
+
+/// The earliest epoch estimated to begin at or after `target`, a Unix
+/// timestamp, to use as a scheduled activation's `not_before_epoch`.
+///
+/// Since no feature can activate before the next epoch boundary, the result
+/// is never earlier than the next epoch.
+pub fn epoch_not_before(
+    target: UnixTimestamp,
+    epoch_schedule: &EpochSchedule,
+    current_slot: Slot,
+    now: UnixTimestamp,
+    config: &ActivationEstimateConfig,
+) -> Epoch {
+    let next_epoch = epoch_schedule.get_epoch(current_slot).saturating_add(1);
+    let seconds = u64::try_from(target.saturating_sub(now)).unwrap_or(0);
+    let millis_per_slot = u64::try_from(config.slot_duration.as_millis())
+        .unwrap_or(u64::MAX)
+        .max(1);
+    let target_slot =
+        current_slot.saturating_add(seconds.saturating_mul(1_000).div_ceil(millis_per_slot));
+
+    let epoch = epoch_schedule.get_epoch(target_slot);
+    let epoch = if epoch_schedule.get_first_slot_in_epoch(epoch) < target_slot {
+        epoch.saturating_add(1)
+    } else {
+        epoch
+    };
+    epoch.max(next_epoch)
+}
+
+/// Builds a `ScheduleActivation` instruction, signed by the feature keypair
+/// and `payer`.
+pub fn schedule_activation_instruction(
+    feature_id: &Address,
+    payer: &Address,
+    not_before_epoch: Epoch,
+) -> Instruction {
+    ScheduleActivationBuilder::new()
+        .feature(*feature_id)
+        .payer(*payer)
+        .not_before_epoch(not_before_epoch)
+        .audit_log(find_audit_log_address().0)
+        .instruction()
+}
+
+/// Builds a `ReleaseScheduledActivation` instruction, which anyone may send.
+pub fn release_scheduled_activation_instruction(feature_id: &Address) -> Instruction {
+    ReleaseScheduledActivationBuilder::new()
+        .feature(*feature_id)
+        .instruction()
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, std::time::Duration};
+
+    const NOW: UnixTimestamp = 1_700_000_000;
+
+    fn config() -> ActivationEstimateConfig {
+        ActivationEstimateConfig {
+            slot_duration: Duration::from_secs(1),
+        }
+    }
+
+    #[test]
+    fn test_unpack() {
+        let mut data = vec![SCHEDULED_ACTIVATION_TAG];
+        data.extend_from_slice(&42u64.to_le_bytes());
+        let scheduled = ScheduledActivation::unpack(&data).unwrap();
+        assert_eq!(scheduled.not_before_epoch, 42);
+        assert_eq!(scheduled.release_epoch(), 41);
+        assert!(!scheduled.is_releasable_in(40));
+        assert!(scheduled.is_releasable_in(41));
+
+        assert!(ScheduledActivation::unpack(&[0; 9]).is_err());
+        assert!(ScheduledActivation::unpack(&data[..8]).is_err());
+    }
+
+    #[test]
+    fn test_epoch_not_before() {
+        let epoch_schedule = EpochSchedule::without_warmup();
+        let slots_per_epoch = epoch_schedule.slots_per_epoch;
+        let current_slot = slots_per_epoch.saturating_mul(10).saturating_add(1_000);
+        let epoch_start = |epoch: Epoch| {
+            estimate_epoch_start(epoch, &epoch_schedule, current_slot, NOW, &config())
+        };
+
+        let start_of_12 = NOW.saturating_add(
+            i64::try_from(slots_per_epoch.saturating_mul(2).saturating_sub(1_000)).unwrap(),
+        );
+        assert_eq!(epoch_start(12), start_of_12);
+        assert_eq!(epoch_start(10), NOW);
+
+        let not_before =
+            |target| epoch_not_before(target, &epoch_schedule, current_slot, NOW, &config());
+        // A target on an estimated boundary lands in that epoch, and one
+        // just after it in the following epoch.
+        assert_eq!(not_before(start_of_12), 12);
+        assert_eq!(not_before(start_of_12.saturating_add(1)), 13);
+        assert_eq!(not_before(start_of_12.saturating_sub(1)), 12);
+        // Targets in the past or the current epoch map to the next epoch.
+        assert_eq!(not_before(NOW.saturating_sub(100)), 11);
+        assert_eq!(not_before(NOW.saturating_add(10)), 11);
+    }
+
+    #[test]
+    fn test_instructions() {
+        let feature_id = Address::new_unique();
+        let payer = Address::new_unique();
+
+        let schedule = schedule_activation_instruction(&feature_id, &payer, 42);
+        assert_eq!(schedule.data[0], 7);
+        assert_eq!(schedule.data[1..], 42u64.to_le_bytes());
+        assert!(schedule.accounts[0].is_signer);
+        assert!(schedule.accounts[1].is_signer);
+
+        let release = release_scheduled_activation_instruction(&feature_id);
+        assert_eq!(release.data, vec![8]);
+        assert_eq!(release.accounts.len(), 1);
+        assert!(release.accounts[0].is_writable);
+    }
+}
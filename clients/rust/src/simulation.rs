@@ -0,0 +1,118 @@
+//! Local dry-run simulation of Feature Gate instructions.
+//!
+//! Executes `RevokePendingActivation` against a feature account snapshot in
+//! an embedded SVM ([Mollusk](https://github.com/anza-xyz/mollusk)) running
+//! the built Feature Gate program, without touching the network.
+
+use {
+    crate::{
+        audit_log::find_audit_log_address,
+        instructions::RevokePendingActivationBuilder,
+        snapshot::FeatureAccountSnapshot,
+        transaction_error::{decode_instruction_error, FeatureGateProgramError},
+        ID,
+    },
+    mollusk_svm::{program::keyed_account_for_system_program, Mollusk},
+    solana_account::Account,
+    solana_address::Address,
+    solana_instruction::error::InstructionError,
+    solana_sdk_ids::{bpf_loader_upgradeable, incinerator},
+    std::path::Path,
+};
+
+/// The name of the built Feature Gate program, without the `.so` extension.
+pub const PROGRAM_NAME: &str = "solana_feature_gate_program";
+
+/// The outcome of a simulated instruction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationReport {
+    /// The raw instruction result.
+    pub result: Result<(), InstructionError>,
+    /// The decoded program error, if the instruction failed.
+    pub error: Option<FeatureGateProgramError>,
+    /// The compute units consumed by the instruction.
+    pub compute_units_consumed: u64,
+    /// The state of every instruction account after execution.
+    pub resulting_accounts: Vec<(Address, Account)>,
+}
+
+impl SimulationReport {
+    /// Whether the instruction succeeded.
+    pub fn is_success(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// The resulting state of an account, if it was part of the instruction.
+    pub fn account(&self, address: &Address) -> Option<&Account> {
+        self.resulting_accounts
+            .iter()
+            .find(|(key, _)| key == address)
+            .map(|(_, account)| account)
+    }
+}
+
+/// Simulates Feature Gate instructions against the built program.
+pub struct Simulator {
+    mollusk: Mollusk,
+}
+
+impl Simulator {
+    /// Creates a simulator that loads the built program from Mollusk's
+    /// default search paths (`tests/fixtures`, `BPF_OUT_DIR`, `SBF_OUT_DIR`
+    /// and the current directory).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the program binary cannot be found.
+    pub fn new() -> Self {
+        Self {
+            mollusk: Mollusk::new(&ID, PROGRAM_NAME),
+        }
+    }
+
+    /// Creates a simulator running the given program ELF.
+    pub fn from_elf(elf: &[u8]) -> Self {
+        let mut mollusk = Mollusk::default();
+        mollusk.add_program_with_loader_and_elf(&ID, &bpf_loader_upgradeable::id(), elf);
+        Self { mollusk }
+    }
+
+    /// Creates a simulator running the program ELF at `path`.
+    pub fn from_program_file(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self::from_elf(&std::fs::read(path)?))
+    }
+
+    /// Simulates `RevokePendingActivation` against a feature account snapshot.
+    pub fn simulate_revoke(&self, snapshot: &FeatureAccountSnapshot) -> SimulationReport {
+        let instruction = RevokePendingActivationBuilder::new()
+            .feature(snapshot.address)
+            .incinerator(incinerator::id())
+            .audit_log(Some(find_audit_log_address().0))
+            .instruction();
+        let result = self.mollusk.process_instruction(
+            &instruction,
+            &[
+                (snapshot.address, snapshot.to_account()),
+                (incinerator::id(), Account::default()),
+                keyed_account_for_system_program(),
+            ],
+        );
+
+        SimulationReport {
+            error: result
+                .raw_result
+                .as_ref()
+                .err()
+                .map(decode_instruction_error),
+            result: result.raw_result,
+            compute_units_consumed: result.compute_units_consumed,
+            resulting_accounts: result.resulting_accounts,
+        }
+    }
+}
+
+impl Default for Simulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,659 @@
+//! Snapshots of feature account state.
+//!
+//! A snapshot captures a feature account as it existed on a cluster, so that
+//! it can be inspected or replayed locally without touching the network.
+//! Snapshots can be fetched over RPC (with the `fetch` feature) or loaded from
+//! JSON (with the `serde` feature).
+//!
+//! A [`ClusterSnapshot`] captures every feature account owned by the Feature
+//! Gate program. It can be exported to and imported from versioned JSON or CSV
+//! files, and its accounts can be loaded into Mollusk to replay a real
+//! cluster's feature state in tests.
+
+#[cfg(feature = "fetch")]
+use solana_rpc_client::{
+    api::{
+        client_error::Error as ClientError, config::RpcProgramAccountsConfig, filter::RpcFilterType,
+    },
+    rpc_client::RpcClient,
+};
+use {
+    crate::{config::TOMBSTONE_TAG, schedule::ScheduledActivation},
+    solana_account::Account,
+    solana_address::Address,
+    solana_clock::{Epoch, Slot},
+    solana_feature_gate_interface::{from_account, Feature},
+    thiserror::Error,
+};
+#[cfg(feature = "serde")]
+use {
+    base64::{prelude::BASE64_STANDARD, Engine},
+    serde::{Deserialize, Serialize, Serializer},
+    std::path::Path,
+};
+
+/// Errors that can occur while loading or fetching a snapshot.
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    /// The snapshot file could not be read or written.
+    #[error("Snapshot I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The snapshot is not valid JSON.
+    #[cfg(feature = "serde")]
+    #[error("Invalid snapshot JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    /// The RPC request failed.
+    #[cfg(feature = "fetch")]
+    #[error("RPC error: {0}")]
+    Rpc(#[from] Box<ClientError>),
+    /// The account does not exist on the cluster.
+    #[error("Account {0} not found")]
+    AccountNotFound(Address),
+    /// The snapshot file was written by an unsupported format version.
+    #[error("Unsupported snapshot version {0}")]
+    UnsupportedVersion(u32),
+    /// The snapshot CSV is malformed.
+    #[error("Invalid snapshot CSV at line {line}: {message}")]
+    InvalidCsv {
+        /// The 1-based line number of the malformed line.
+        line: usize,
+        /// What is wrong with the line.
+        message: String,
+    },
+    /// The file extension is neither `.json` nor `.csv`.
+    #[error("Unsupported snapshot file format: {0}")]
+    UnsupportedFormat(String),
+}
+
+/// The decoded state of a feature account.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(
+        rename_all = "camelCase",
+        rename_all_fields = "camelCase",
+        tag = "status"
+    )
+)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeatureStatus {
+    /// The feature is scheduled to activate no earlier than a given epoch,
+    /// and is ignored by the runtime until it is released.
+    Scheduled {
+        /// The earliest epoch in which the feature may activate.
+        not_before_epoch: Epoch,
+    },
+    /// The feature is queued for activation at the next epoch boundary.
+    Pending,
+    /// The feature has been activated by the runtime.
+    Active {
+        /// The slot at which the feature was activated.
+        activated_at: Slot,
+    },
+    /// The feature was revoked by its stake guard and can never be queued
+    /// again.
+    Tombstone {
+        /// The slot at which the feature was revoked.
+        revoked_at: Slot,
+    },
+    /// The account is not a valid feature account.
+    Invalid,
+}
+
+impl FeatureStatus {
+    /// A short, lowercase label for the status.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Scheduled { .. } => "scheduled",
+            Self::Pending => "pending",
+            Self::Active { .. } => "active",
+            Self::Tombstone { .. } => "tombstone",
+            Self::Invalid => "invalid",
+        }
+    }
+}
+
+impl From<Option<Feature>> for FeatureStatus {
+    fn from(feature: Option<Feature>) -> Self {
+        match feature {
+            Some(Feature {
+                activated_at: Some(activated_at),
+            }) => Self::Active { activated_at },
+            Some(Feature { activated_at: None }) => Self::Pending,
+            None => Self::Invalid,
+        }
+    }
+}
+
+/// A snapshot of a single feature account.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeatureAccountSnapshot {
+    /// The feature ID.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub address: Address,
+    /// The account balance.
+    pub lamports: u64,
+    /// The account owner.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub owner: Address,
+    /// The raw account data.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::base64::Base64>")
+    )]
+    pub data: Vec<u8>,
+    /// The slot at which the account was observed, if known.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub slot: Option<Slot>,
+}
+
+impl FeatureAccountSnapshot {
+    /// Creates a snapshot from an account.
+    pub fn from_account(address: Address, account: &Account, slot: Option<Slot>) -> Self {
+        Self {
+            address,
+            lamports: account.lamports,
+            owner: account.owner,
+            data: account.data.clone(),
+            slot,
+        }
+    }
+
+    /// Converts the snapshot back into an account.
+    pub fn to_account(&self) -> Account {
+        Account {
+            lamports: self.lamports,
+            data: self.data.clone(),
+            owner: self.owner,
+            ..Account::default()
+        }
+    }
+
+    /// Decodes the feature state, if the account is a valid feature account.
+    pub fn feature(&self) -> Option<Feature> {
+        from_account(&self.to_account())
+    }
+
+    /// The decoded status of the feature account, including the scheduled
+    /// activations and tombstones the program leaves at feature addresses.
+    pub fn status(&self) -> FeatureStatus {
+        if self.owner == crate::ID {
+            if let Ok(scheduled) = ScheduledActivation::unpack(&self.data) {
+                return FeatureStatus::Scheduled {
+                    not_before_epoch: scheduled.not_before_epoch,
+                };
+            }
+            if let [TOMBSTONE_TAG, revoked_at @ ..] = self.data.as_slice() {
+                if let Ok(revoked_at) = revoked_at.try_into() {
+                    return FeatureStatus::Tombstone {
+                        revoked_at: Slot::from_le_bytes(revoked_at),
+                    };
+                }
+            }
+        }
+        self.feature().into()
+    }
+
+    /// Parses a snapshot from JSON.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, SnapshotError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Loads a snapshot from a JSON file.
+    #[cfg(feature = "serde")]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SnapshotError> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Fetches a snapshot of a feature account from a cluster.
+    #[cfg(feature = "fetch")]
+    pub fn fetch(rpc_client: &RpcClient, address: &Address) -> Result<Self, SnapshotError> {
+        let response = rpc_client
+            .get_account_with_commitment(address, rpc_client.commitment())
+            .map_err(Box::new)?;
+        let account = response
+            .value
+            .ok_or(SnapshotError::AccountNotFound(*address))?;
+        Ok(Self::from_account(
+            *address,
+            &account,
+            Some(response.context.slot),
+        ))
+    }
+
+    /// Fetches snapshots of several feature accounts in a single request.
+    ///
+    /// Returns `None` for each account that does not exist on the cluster.
+    #[cfg(feature = "fetch")]
+    pub fn fetch_many(
+        rpc_client: &RpcClient,
+        addresses: &[Address],
+    ) -> Result<Vec<Option<Self>>, SnapshotError> {
+        let response = rpc_client
+            .get_multiple_accounts_with_commitment(addresses, rpc_client.commitment())
+            .map_err(Box::new)?;
+        let slot = response.context.slot;
+        Ok(addresses
+            .iter()
+            .zip(response.value)
+            .map(|(address, account)| {
+                account.map(|account| Self::from_account(*address, &account, Some(slot)))
+            })
+            .collect())
+    }
+
+    /// Fetches snapshots of every feature account owned by the Feature Gate
+    /// program.
+    ///
+    /// Only accounts of a feature's size are requested, which leaves out the
+    /// program's config, stake guards, bundles and other accounts.
+    #[cfg(feature = "fetch")]
+    pub fn fetch_all(rpc_client: &RpcClient) -> Result<Vec<Self>, SnapshotError> {
+        let slot = rpc_client.get_slot().map_err(Box::new)?;
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![RpcFilterType::DataSize(Feature::size_of() as u64)]),
+            ..RpcProgramAccountsConfig::default()
+        };
+        Ok(rpc_client
+            .get_program_ui_accounts_with_config(&crate::ID, config)
+            .map_err(Box::new)?
+            .into_iter()
+            .filter_map(|(address, account)| {
+                Some(Self::from_account(address, &account.decode()?, Some(slot)))
+            })
+            .collect())
+    }
+}
+
+/// A snapshot of every feature account owned by the Feature Gate program.
+///
+/// Exported files are versioned. Besides the raw account fields, each
+/// exported account includes its decoded status for readability; the status
+/// is recomputed from the account data on import.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClusterSnapshot {
+    /// The snapshot format version.
+    pub version: u32,
+    /// The slot at which the accounts were fetched, if known.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub slot: Option<Slot>,
+    /// The feature accounts, ordered by address.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_accounts"))]
+    pub accounts: Vec<FeatureAccountSnapshot>,
+}
+
+/// The CSV columns, in order.
+#[cfg(feature = "serde")]
+const CSV_HEADER: &str = "address,lamports,owner,data,slot,status,activated_at";
+
+impl ClusterSnapshot {
+    /// The current snapshot format version.
+    pub const VERSION: u32 = 1;
+
+    /// Creates a snapshot of the given accounts.
+    pub fn new(slot: Option<Slot>, mut accounts: Vec<FeatureAccountSnapshot>) -> Self {
+        accounts.sort_by_key(|account| account.address);
+        Self {
+            version: Self::VERSION,
+            slot,
+            accounts,
+        }
+    }
+
+    /// Fetches every feature account owned by the Feature Gate program.
+    #[cfg(feature = "fetch")]
+    pub fn fetch(rpc_client: &RpcClient) -> Result<Self, SnapshotError> {
+        let accounts = FeatureAccountSnapshot::fetch_all(rpc_client)?;
+        let slot = accounts.iter().filter_map(|account| account.slot).max();
+        Ok(Self::new(slot, accounts))
+    }
+
+    /// Looks up an account by address.
+    pub fn get(&self, address: &Address) -> Option<&FeatureAccountSnapshot> {
+        self.accounts
+            .iter()
+            .find(|account| &account.address == address)
+    }
+
+    /// The accounts as `(address, account)` pairs, ready to be loaded into
+    /// Mollusk.
+    pub fn keyed_accounts(&self) -> Vec<(Address, Account)> {
+        self.accounts
+            .iter()
+            .map(|account| (account.address, account.to_account()))
+            .collect()
+    }
+
+    /// Serializes the snapshot to pretty-printed JSON.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String, SnapshotError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Parses a snapshot from JSON.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, SnapshotError> {
+        let snapshot: Self = serde_json::from_str(json)?;
+        snapshot.check_version()?;
+        Ok(snapshot)
+    }
+
+    /// Serializes the snapshot to CSV.
+    ///
+    /// The first line is a comment carrying the format version and slot,
+    /// followed by a header row and one row per account. Account data is
+    /// base64-encoded.
+    #[cfg(feature = "serde")]
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("# version={}", self.version);
+        if let Some(slot) = self.slot {
+            csv.push_str(&format!(" slot={slot}"));
+        }
+        csv.push('\n');
+        csv.push_str(CSV_HEADER);
+        csv.push('\n');
+        for account in &self.accounts {
+            let status = account.status();
+            let activated_at = match status {
+                FeatureStatus::Active { activated_at } => activated_at.to_string(),
+                _ => String::new(),
+            };
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                account.address,
+                account.lamports,
+                account.owner,
+                BASE64_STANDARD.encode(&account.data),
+                account
+                    .slot
+                    .map(|slot| slot.to_string())
+                    .unwrap_or_default(),
+                status.label(),
+                activated_at,
+            ));
+        }
+        csv
+    }
+
+    /// Parses a snapshot from CSV written by [`ClusterSnapshot::to_csv`].
+    #[cfg(feature = "serde")]
+    pub fn from_csv(csv: &str) -> Result<Self, SnapshotError> {
+        let mut lines = csv
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index.saturating_add(1), line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+
+        let invalid = |line: usize, message: &str| SnapshotError::InvalidCsv {
+            line,
+            message: message.to_string(),
+        };
+        let (line, metadata) = lines.next().ok_or_else(|| invalid(1, "empty file"))?;
+        let metadata = metadata
+            .strip_prefix('#')
+            .ok_or_else(|| invalid(line, "missing `# version=` line"))?;
+        let (mut version, mut slot) = (None, None);
+        for entry in metadata.split_whitespace() {
+            match entry.split_once('=') {
+                Some(("version", value)) => version = value.parse().ok(),
+                Some(("slot", value)) => slot = value.parse().ok(),
+                _ => {}
+            }
+        }
+        let version = version.ok_or_else(|| invalid(line, "missing version"))?;
+
+        let (line, header) = lines
+            .next()
+            .ok_or_else(|| invalid(line, "missing header"))?;
+        if header != CSV_HEADER {
+            return Err(invalid(line, "unexpected header"));
+        }
+
+        let accounts = lines
+            .map(|(line, row)| {
+                let [address, lamports, owner, data, account_slot, _status, _activated_at] = row
+                    .split(',')
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .map_err(|_| invalid(line, "expected 7 columns"))?;
+                Ok(FeatureAccountSnapshot {
+                    address: address
+                        .parse()
+                        .map_err(|_| invalid(line, "invalid address"))?,
+                    lamports: lamports
+                        .parse()
+                        .map_err(|_| invalid(line, "invalid lamports"))?,
+                    owner: owner.parse().map_err(|_| invalid(line, "invalid owner"))?,
+                    data: BASE64_STANDARD
+                        .decode(data)
+                        .map_err(|_| invalid(line, "invalid base64 data"))?,
+                    slot: match account_slot {
+                        "" => None,
+                        account_slot => Some(
+                            account_slot
+                                .parse()
+                                .map_err(|_| invalid(line, "invalid slot"))?,
+                        ),
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>, SnapshotError>>()?;
+
+        let snapshot = Self {
+            version,
+            slot,
+            accounts,
+        };
+        snapshot.check_version()?;
+        Ok(snapshot)
+    }
+
+    /// Writes the snapshot to a `.json` or `.csv` file.
+    #[cfg(feature = "serde")]
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        let path = path.as_ref();
+        let contents = match SnapshotFormat::from_path(path)? {
+            SnapshotFormat::Json => self.to_json()?,
+            SnapshotFormat::Csv => self.to_csv(),
+        };
+        Ok(std::fs::write(path, contents)?)
+    }
+
+    /// Loads a snapshot from a `.json` or `.csv` file.
+    #[cfg(feature = "serde")]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SnapshotError> {
+        let path = path.as_ref();
+        let format = SnapshotFormat::from_path(path)?;
+        let contents = std::fs::read_to_string(path)?;
+        match format {
+            SnapshotFormat::Json => Self::from_json(&contents),
+            SnapshotFormat::Csv => Self::from_csv(&contents),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn check_version(&self) -> Result<(), SnapshotError> {
+        if self.version == Self::VERSION {
+            Ok(())
+        } else {
+            Err(SnapshotError::UnsupportedVersion(self.version))
+        }
+    }
+}
+
+/// A snapshot file format, chosen by file extension.
+#[cfg(feature = "serde")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SnapshotFormat {
+    /// `.json`
+    Json,
+    /// `.csv`
+    Csv,
+}
+
+#[cfg(feature = "serde")]
+impl SnapshotFormat {
+    /// Determines the format from a file's extension.
+    pub fn from_path(path: &Path) -> Result<Self, SnapshotError> {
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Ok(Self::Json),
+            Some("csv") => Ok(Self::Csv),
+            _ => Err(SnapshotError::UnsupportedFormat(path.display().to_string())),
+        }
+    }
+}
+
+/// Serializes each account alongside its decoded status.
+#[cfg(feature = "serde")]
+fn serialize_accounts<S: Serializer>(
+    accounts: &[FeatureAccountSnapshot],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    #[derive(Serialize)]
+    struct ExportedAccount<'a> {
+        #[serde(flatten)]
+        account: &'a FeatureAccountSnapshot,
+        #[serde(flatten)]
+        status: FeatureStatus,
+    }
+
+    serializer.collect_seq(accounts.iter().map(|account| ExportedAccount {
+        account,
+        status: account.status(),
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, crate::ID};
+
+    fn pending_snapshot() -> FeatureAccountSnapshot {
+        FeatureAccountSnapshot {
+            address: Address::new_unique(),
+            lamports: 1_000_000,
+            owner: ID,
+            data: vec![0; Feature::size_of()],
+            slot: Some(42),
+        }
+    }
+
+    #[test]
+    fn test_snapshot_account_round_trip() {
+        let snapshot = pending_snapshot();
+        let account = snapshot.to_account();
+        assert_eq!(
+            FeatureAccountSnapshot::from_account(snapshot.address, &account, snapshot.slot),
+            snapshot
+        );
+        assert_eq!(snapshot.feature(), Some(Feature::default()));
+        assert_eq!(snapshot.status(), FeatureStatus::Pending);
+
+        let mut active = snapshot.clone();
+        active.data = vec![1, 45, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(active.status(), FeatureStatus::Active { activated_at: 45 });
+
+        let mut scheduled = snapshot.clone();
+        scheduled.data = vec![0xFE, 7, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(scheduled.feature(), None);
+        assert_eq!(
+            scheduled.status(),
+            FeatureStatus::Scheduled {
+                not_before_epoch: 7
+            }
+        );
+
+        let mut tombstone = snapshot.clone();
+        tombstone.data = vec![0xFF, 46, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(
+            tombstone.status(),
+            FeatureStatus::Tombstone { revoked_at: 46 }
+        );
+        tombstone.owner = Address::new_unique();
+        assert_eq!(tombstone.status(), FeatureStatus::Invalid);
+
+        let mut not_a_feature = snapshot;
+        not_a_feature.owner = Address::new_unique();
+        assert_eq!(not_a_feature.feature(), None);
+        assert_eq!(not_a_feature.status(), FeatureStatus::Invalid);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_snapshot_json_round_trip() {
+        let snapshot = pending_snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert!(json.contains(r#""data":"AAAAAAAAAAAA""#));
+        assert_eq!(FeatureAccountSnapshot::from_json(&json).unwrap(), snapshot);
+    }
+
+    #[cfg(feature = "serde")]
+    fn cluster_snapshot() -> ClusterSnapshot {
+        let mut active = pending_snapshot();
+        active.data = vec![1, 45, 0, 0, 0, 0, 0, 0, 0];
+        let mut invalid = pending_snapshot();
+        invalid.data = vec![2];
+        invalid.slot = None;
+        ClusterSnapshot::new(Some(42), vec![pending_snapshot(), active, invalid])
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_cluster_snapshot_json_round_trip() {
+        let snapshot = cluster_snapshot();
+        let json = snapshot.to_json().unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["version"], 1);
+        let statuses = value["accounts"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|account| account["status"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(statuses.len(), 3);
+        assert!(json.contains(r#""activatedAt": 45"#));
+        assert_eq!(ClusterSnapshot::from_json(&json).unwrap(), snapshot);
+
+        let unsupported = json.replace(r#""version": 1"#, r#""version": 2"#);
+        assert!(matches!(
+            ClusterSnapshot::from_json(&unsupported),
+            Err(SnapshotError::UnsupportedVersion(2))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_cluster_snapshot_csv_round_trip() {
+        let snapshot = cluster_snapshot();
+        let csv = snapshot.to_csv();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("# version=1 slot=42"));
+        assert_eq!(lines.next(), Some(CSV_HEADER));
+        assert!(csv.contains(",AS0AAAAAAAAA,"));
+        assert!(csv.contains(",active,45\n"));
+        assert!(csv.contains(",invalid,\n"));
+        assert_eq!(ClusterSnapshot::from_csv(&csv).unwrap(), snapshot);
+
+        let truncated = csv.replace(",pending,", ",");
+        assert!(matches!(
+            ClusterSnapshot::from_csv(&truncated),
+            Err(SnapshotError::InvalidCsv { line: 3.., .. })
+        ));
+    }
+}
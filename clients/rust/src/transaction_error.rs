@@ -0,0 +1,372 @@
+//! Decoding of failed transactions into Feature Gate program errors.
+//!
+//! A failed instruction surfaces as an opaque `custom program error: 0x0` or a
+//! bare `InstructionError`. The helpers in this module map those errors back
+//! to the [`FeatureGateError`] variants and `ProgramError` cases the program
+//! can return, and attach a remediation hint to each.
+
+use {
+    crate::{errors::FeatureGateError, ID},
+    num_traits::FromPrimitive,
+    solana_instruction::error::InstructionError,
+    solana_message::Message,
+    solana_transaction_error::TransactionError,
+    thiserror::Error,
+};
+
+/// An error returned by the Feature Gate program.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum FeatureGateProgramError {
+    /// A custom error defined by the program.
+    #[error("{0}")]
+    Program(FeatureGateError),
+    /// A custom error code the client does not know about.
+    #[error("Unknown custom program error: {0:#x}")]
+    UnknownCustom(u32),
+    /// An account the instruction requires to sign did not.
+    #[error("Missing required signature")]
+    MissingRequiredSignature,
+    /// The feature account is not owned by the Feature Gate program.
+    #[error("Invalid account owner")]
+    InvalidAccountOwner,
+    /// The feature account data is not a valid feature.
+    #[error("Invalid account data")]
+    InvalidAccountData,
+    /// The instruction data does not decode to a known instruction.
+    #[error("Invalid instruction data")]
+    InvalidInstructionData,
+    /// The instruction was given too few accounts.
+    #[error("Not enough account keys")]
+    NotEnoughAccountKeys,
+    /// An account was passed without the privileges the program needs.
+    #[error("Privilege escalation")]
+    PrivilegeEscalation,
+    /// The lamports of an account passed as read-only were changed.
+    #[error("Read-only account lamports changed")]
+    ReadonlyLamportChange,
+    /// Any other instruction error.
+    #[error("{0}")]
+    Other(InstructionError),
+}
+
+impl FeatureGateProgramError {
+    /// A human-readable hint describing how to resolve the error.
+    pub fn hint(&self) -> &'static str {
+        match self {
+            Self::Program(FeatureGateError::FeatureAlreadyActivated) => {
+                "The runtime has already activated this feature; activated features cannot be \
+                 revoked."
+            }
+            Self::Program(FeatureGateError::InvalidVoteAuthority) => {
+                "Sign with the vote account's node identity or authorized withdrawer to signal \
+                 support on its behalf."
+            }
+            Self::Program(FeatureGateError::FeatureSufficientlySupported) => {
+                "Enough stake has signaled support for this feature this epoch, so it can only be \
+                 revoked with the feature keypair."
+            }
+            Self::Program(FeatureGateError::RevokeWindowClosed) => {
+                "Guarded features can only be revoked permissionlessly near the end of an epoch; \
+                 retry once the revoke window opens."
+            }
+            Self::Program(FeatureGateError::InvalidRefundRecipient) => {
+                "Refunds can only go to recipients listed in the program config, which must be \
+                 passed after the System program; otherwise send the lamports to the incinerator."
+            }
+            Self::Program(FeatureGateError::ActivationNotDue) => {
+                "A scheduled activation can only be released from the epoch before its target; \
+                 retry once that epoch begins."
+            }
+            Self::Program(FeatureGateError::InvalidBundleMember) => {
+                "Pass every member feature account of the bundle, in the order the bundle lists \
+                 them; fetch the bundle to get its member list."
+            }
+            Self::Program(FeatureGateError::InvalidConfig) => {
+                "The revoke guard window must be non-zero, and refund recipients must be distinct \
+                 and must not include the incinerator."
+            }
+            Self::UnknownCustom(_) => {
+                "The program returned an error code this client does not recognize; make sure the \
+                 client is up to date with the deployed program."
+            }
+            Self::MissingRequiredSignature => {
+                "An account the instruction requires to sign did not, such as the feature account \
+                 for a revoke or the authority for a config update; sign with its keypair."
+            }
+            Self::InvalidAccountOwner => {
+                "The feature account is not owned by the Feature Gate program; it was never queued \
+                 for activation or has already been revoked."
+            }
+            Self::InvalidAccountData => {
+                "The feature account data is not a valid feature; check that the feature ID is \
+                 correct."
+            }
+            Self::InvalidInstructionData => {
+                "The instruction data was not recognized; rebuild the instruction with this client."
+            }
+            Self::NotEnoughAccountKeys => {
+                "The instruction is missing accounts; build it with this client's instruction \
+                 builders, which pass every account the program expects."
+            }
+            Self::PrivilegeEscalation => {
+                "An account was passed without the signer or writable privilege the instruction \
+                 requires; check the signer and writable flags of its accounts."
+            }
+            Self::ReadonlyLamportChange => {
+                "An account whose lamports the instruction moves was passed read-only, such as the \
+                 feature account or the recipient of its lamports; pass it as writable."
+            }
+            Self::Other(_) => "The error was not raised by the Feature Gate program itself.",
+        }
+    }
+}
+
+impl From<&InstructionError> for FeatureGateProgramError {
+    // The runtime still reports `ProgramError::NotEnoughAccountKeys` as the
+    // deprecated variant.
+    #[allow(deprecated)]
+    fn from(error: &InstructionError) -> Self {
+        match error {
+            InstructionError::Custom(code) => FeatureGateError::from_u32(*code)
+                .map(Self::Program)
+                .unwrap_or(Self::UnknownCustom(*code)),
+            InstructionError::MissingRequiredSignature => Self::MissingRequiredSignature,
+            InstructionError::InvalidAccountOwner => Self::InvalidAccountOwner,
+            InstructionError::InvalidAccountData => Self::InvalidAccountData,
+            InstructionError::InvalidInstructionData => Self::InvalidInstructionData,
+            InstructionError::NotEnoughAccountKeys | InstructionError::MissingAccount => {
+                Self::NotEnoughAccountKeys
+            }
+            InstructionError::PrivilegeEscalation => Self::PrivilegeEscalation,
+            InstructionError::ReadonlyLamportChange => Self::ReadonlyLamportChange,
+            other => Self::Other(other.clone()),
+        }
+    }
+}
+
+/// A failed transaction decoded into a Feature Gate program error.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[error("Instruction {instruction_index} failed: {error}")]
+pub struct DecodedTransactionError {
+    /// Index of the failing instruction within the transaction.
+    pub instruction_index: u8,
+    /// The decoded program error.
+    pub error: FeatureGateProgramError,
+    /// Messages logged by the Feature Gate program before it failed.
+    pub program_logs: Vec<String>,
+}
+
+impl DecodedTransactionError {
+    /// A human-readable hint describing how to resolve the error.
+    pub fn hint(&self) -> &'static str {
+        self.error.hint()
+    }
+}
+
+/// Decodes an instruction error returned by the Feature Gate program.
+pub fn decode_instruction_error(error: &InstructionError) -> FeatureGateProgramError {
+    error.into()
+}
+
+/// Decodes a failed transaction into a Feature Gate program error.
+///
+/// Returns `None` when the transaction did not fail in the Feature Gate
+/// program. When `logs` are provided, they are used to identify the failing
+/// program, which may have been invoked through a CPI, and to collect the
+/// messages it logged. Otherwise, the failing instruction of `message` must
+/// call the Feature Gate program directly.
+pub fn decode_transaction_error(
+    error: &TransactionError,
+    message: &Message,
+    logs: Option<&[String]>,
+) -> Option<DecodedTransactionError> {
+    let TransactionError::InstructionError(instruction_index, instruction_error) = error else {
+        return None;
+    };
+
+    let program_logs = match logs {
+        Some(logs) => {
+            let failed_prefix = format!("Program {ID} failed");
+            let failed_program = logs
+                .iter()
+                .rev()
+                .find(|log| log.starts_with("Program ") && log.contains(" failed"))?;
+            if !failed_program.starts_with(&failed_prefix) {
+                return None;
+            }
+            feature_gate_logs(logs)
+        }
+        None => {
+            let instruction = message.instructions.get(usize::from(*instruction_index))?;
+            if instruction.program_id(&message.account_keys) != &ID {
+                return None;
+            }
+            Vec::new()
+        }
+    };
+
+    Some(DecodedTransactionError {
+        instruction_index: *instruction_index,
+        error: instruction_error.into(),
+        program_logs,
+    })
+}
+
+/// Collects the `Program log:` messages of the last Feature Gate invocation.
+fn feature_gate_logs(logs: &[String]) -> Vec<String> {
+    let invoke_prefix = format!("Program {ID} invoke");
+    let start = logs
+        .iter()
+        .rposition(|log| log.starts_with(&invoke_prefix))
+        .unwrap_or_default();
+    logs[start..]
+        .iter()
+        .filter_map(|log| log.strip_prefix("Program log: "))
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        solana_address::Address,
+        solana_instruction::{AccountMeta, Instruction},
+        solana_sdk_ids::system_program,
+    };
+
+    fn logs(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    /// A message whose instructions call `program_ids`, in order.
+    fn message(program_ids: &[Address]) -> Message {
+        let payer = Address::new_unique();
+        let instructions = program_ids
+            .iter()
+            .map(|program_id| Instruction {
+                program_id: *program_id,
+                accounts: vec![AccountMeta::new(payer, true)],
+                data: Vec::new(),
+            })
+            .collect::<Vec<_>>();
+        Message::new(&instructions, Some(&payer))
+    }
+
+    #[test]
+    fn test_decode_custom_error() {
+        let error = TransactionError::InstructionError(0, InstructionError::Custom(0));
+        let decoded = decode_transaction_error(&error, &message(&[ID]), None).unwrap();
+        assert_eq!(decoded.instruction_index, 0);
+        assert_eq!(
+            decoded.error,
+            FeatureGateProgramError::Program(FeatureGateError::FeatureAlreadyActivated)
+        );
+        assert_eq!(
+            decoded.to_string(),
+            "Instruction 0 failed: Feature already activated"
+        );
+
+        assert_eq!(
+            decode_instruction_error(&InstructionError::Custom(42)),
+            FeatureGateProgramError::UnknownCustom(42)
+        );
+    }
+
+    #[test]
+    fn test_decode_program_errors() {
+        for (error, expected) in [
+            (
+                InstructionError::MissingRequiredSignature,
+                FeatureGateProgramError::MissingRequiredSignature,
+            ),
+            (
+                InstructionError::InvalidAccountOwner,
+                FeatureGateProgramError::InvalidAccountOwner,
+            ),
+            (
+                InstructionError::InvalidAccountData,
+                FeatureGateProgramError::InvalidAccountData,
+            ),
+            (
+                InstructionError::InvalidInstructionData,
+                FeatureGateProgramError::InvalidInstructionData,
+            ),
+            (
+                InstructionError::MissingAccount,
+                FeatureGateProgramError::NotEnoughAccountKeys,
+            ),
+            (
+                InstructionError::ReadonlyLamportChange,
+                FeatureGateProgramError::ReadonlyLamportChange,
+            ),
+            (
+                InstructionError::AccountBorrowFailed,
+                FeatureGateProgramError::Other(InstructionError::AccountBorrowFailed),
+            ),
+        ] {
+            assert_eq!(decode_instruction_error(&error), expected);
+        }
+    }
+
+    #[test]
+    fn test_decode_with_logs() {
+        let error = TransactionError::InstructionError(1, InstructionError::Custom(0));
+        let logs = logs(&[
+            "Program ComputeBudget111111111111111111111111111111 invoke [1]",
+            "Program ComputeBudget111111111111111111111111111111 success",
+            "Program Feature111111111111111111111111111111111111 invoke [1]",
+            "Program log: Instruction: RevokePendingActivation",
+            "Program log: Feature already activated",
+            "Program Feature111111111111111111111111111111111111 consumed 1700 of 200000 compute \
+             units",
+            "Program Feature111111111111111111111111111111111111 failed: custom program error: 0x0",
+        ]);
+
+        let message = message(&[solana_sdk_ids::compute_budget::id(), ID]);
+        let decoded = decode_transaction_error(&error, &message, Some(&logs)).unwrap();
+        assert_eq!(decoded.instruction_index, 1);
+        assert_eq!(
+            decoded.program_logs,
+            vec![
+                "Instruction: RevokePendingActivation".to_string(),
+                "Feature already activated".to_string(),
+            ]
+        );
+        assert_eq!(
+            decoded.hint(),
+            "The runtime has already activated this feature; activated features cannot be revoked."
+        );
+    }
+
+    #[test]
+    fn test_decode_other_program_failure() {
+        let error = TransactionError::InstructionError(0, InstructionError::Custom(0));
+        let logs = logs(&[
+            "Program 11111111111111111111111111111111 invoke [1]",
+            "Program 11111111111111111111111111111111 failed: custom program error: 0x0",
+        ]);
+        let message = message(&[system_program::id()]);
+        assert_eq!(
+            decode_transaction_error(&error, &message, Some(&logs)),
+            None
+        );
+
+        // Without logs, the failing instruction's program identifies the
+        // failing program.
+        assert_eq!(decode_transaction_error(&error, &message, None), None);
+        assert_eq!(
+            decode_transaction_error(
+                &TransactionError::InstructionError(1, InstructionError::Custom(0)),
+                &message,
+                None
+            ),
+            None
+        );
+
+        assert_eq!(
+            decode_transaction_error(&TransactionError::AccountNotFound, &message, None),
+            None
+        );
+    }
+}
@@ -0,0 +1,753 @@
+//! Streaming of feature state transitions.
+//!
+//! A [`Watcher`] repeatedly reads every feature account through a
+//! [`FeatureAccountSource`] and reports a [`WatchEvent`] whenever a feature is
+//! scheduled, released, queued, revoked or activated. Events are delivered to
+//! an [`EventSink`]: JSON lines on stdout or in a file ([`JsonLinesSink`]),
+//! or, with the `webhook` feature, an HTTP endpoint ([`WebhookSink`]).
+//!
+//! With the `fetch` feature, [`RpcClient`] is a source that polls
+//! `getProgramAccounts`. Other transports, such as a subscription or a test
+//! double, only need to implement [`FeatureAccountSource`].
+//!
+//! [`Watcher::run`] keeps going through transient failures: a failed read is
+//! retried with exponential backoff, and an event that cannot be delivered is
+//! reported and skipped.
+
+#[cfg(feature = "fetch")]
+use solana_rpc_client::rpc_client::RpcClient;
+use {
+    crate::{
+        diff::FeatureSet,
+        known_features::KnownFeatureRegistry,
+        snapshot::{ClusterSnapshot, FeatureStatus, SnapshotError},
+    },
+    serde::{Deserialize, Serialize},
+    solana_address::Address,
+    solana_clock::{Epoch, Slot},
+    std::{
+        collections::BTreeSet,
+        fs::{File, OpenOptions},
+        io::{self, Stdout, Write},
+        path::Path,
+        time::Duration,
+    },
+    thiserror::Error,
+};
+
+/// Errors that can occur while watching features.
+#[derive(Debug, Error)]
+pub enum WatchError {
+    /// The feature accounts could not be read.
+    #[error(transparent)]
+    Snapshot(#[from] SnapshotError),
+    /// An event could not be written.
+    #[error("Failed to write event: {0}")]
+    Io(#[from] io::Error),
+    /// An event could not be serialized.
+    #[error("Failed to serialize event: {0}")]
+    Json(#[from] serde_json::Error),
+    /// An event could not be delivered to a webhook.
+    #[cfg(feature = "webhook")]
+    #[error("Failed to deliver event to webhook: {0}")]
+    Webhook(#[from] reqwest::Error),
+}
+
+/// A transport that reads the current state of every feature account.
+pub trait FeatureAccountSource {
+    /// Reads every feature account.
+    fn snapshot(&mut self) -> Result<ClusterSnapshot, SnapshotError>;
+}
+
+#[cfg(feature = "fetch")]
+impl FeatureAccountSource for RpcClient {
+    fn snapshot(&mut self) -> Result<ClusterSnapshot, SnapshotError> {
+        ClusterSnapshot::fetch(self)
+    }
+}
+
+/// A change in a feature's state.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase",
+    tag = "event"
+)]
+pub enum FeatureTransition {
+    /// The feature account was created with a scheduled activation.
+    Scheduled {
+        /// The earliest epoch in which the feature may activate.
+        not_before_epoch: Epoch,
+    },
+    /// A scheduled feature was released and is awaiting activation.
+    Released,
+    /// A scheduled feature account was closed or reassigned before it was
+    /// released.
+    ScheduledRevoked,
+    /// The feature account was created and is awaiting activation.
+    Queued,
+    /// A pending feature account was closed or reassigned before activation.
+    Revoked,
+    /// The runtime activated the feature.
+    Activated {
+        /// The slot at which the feature was activated.
+        activated_at: Slot,
+    },
+}
+
+impl FeatureTransition {
+    /// The transition between two observed statuses of a feature, if any.
+    ///
+    /// A feature that is queued, or released, and activated between two
+    /// observations is reported as activated.
+    pub fn between(
+        previous: Option<FeatureStatus>,
+        current: Option<FeatureStatus>,
+    ) -> Option<Self> {
+        match (previous, current) {
+            (Some(FeatureStatus::Active { .. }), _) => None,
+            (_, Some(FeatureStatus::Active { activated_at })) => {
+                Some(Self::Activated { activated_at })
+            }
+            (Some(FeatureStatus::Scheduled { .. }), Some(FeatureStatus::Scheduled { .. })) => None,
+            (_, Some(FeatureStatus::Scheduled { not_before_epoch })) => {
+                Some(Self::Scheduled { not_before_epoch })
+            }
+            (Some(FeatureStatus::Scheduled { .. }), Some(FeatureStatus::Pending)) => {
+                Some(Self::Released)
+            }
+            (Some(FeatureStatus::Scheduled { .. }), _) => Some(Self::ScheduledRevoked),
+            (Some(FeatureStatus::Pending), Some(FeatureStatus::Pending)) => None,
+            (_, Some(FeatureStatus::Pending)) => Some(Self::Queued),
+            (Some(FeatureStatus::Pending), _) => Some(Self::Revoked),
+            _ => None,
+        }
+    }
+}
+
+/// A feature state transition observed by a [`Watcher`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchEvent {
+    /// The slot at which the transition was observed, if known.
+    pub slot: Option<Slot>,
+    /// The feature ID.
+    #[serde(with = "serde_with::As::<serde_with::DisplayFromStr>")]
+    pub id: Address,
+    /// The feature's name in the known-feature registry, if any.
+    pub name: Option<String>,
+    /// What changed.
+    #[serde(flatten)]
+    pub transition: FeatureTransition,
+}
+
+/// A destination for watch events.
+pub trait EventSink {
+    /// Delivers an event.
+    fn send(&mut self, event: &WatchEvent) -> Result<(), WatchError>;
+}
+
+impl EventSink for Vec<WatchEvent> {
+    fn send(&mut self, event: &WatchEvent) -> Result<(), WatchError> {
+        self.push(event.clone());
+        Ok(())
+    }
+}
+
+/// A sink that writes each event as a line of JSON.
+#[derive(Debug)]
+pub struct JsonLinesSink<W> {
+    writer: W,
+}
+
+impl<W: Write> JsonLinesSink<W> {
+    /// Creates a sink that writes to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl JsonLinesSink<Stdout> {
+    /// Creates a sink that writes to stdout.
+    pub fn stdout() -> Self {
+        Self::new(io::stdout())
+    }
+}
+
+impl JsonLinesSink<File> {
+    /// Creates a sink that appends to the file at `path`, creating it if
+    /// needed.
+    pub fn append(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(
+            OpenOptions::new().create(true).append(true).open(path)?,
+        ))
+    }
+}
+
+impl<W: Write> EventSink for JsonLinesSink<W> {
+    fn send(&mut self, event: &WatchEvent) -> Result<(), WatchError> {
+        serde_json::to_writer(&mut self.writer, event)?;
+        self.writer.write_all(b"\n")?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// The number of times [`WebhookSink`] retries a failed delivery by default.
+#[cfg(feature = "webhook")]
+pub const DEFAULT_WEBHOOK_RETRIES: u32 = 3;
+
+/// The delay before [`WebhookSink`] first retries a failed delivery. Each
+/// further retry waits twice as long as the previous one.
+#[cfg(feature = "webhook")]
+const WEBHOOK_RETRY_DELAY: Duration = Duration::from_millis(250);
+
+/// A sink that POSTs each event as JSON to a webhook URL.
+///
+/// A delivery that fails, whether the request errors or the endpoint
+/// responds with an error status, is retried up to
+/// [`DEFAULT_WEBHOOK_RETRIES`] times before the sink gives up on the event.
+#[cfg(feature = "webhook")]
+#[derive(Clone, Debug)]
+pub struct WebhookSink {
+    client: reqwest::blocking::Client,
+    url: String,
+    retries: u32,
+}
+
+#[cfg(feature = "webhook")]
+impl WebhookSink {
+    /// Creates a sink that posts to `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::blocking::Client::new(),
+            url: url.into(),
+            retries: DEFAULT_WEBHOOK_RETRIES,
+        }
+    }
+
+    /// Retries a failed delivery up to `retries` times.
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    fn post(&self, event: &WatchEvent) -> Result<(), reqwest::Error> {
+        self.client
+            .post(&self.url)
+            .json(event)
+            .send()?
+            .error_for_status()?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "webhook")]
+impl EventSink for WebhookSink {
+    fn send(&mut self, event: &WatchEvent) -> Result<(), WatchError> {
+        let mut delay = WEBHOOK_RETRY_DELAY;
+        let mut retries = self.retries;
+        loop {
+            match self.post(event) {
+                Ok(()) => return Ok(()),
+                Err(_) if retries > 0 => {
+                    retries = retries.saturating_sub(1);
+                    std::thread::sleep(delay);
+                    delay = delay.saturating_mul(2);
+                }
+                Err(error) => return Err(error.into()),
+            }
+        }
+    }
+}
+
+/// The longest [`Watcher::run`] waits between failed reads by default.
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Polls a [`FeatureAccountSource`] and reports feature state transitions.
+#[derive(Debug)]
+pub struct Watcher<S> {
+    source: S,
+    registry: KnownFeatureRegistry,
+    features: Option<FeatureSet>,
+    max_backoff: Duration,
+}
+
+impl<S: FeatureAccountSource> Watcher<S> {
+    /// Creates a watcher that names features using the built-in registry.
+    pub fn new(source: S) -> Self {
+        Self {
+            source,
+            registry: KnownFeatureRegistry::new(),
+            features: None,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Names features using `registry`.
+    pub fn registry(mut self, registry: KnownFeatureRegistry) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Waits at most `max_backoff` between failed reads in [`Watcher::run`].
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Reads the feature accounts once and returns the transitions since the
+    /// previous poll, ordered by feature ID.
+    ///
+    /// The first poll only records the initial state and returns no events.
+    pub fn poll(&mut self) -> Result<Vec<WatchEvent>, WatchError> {
+        let snapshot = self.source.snapshot()?;
+        let current = FeatureSet::from(&snapshot);
+        let events = match &self.features {
+            Some(previous) => self.transitions(previous, &current, snapshot.slot),
+            None => Vec::new(),
+        };
+        self.features = Some(current);
+        Ok(events)
+    }
+
+    /// Polls every `interval`, sending each event to `sink`, for as long as
+    /// `keep_watching` returns `true`.
+    ///
+    /// Errors do not stop the watcher; each is passed to `on_error`. After a
+    /// failed read, the wait before the next poll doubles, up to the maximum
+    /// backoff, until a read succeeds. An event that cannot be delivered is
+    /// skipped.
+    pub fn run(
+        &mut self,
+        sink: &mut dyn EventSink,
+        interval: Duration,
+        mut keep_watching: impl FnMut() -> bool,
+        mut on_error: impl FnMut(&WatchError),
+    ) {
+        let mut delay = interval;
+        while keep_watching() {
+            match self.poll() {
+                Ok(events) => {
+                    for event in events {
+                        if let Err(error) = sink.send(&event) {
+                            on_error(&error);
+                        }
+                    }
+                    delay = interval;
+                }
+                Err(error) => {
+                    on_error(&error);
+                    delay = delay.saturating_mul(2).min(self.max_backoff).max(interval);
+                }
+            }
+            std::thread::sleep(delay);
+        }
+    }
+
+    fn transitions(
+        &self,
+        previous: &FeatureSet,
+        current: &FeatureSet,
+        slot: Option<Slot>,
+    ) -> Vec<WatchEvent> {
+        let ids = previous
+            .iter()
+            .chain(current.iter())
+            .map(|(id, _)| *id)
+            .collect::<BTreeSet<_>>();
+        ids.into_iter()
+            .filter_map(|id| {
+                let transition = FeatureTransition::between(previous.get(&id), current.get(&id))?;
+                Some(WatchEvent {
+                    slot,
+                    id,
+                    name: self
+                        .registry
+                        .get(&id)
+                        .map(|feature| feature.name.to_string()),
+                    transition,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::snapshot::FeatureAccountSnapshot,
+        solana_account::Account,
+        solana_address::address,
+        solana_feature_gate_interface::{create_account, Feature},
+        std::collections::VecDeque,
+    };
+
+    const LOADER_V4: Address = address!("2aQJYqER2aKyb3cZw22v4SL2xMX7vwXBRWfvS4pTrtED");
+
+    fn feature_account(activated_at: Option<Slot>) -> Account {
+        Account::from(create_account(&Feature { activated_at }, 1))
+    }
+
+    fn cluster(slot: Slot, accounts: &[(Address, Option<Slot>)]) -> ClusterSnapshot {
+        ClusterSnapshot::new(
+            Some(slot),
+            accounts
+                .iter()
+                .map(|(address, activated_at)| {
+                    FeatureAccountSnapshot::from_account(
+                        *address,
+                        &feature_account(*activated_at),
+                        Some(slot),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    struct FakeSource(VecDeque<Result<ClusterSnapshot, SnapshotError>>);
+
+    impl FeatureAccountSource for FakeSource {
+        fn snapshot(&mut self) -> Result<ClusterSnapshot, SnapshotError> {
+            self.0.pop_front().expect("no more snapshots")
+        }
+    }
+
+    /// A sink that fails to deliver every event.
+    struct FailingSink;
+
+    impl EventSink for FailingSink {
+        fn send(&mut self, _event: &WatchEvent) -> Result<(), WatchError> {
+            Err(io::Error::other("unreachable").into())
+        }
+    }
+
+    #[test]
+    fn test_transitions() {
+        use FeatureStatus::*;
+        let active = Active { activated_at: 5 };
+        let activated = Some(FeatureTransition::Activated { activated_at: 5 });
+        let scheduled = Scheduled {
+            not_before_epoch: 3,
+        };
+
+        let cases = [
+            (None, Some(Pending), Some(FeatureTransition::Queued)),
+            (
+                Some(Invalid),
+                Some(Pending),
+                Some(FeatureTransition::Queued),
+            ),
+            (Some(Pending), None, Some(FeatureTransition::Revoked)),
+            (
+                Some(Pending),
+                Some(Invalid),
+                Some(FeatureTransition::Revoked),
+            ),
+            (Some(Pending), Some(active), activated),
+            (None, Some(active), activated),
+            (Some(Pending), Some(Pending), None),
+            (Some(active), Some(active), None),
+            (Some(active), None, None),
+            (None, Some(Invalid), None),
+            (
+                None,
+                Some(scheduled),
+                Some(FeatureTransition::Scheduled {
+                    not_before_epoch: 3,
+                }),
+            ),
+            (
+                Some(scheduled),
+                Some(Pending),
+                Some(FeatureTransition::Released),
+            ),
+            (Some(scheduled), Some(active), activated),
+            (
+                Some(scheduled),
+                None,
+                Some(FeatureTransition::ScheduledRevoked),
+            ),
+            (
+                Some(scheduled),
+                Some(Invalid),
+                Some(FeatureTransition::ScheduledRevoked),
+            ),
+            (Some(scheduled), Some(scheduled), None),
+        ];
+        for (previous, current, expected) in cases {
+            assert_eq!(
+                FeatureTransition::between(previous, current),
+                expected,
+                "{previous:?} -> {current:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_watcher() {
+        let (queued, revoked) = (Address::new_unique(), Address::new_unique());
+        let source = FakeSource(VecDeque::from([
+            Ok(cluster(10, &[(LOADER_V4, None), (revoked, None)])),
+            Ok(cluster(
+                20,
+                &[(LOADER_V4, None), (revoked, None), (queued, None)],
+            )),
+            Ok(cluster(30, &[(LOADER_V4, Some(25)), (queued, None)])),
+        ]));
+        let mut watcher = Watcher::new(source);
+        let mut events = Vec::new();
+        let mut polls = 0u8;
+        watcher.run(
+            &mut events,
+            Duration::ZERO,
+            || {
+                polls = polls.saturating_add(1);
+                polls <= 3
+            },
+            |error| panic!("unexpected error: {error}"),
+        );
+
+        let mut expected_last = vec![
+            WatchEvent {
+                slot: Some(30),
+                id: LOADER_V4,
+                name: Some("enable_loader_v4".to_string()),
+                transition: FeatureTransition::Activated { activated_at: 25 },
+            },
+            WatchEvent {
+                slot: Some(30),
+                id: revoked,
+                name: None,
+                transition: FeatureTransition::Revoked,
+            },
+        ];
+        expected_last.sort_by_key(|event| event.id);
+        let mut expected = vec![WatchEvent {
+            slot: Some(20),
+            id: queued,
+            name: None,
+            transition: FeatureTransition::Queued,
+        }];
+        expected.extend(expected_last);
+        assert_eq!(events, expected);
+    }
+
+    #[test]
+    fn test_watcher_survives_errors() {
+        let queued = Address::new_unique();
+        let source = FakeSource(VecDeque::from([
+            Ok(cluster(10, &[])),
+            Err(SnapshotError::AccountNotFound(queued)),
+            Ok(cluster(20, &[(queued, None)])),
+            Err(SnapshotError::AccountNotFound(queued)),
+            Ok(cluster(30, &[])),
+        ]));
+        let mut watcher = Watcher::new(source).max_backoff(Duration::ZERO);
+        let mut errors = Vec::new();
+        let mut polls = 0u8;
+        watcher.run(
+            &mut FailingSink,
+            Duration::ZERO,
+            || {
+                polls = polls.saturating_add(1);
+                polls <= 5
+            },
+            |error| errors.push(error.to_string()),
+        );
+
+        // Both failed reads and both undeliverable events are reported, and
+        // the watcher keeps polling after each.
+        assert_eq!(
+            errors,
+            vec![
+                format!("Account {queued} not found"),
+                "Failed to write event: unreachable".to_string(),
+                format!("Account {queued} not found"),
+                "Failed to write event: unreachable".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_lines_sink() {
+        let event = WatchEvent {
+            slot: Some(30),
+            id: LOADER_V4,
+            name: Some("enable_loader_v4".to_string()),
+            transition: FeatureTransition::Activated { activated_at: 25 },
+        };
+        let mut sink = JsonLinesSink::new(Vec::new());
+        sink.send(&event).unwrap();
+        sink.send(&event).unwrap();
+
+        let output = String::from_utf8(sink.into_inner()).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            format!(
+                r#"{{"slot":30,"id":"{LOADER_V4}","name":"enable_loader_v4","event":"activated","activatedAt":25}}"#
+            )
+        );
+        assert_eq!(serde_json::from_str::<WatchEvent>(lines[1]).unwrap(), event);
+
+        for (transition, json) in [
+            (
+                FeatureTransition::Scheduled {
+                    not_before_epoch: 3,
+                },
+                r#"{"event":"scheduled","notBeforeEpoch":3}"#,
+            ),
+            (FeatureTransition::Released, r#"{"event":"released"}"#),
+            (
+                FeatureTransition::ScheduledRevoked,
+                r#"{"event":"scheduledRevoked"}"#,
+            ),
+        ] {
+            assert_eq!(serde_json::to_string(&transition).unwrap(), json);
+        }
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn test_watcher_with_mock_rpc() {
+        use {
+            base64::{prelude::BASE64_STANDARD, Engine},
+            solana_rpc_client::{api::request::RpcRequest, mock_sender::MocksMap},
+        };
+
+        let program_accounts = |activated_at: Option<Slot>| {
+            let account = feature_account(activated_at);
+            serde_json::json!([{
+                "pubkey": LOADER_V4.to_string(),
+                "account": {
+                    "lamports": account.lamports,
+                    "data": [BASE64_STANDARD.encode(&account.data), "base64"],
+                    "owner": crate::ID.to_string(),
+                    "executable": false,
+                    "rentEpoch": 0,
+                    "space": account.data.len(),
+                },
+            }])
+        };
+        let mut mocks = MocksMap::default();
+        mocks.insert(RpcRequest::GetSlot, serde_json::json!(100));
+        mocks.insert(RpcRequest::GetProgramAccounts, program_accounts(None));
+        mocks.insert(RpcRequest::GetSlot, serde_json::json!(200));
+        mocks.insert(RpcRequest::GetProgramAccounts, program_accounts(Some(150)));
+
+        let mut watcher = Watcher::new(RpcClient::new_mock_with_mocks_map("succeeds", mocks));
+        assert!(watcher.poll().unwrap().is_empty());
+        assert_eq!(
+            watcher.poll().unwrap(),
+            vec![WatchEvent {
+                slot: Some(200),
+                id: LOADER_V4,
+                name: Some("enable_loader_v4".to_string()),
+                transition: FeatureTransition::Activated { activated_at: 150 },
+            }]
+        );
+    }
+
+    /// Serves one request per status in `statuses`, in order, responding
+    /// with that status and returning the request bodies.
+    #[cfg(feature = "webhook")]
+    fn webhook_server(
+        statuses: &'static [&'static str],
+    ) -> (String, std::thread::JoinHandle<Vec<Vec<u8>>>) {
+        use std::{
+            io::{BufRead, BufReader, Read},
+            net::TcpListener,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/events", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            statuses
+                .iter()
+                .map(|status| {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if line == "\r\n" {
+                            break;
+                        }
+                        if let Some(value) =
+                            line.to_ascii_lowercase().strip_prefix("content-length:")
+                        {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                    let mut body = vec![0; content_length];
+                    reader.read_exact(&mut body).unwrap();
+                    write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    )
+                    .unwrap();
+                    body
+                })
+                .collect()
+        });
+        (url, server)
+    }
+
+    #[cfg(feature = "webhook")]
+    fn webhook_event() -> WatchEvent {
+        WatchEvent {
+            slot: Some(20),
+            id: LOADER_V4,
+            name: None,
+            transition: FeatureTransition::Queued,
+        }
+    }
+
+    #[cfg(feature = "webhook")]
+    #[test]
+    fn test_webhook_sink() {
+        let (url, server) = webhook_server(&["204 No Content"]);
+        let event = webhook_event();
+        WebhookSink::new(url).send(&event).unwrap();
+        let bodies = server.join().unwrap();
+        assert_eq!(
+            serde_json::from_slice::<WatchEvent>(&bodies[0]).unwrap(),
+            event
+        );
+    }
+
+    #[cfg(feature = "webhook")]
+    #[test]
+    fn test_webhook_sink_retries() {
+        let (url, server) = webhook_server(&["500 Internal Server Error", "204 No Content"]);
+        let event = webhook_event();
+        WebhookSink::new(url).send(&event).unwrap();
+        let bodies = server.join().unwrap();
+        assert_eq!(bodies.len(), 2);
+        assert_eq!(
+            serde_json::from_slice::<WatchEvent>(&bodies[1]).unwrap(),
+            event
+        );
+    }
+
+    #[cfg(feature = "webhook")]
+    #[test]
+    fn test_webhook_sink_gives_up() {
+        let (url, server) = webhook_server(&["503 Service Unavailable", "503 Service Unavailable"]);
+        let error = WebhookSink::new(url)
+            .retries(1)
+            .send(&webhook_event())
+            .unwrap_err();
+        assert!(matches!(error, WatchError::Webhook(_)));
+        assert_eq!(server.join().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "simulation")]
+
+use {
+    solana_address::Address,
+    solana_feature_gate_client::{
+        errors::FeatureGateError, simulation::Simulator, snapshot::FeatureAccountSnapshot,
+        transaction_error::FeatureGateProgramError, ID,
+    },
+    solana_sdk_ids::{incinerator, system_program},
+};
+
+fn snapshot(data: Vec<u8>) -> FeatureAccountSnapshot {
+    FeatureAccountSnapshot {
+        address: Address::new_unique(),
+        lamports: 1_000_000,
+        owner: ID,
+        data,
+        slot: None,
+    }
+}
+
+#[test]
+fn simulate_revoke_pending() {
+    let snapshot = snapshot(vec![0; 9]);
+
+    let report = Simulator::new().simulate_revoke(&snapshot);
+
+    assert!(report.is_success());
+    assert_eq!(report.error, None);
+    assert!(report.compute_units_consumed > 0);
+    let feature = report.account(&snapshot.address).unwrap();
+    assert_eq!(feature.lamports, 0);
+    assert_eq!(feature.owner, system_program::id());
+    assert!(feature.data.is_empty());
+    assert_eq!(
+        report.account(&incinerator::id()).unwrap().lamports,
+        snapshot.lamports
+    );
+}
+
+#[test]
+fn simulate_revoke_active() {
+    let snapshot = snapshot(vec![1, 45, 0, 0, 0, 0, 0, 0, 0]);
+
+    let report = Simulator::new().simulate_revoke(&snapshot);
+
+    assert!(!report.is_success());
+    assert_eq!(
+        report.error,
+        Some(FeatureGateProgramError::Program(
+            FeatureGateError::FeatureAlreadyActivated
+        ))
+    );
+    assert_eq!(
+        report.account(&snapshot.address).unwrap().lamports,
+        1_000_000
+    );
+}
@@ -1,41 +1,23 @@
 //! Feature Gate program compute unit benchmark testing.
 
 use {
-    mollusk_svm::{program::keyed_account_for_system_program, Mollusk},
     mollusk_svm_bencher::{Bench, MolluskComputeUnitBencher},
-    solana_account::Account,
     solana_address::Address,
-    solana_feature_gate_interface::Feature,
     solana_feature_gate_program::instruction::revoke_pending_activation,
-    solana_sdk_ids::incinerator,
+    solana_feature_gate_test_utils::{mollusk, revoke_accounts, FeatureAccountBuilder},
 };
 
 fn main() {
     std::env::set_var("SBF_OUT_DIR", "../target/deploy");
-    let mollusk = Mollusk::new(
-        &solana_sdk_ids::feature::id(),
-        "solana_feature_gate_program",
-    );
+    let mollusk = mollusk();
 
     let feature = Address::new_unique();
+    let accounts = revoke_accounts(feature, FeatureAccountBuilder::pending().build());
 
     let bench: Bench = (
         "revoke_pending_activation",
         &revoke_pending_activation(&feature),
-        &[
-            (
-                feature,
-                Account::new_data_with_space(
-                    42,
-                    &Feature { activated_at: None },
-                    9,
-                    &solana_sdk_ids::feature::id(),
-                )
-                .unwrap(),
-            ),
-            (incinerator::id(), Account::default()),
-            keyed_account_for_system_program(),
-        ],
+        &accounts,
     );
 
     MolluskComputeUnitBencher::new(mollusk)
@@ -0,0 +1,48 @@
+//! Feature check compute unit benchmark testing.
+
+use {
+    mollusk_svm::Mollusk,
+    mollusk_svm_bencher::{Bench, MolluskComputeUnitBencher},
+    solana_address::Address,
+    solana_feature_gate_check_bench::{CheckInstruction, FEATURE_ID},
+    solana_feature_gate_test_utils::FeatureAccountBuilder,
+    solana_instruction::{AccountMeta, Instruction},
+};
+
+const PROGRAM_NAME: &str = "solana_feature_gate_check_bench";
+
+fn main() {
+    std::env::set_var("SBF_OUT_DIR", "../../target/deploy");
+    let program_id = Address::new_from_array([1; 32]);
+    let mollusk = Mollusk::new(&program_id, PROGRAM_NAME);
+
+    let feature = FEATURE_ID;
+    let accounts = [(feature, FeatureAccountBuilder::active(1).build())];
+    let instruction = |check: CheckInstruction| {
+        Instruction::new_with_bytes(
+            program_id,
+            &[check as u8],
+            vec![AccountMeta::new_readonly(feature, false)],
+        )
+    };
+    let (noop, is_feature_active, feature_state) = (
+        instruction(CheckInstruction::Noop),
+        instruction(CheckInstruction::IsFeatureActive),
+        instruction(CheckInstruction::FeatureState),
+    );
+
+    let benches: [Bench; 3] = [
+        ("noop", &noop, &accounts),
+        ("is_feature_active", &is_feature_active, &accounts),
+        ("feature_state", &feature_state, &accounts),
+    ];
+
+    benches
+        .into_iter()
+        .fold(MolluskComputeUnitBencher::new(mollusk), |bencher, bench| {
+            bencher.bench(bench)
+        })
+        .must_pass(true)
+        .out_dir("./benches")
+        .execute();
+}
@@ -0,0 +1,63 @@
+//! A program that does nothing but check a feature account, so that the
+//! compute units [`solana_feature_gate_check`] adds to a calling program can
+//! be benchmarked.
+//!
+//! The single instruction data byte selects the check, as a
+//! [`CheckInstruction`], and the first account is the account of
+//! [`FEATURE_ID`].
+
+#![deny(missing_docs)]
+
+use {
+    solana_account_info::AccountInfo,
+    solana_address::Address,
+    solana_feature_gate_check::{feature_state, is_feature_active},
+    solana_program_error::{ProgramError, ProgramResult},
+};
+
+#[cfg(target_os = "solana")]
+solana_program_entrypoint::entrypoint!(process);
+
+/// The feature the benchmark checks.
+pub const FEATURE_ID: Address = Address::new_from_array([2; 32]);
+
+/// The check to run.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u8)]
+pub enum CheckInstruction {
+    /// Return without checking anything, to measure the baseline.
+    Noop,
+    /// Call [`is_feature_active`].
+    IsFeatureActive,
+    /// Call [`feature_state`].
+    FeatureState,
+}
+
+impl CheckInstruction {
+    /// Unpacks a byte buffer into a [`CheckInstruction`].
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        match input {
+            [0] => Ok(Self::Noop),
+            [1] => Ok(Self::IsFeatureActive),
+            [2] => Ok(Self::FeatureState),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// Runs the check selected by `input` on the first account, failing if the
+/// feature is not active.
+pub fn process(_program_id: &Address, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
+    let instruction = CheckInstruction::unpack(input)?;
+    let account = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    let active = match instruction {
+        CheckInstruction::Noop => return Ok(()),
+        CheckInstruction::IsFeatureActive => is_feature_active(account, &FEATURE_ID)?.is_some(),
+        CheckInstruction::FeatureState => feature_state(account, &FEATURE_ID)?.is_active(),
+    };
+    if active {
+        Ok(())
+    } else {
+        Err(ProgramError::InvalidArgument)
+    }
+}
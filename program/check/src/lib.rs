@@ -0,0 +1,210 @@
+//! On-chain feature checks for programs gated on a cluster feature.
+//!
+//! A feature account holds a bincode-serialized `Feature`: one byte that is
+//! `1` once the runtime has activated the feature, followed by the
+//! little-endian activation slot. [`is_feature_active`] reads that slot from
+//! a feature account after verifying the account is the expected feature and
+//! is owned by the Feature Gate program; [`feature_state`] does the same
+//! without copying, returning a [`FeatureState`] view into the account data.
+//!
+//! The Feature Gate program owns accounts other than features, such as its
+//! config, so the owner check alone does not identify a feature. Both
+//! functions take the expected feature ID and reject any other account.
+//!
+//! The crate is `no_std` and does not depend on bincode, so it adds only a
+//! few compute units to the calling program.
+
+#![cfg_attr(not(test), no_std)]
+#![deny(missing_docs)]
+
+use {
+    core::cell::Ref, solana_account_info::AccountInfo, solana_address::Address,
+    solana_program_error::ProgramError,
+};
+
+/// The Feature Gate program ID, which owns every feature account.
+pub const ID: Address = solana_sdk_ids::feature::ID;
+
+/// A zero-copy view of a feature account's data.
+#[derive(Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct FeatureState {
+    is_active: u8,
+    activated_at: [u8; 8],
+}
+
+impl FeatureState {
+    /// The serialized size of a feature.
+    pub const LEN: usize = core::mem::size_of::<Self>();
+
+    /// Interprets feature account data, failing with
+    /// [`ProgramError::InvalidAccountData`] if it is shorter than
+    /// [`FeatureState::LEN`] or is not a valid feature.
+    pub fn from_bytes(data: &[u8]) -> Result<&Self, ProgramError> {
+        let bytes = data
+            .get(..Self::LEN)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        if bytes[0] > 1 {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        // SAFETY: `FeatureState` is `repr(C)`, consists only of bytes, so has
+        // an alignment of 1, and `bytes` is exactly `FeatureState::LEN` long.
+        Ok(unsafe { &*bytes.as_ptr().cast::<Self>() })
+    }
+
+    /// The slot at which the feature was activated, or `None` if it is still
+    /// pending.
+    pub fn activated_at(&self) -> Option<u64> {
+        self.is_active()
+            .then(|| u64::from_le_bytes(self.activated_at))
+    }
+
+    /// Whether the feature has been activated.
+    pub fn is_active(&self) -> bool {
+        self.is_active == 1
+    }
+}
+
+/// Returns the slot at which the feature `feature_id` was activated, or
+/// `None` if it is still pending, reading it from `account`.
+///
+/// Fails with [`ProgramError::InvalidArgument`] if `account` is not the
+/// account of `feature_id`, with [`ProgramError::InvalidAccountOwner`] if the
+/// account is not owned by the Feature Gate program, and with
+/// [`ProgramError::InvalidAccountData`] if its data is not a feature.
+pub fn is_feature_active(
+    account: &AccountInfo,
+    feature_id: &Address,
+) -> Result<Option<u64>, ProgramError> {
+    Ok(feature_state(account, feature_id)?.activated_at())
+}
+
+/// Borrows the feature in `account` without copying its data.
+///
+/// Fails as [`is_feature_active`] does, or with
+/// [`ProgramError::AccountBorrowFailed`] if the account data is already
+/// mutably borrowed.
+pub fn feature_state<'a>(
+    account: &'a AccountInfo,
+    feature_id: &Address,
+) -> Result<Ref<'a, FeatureState>, ProgramError> {
+    if account.key != feature_id {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if account.owner != &ID {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    Ref::filter_map(account.try_borrow_data()?, |data| {
+        FeatureState::from_bytes(data).ok()
+    })
+    .map_err(|_| ProgramError::InvalidAccountData)
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        solana_account::ReadableAccount,
+        solana_feature_gate_interface::{create_account, Feature},
+        solana_feature_gate_program_interface::state::{AuditLog, Config, Tombstone},
+    };
+
+    fn check(owner: &Address, mut data: Vec<u8>) -> Result<Option<u64>, ProgramError> {
+        let key = Address::new_unique();
+        let mut lamports = 1;
+        let account = AccountInfo::new(&key, false, false, &mut lamports, &mut data, owner, false);
+
+        // The zero-copy variant, the bincode decoding this crate replaces,
+        // and `is_feature_active` all agree.
+        let expected = Feature::from_account_info(&account).map(|feature| feature.activated_at);
+        assert_eq!(
+            feature_state(&account, &key).map(|state| state.activated_at()),
+            expected
+        );
+        let result = is_feature_active(&account, &key);
+        assert_eq!(result, expected);
+        result
+    }
+
+    fn feature_data(activated_at: Option<u64>) -> Vec<u8> {
+        create_account(&Feature { activated_at }, 1).data().to_vec()
+    }
+
+    #[test]
+    fn test_is_feature_active() {
+        assert_eq!(check(&ID, feature_data(None)), Ok(None));
+        assert_eq!(check(&ID, feature_data(Some(0))), Ok(Some(0)));
+        assert_eq!(check(&ID, feature_data(Some(u64::MAX))), Ok(Some(u64::MAX)));
+
+        // Trailing data is ignored, as by bincode.
+        let mut data = feature_data(Some(42));
+        data.extend_from_slice(&[7; 8]);
+        assert_eq!(check(&ID, data), Ok(Some(42)));
+    }
+
+    #[test]
+    fn test_is_feature_active_invalid() {
+        assert_eq!(
+            check(&Address::new_unique(), feature_data(Some(1))),
+            Err(ProgramError::InvalidAccountOwner)
+        );
+        assert_eq!(check(&ID, vec![]), Err(ProgramError::InvalidAccountData));
+        assert_eq!(
+            check(&ID, vec![1; FeatureState::LEN - 1]),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            check(&ID, vec![2; FeatureState::LEN]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_is_feature_active_wrong_account() {
+        let (feature_id, key) = (Address::new_unique(), Address::new_unique());
+        let (mut lamports, mut data) = (1, feature_data(Some(7)));
+        let account = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &ID, false);
+        assert_eq!(
+            is_feature_active(&account, &feature_id),
+            Err(ProgramError::InvalidArgument)
+        );
+        assert_eq!(
+            feature_state(&account, &feature_id).unwrap_err(),
+            ProgramError::InvalidArgument
+        );
+    }
+
+    #[test]
+    fn test_is_feature_active_program_account() {
+        // Accounts the Feature Gate program owns besides features are
+        // rejected, even when passed under their own key.
+        for data in [
+            Config::default().pack(),
+            AuditLog::default().pack(),
+            Tombstone { revoked_at: 1 }.pack(),
+        ] {
+            assert_eq!(check(&ID, data), Err(ProgramError::InvalidAccountData));
+        }
+    }
+
+    #[test]
+    fn test_feature_state_borrows_data() {
+        let key = Address::new_unique();
+        let (mut lamports, mut data) = (1, feature_data(Some(7)));
+        let account = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &ID, false);
+
+        let state = feature_state(&account, &key).unwrap();
+        assert!(state.is_active());
+        assert_eq!(
+            account.try_borrow_mut_data().unwrap_err(),
+            ProgramError::AccountBorrowFailed
+        );
+        drop(state);
+
+        let _data = account.try_borrow_mut_data().unwrap();
+        assert_eq!(
+            feature_state(&account, &key).unwrap_err(),
+            ProgramError::AccountBorrowFailed
+        );
+    }
+}
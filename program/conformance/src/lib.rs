@@ -0,0 +1,697 @@
+//! Conformance fixtures for alternative validator clients.
+//!
+//! Success and error paths of every instruction are described as a
+//! [`Case`]. The `generate_fixtures` test (ignored by default) executes each
+//! case against the built program and writes its inputs and effects to
+//! `fixtures/`, both in Mollusk's fixture format and in Firedancer's
+//! `InstrFixture` protobuf format used by SVM conformance tooling:
+//!
+//! ```sh
+//! cargo test --manifest-path program/conformance/Cargo.toml -- --ignored generate_fixtures
+//! ```
+//!
+//! The `replay_*` tests run every fixture against the built program and
+//! check that the result matches. Like the `regression` make target, they
+//! ignore compute units, which vary between toolchains.
+
+use {
+    mollusk_svm_fuzz_fixture::{proto as mollusk_proto, Fixture},
+    mollusk_svm_fuzz_fixture_firedancer::{
+        proto as firedancer_proto, Fixture as FiredancerFixture,
+    },
+    mollusk_svm_fuzz_fs::{IntoSerializableFixture, SerializableFixture},
+    solana_account::Account,
+    solana_address::Address,
+    solana_feature_gate_program::{
+        instruction::{
+            extend_audit_log, initialize_audit_log, initialize_bundle, initialize_config,
+            initialize_stake_guard, queue_bundle, release_scheduled_activation, revoke_bundle,
+            revoke_pending_activation, revoke_pending_activation_with_refund,
+            revoke_unsupported_activation, schedule_activation, signal_support, tally_support,
+            update_config,
+        },
+        state::{
+            find_audit_log_address, find_bundle_address, find_config_address,
+            find_stake_guard_address, find_support_signal_address, AuditLog, Bundle, Config,
+            ScheduledActivation, StakeGuard, TombstonePolicy, MAX_BASIS_POINTS,
+            MAX_REFUND_RECIPIENTS,
+        },
+    },
+    solana_feature_gate_test_utils::{
+        feature_rent, keyed_audit_log_account, keyed_incinerator_account, keyed_program_account,
+        keyed_system_program_account, revoke_accounts, vote_account, FeatureAccountBuilder,
+    },
+    solana_instruction::Instruction,
+    solana_message::Message,
+    solana_rent::Rent,
+    solana_sdk_ids::{bpf_loader_upgradeable, feature, system_program},
+    std::{
+        collections::BTreeSet,
+        fs,
+        path::{Path, PathBuf},
+    },
+};
+
+/// The fixture formats, each written to the directory of the same name.
+pub const FORMATS: [&str; 2] = ["mollusk", "firedancer"];
+
+/// A single instruction invocation covered by the corpus.
+pub struct Case {
+    /// The fixture file name, without extension.
+    pub name: &'static str,
+    /// The instruction to execute.
+    pub instruction: Instruction,
+    /// The accounts the instruction is executed with.
+    pub accounts: Vec<(Address, Account)>,
+}
+
+impl Case {
+    /// Firedancer fixtures refer to instruction accounts by their index in
+    /// the compiled message but list the accounts in the order given, so the
+    /// accounts are sorted into message order, program account included.
+    fn new(
+        name: &'static str,
+        instruction: Instruction,
+        mut accounts: Vec<(Address, Account)>,
+    ) -> Self {
+        if !accounts
+            .iter()
+            .any(|(address, _)| *address == instruction.program_id)
+        {
+            accounts.push(keyed_program_account());
+        }
+        let message = Message::new(std::slice::from_ref(&instruction), None);
+        accounts.sort_by_key(|(address, _)| {
+            message
+                .account_keys
+                .iter()
+                .position(|key| key == address)
+                .unwrap_or(usize::MAX)
+        });
+        Self {
+            name,
+            instruction,
+            accounts,
+        }
+    }
+}
+
+/// Fixed addresses, so that regenerated fixtures are identical.
+const FEATURE: Address = Address::new_from_array([7; 32]);
+const OTHER_FEATURE: Address = Address::new_from_array([6; 32]);
+const PAYER: Address = Address::new_from_array([10; 32]);
+const VOTE_ACCOUNT: Address = Address::new_from_array([11; 32]);
+const NODE: Address = Address::new_from_array([12; 32]);
+const AUTHORITY: Address = Address::new_from_array([13; 32]);
+const REFUND_RECIPIENT: Address = Address::new_from_array([14; 32]);
+const BUNDLE_NAME: [u8; 32] = [1; 32];
+
+fn revoke_case(name: &'static str, feature_account: Account) -> Case {
+    Case::new(
+        name,
+        revoke_pending_activation(&FEATURE),
+        revoke_accounts(FEATURE, feature_account),
+    )
+}
+
+fn payer_account() -> Account {
+    Account::new(1_000_000_000, 0, &system_program::id())
+}
+
+fn program_account(data: Vec<u8>) -> Account {
+    let mut account = Account::new(
+        Rent::default().minimum_balance(data.len()),
+        data.len(),
+        &feature::id(),
+    );
+    account.data = data;
+    account
+}
+
+fn config() -> Config {
+    let mut refund_recipients = [Address::default(); MAX_REFUND_RECIPIENTS];
+    refund_recipients[0] = REFUND_RECIPIENT;
+    Config {
+        authority: AUTHORITY,
+        revoke_guard_window: 1_000,
+        tombstone_policy: TombstonePolicy::Close,
+        refund_recipients,
+    }
+}
+
+fn keyed_config_account() -> (Address, Account) {
+    (find_config_address().0, program_account(config().pack()))
+}
+
+fn keyed_initialized_audit_log_account() -> (Address, Account) {
+    let mut data = vec![0; AuditLog::space(4)];
+    data[..AuditLog::LEN].copy_from_slice(&AuditLog::default().pack());
+    (find_audit_log_address().0, program_account(data))
+}
+
+/// The upgradeable loader's program data account for the Feature Gate
+/// program, upgradeable by [`AUTHORITY`].
+fn keyed_program_data_account() -> (Address, Account) {
+    let address =
+        Address::find_program_address(&[feature::id().as_ref()], &bpf_loader_upgradeable::id()).0;
+    let mut data = vec![3, 0, 0, 0];
+    data.extend_from_slice(&42u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(AUTHORITY.as_ref());
+    let mut account = Account::new(1_000_000, data.len(), &bpf_loader_upgradeable::id());
+    account.data = data;
+    (address, account)
+}
+
+/// The cases written in every format.
+///
+/// Only cases whose result both formats can encode are listed, so that the
+/// two corpora always hold the same fixtures. Mollusk's format has no code
+/// for `PrivilegeEscalation` or `ReadonlyLamportChange`, so a read-only
+/// feature account is covered by the program's own tests instead.
+pub fn cases() -> Vec<Case> {
+    [
+        revoke_cases(),
+        signal_support_cases(),
+        stake_guard_cases(),
+        config_cases(),
+        scheduled_activation_cases(),
+        audit_log_cases(),
+        bundle_cases(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// `RevokePendingActivation`, whose fixtures predate the other instructions
+/// and so are not prefixed with the instruction name.
+fn revoke_cases() -> Vec<Case> {
+    let pending = || FeatureAccountBuilder::pending().build();
+    let revoke = || revoke_pending_activation(&FEATURE);
+    let with_data = |data: Vec<u8>| Instruction { data, ..revoke() };
+
+    let mut not_signer = revoke();
+    not_signer.accounts[0].is_signer = false;
+    let mut too_few_accounts = revoke();
+    too_few_accounts.accounts.truncate(2);
+    let mut without_audit_log = revoke();
+    without_audit_log.accounts.truncate(3);
+    let wrong_incinerator = Address::new_from_array([9; 32]);
+    let mut incinerator_mismatch = revoke();
+    incinerator_mismatch.accounts[1].pubkey = wrong_incinerator;
+    let refund_accounts = |recipient: Address| {
+        vec![
+            (FEATURE, pending()),
+            (recipient, Account::default()),
+            keyed_system_program_account(),
+            keyed_audit_log_account(),
+            keyed_config_account(),
+        ]
+    };
+
+    vec![
+        revoke_case("success", pending()),
+        revoke_case(
+            "success_excess_lamports",
+            FeatureAccountBuilder::pending()
+                .lamports(feature_rent().saturating_mul(3))
+                .build(),
+        ),
+        Case::new(
+            "success_without_audit_log",
+            without_audit_log,
+            revoke_accounts(FEATURE, pending())
+                .into_iter()
+                .take(3)
+                .collect(),
+        ),
+        Case::new(
+            "success_recorded_in_audit_log",
+            revoke(),
+            vec![
+                (FEATURE, pending()),
+                keyed_incinerator_account(),
+                keyed_system_program_account(),
+                keyed_initialized_audit_log_account(),
+            ],
+        ),
+        Case::new(
+            "success_refund",
+            revoke_pending_activation_with_refund(&FEATURE, &REFUND_RECIPIENT),
+            refund_accounts(REFUND_RECIPIENT),
+        ),
+        Case::new(
+            "refund_recipient_not_allowed",
+            revoke_pending_activation_with_refund(&FEATURE, &wrong_incinerator),
+            refund_accounts(wrong_incinerator),
+        ),
+        Case::new(
+            "invalid_instruction_data_empty",
+            with_data(Vec::new()),
+            revoke_accounts(FEATURE, pending()),
+        ),
+        Case::new(
+            "invalid_instruction_data_unknown",
+            with_data(vec![u8::MAX]),
+            revoke_accounts(FEATURE, pending()),
+        ),
+        Case::new(
+            "invalid_instruction_data_trailing",
+            with_data(vec![0, 0]),
+            revoke_accounts(FEATURE, pending()),
+        ),
+        Case::new(
+            "not_enough_account_keys",
+            too_few_accounts,
+            vec![(FEATURE, pending()), keyed_incinerator_account()],
+        ),
+        Case::new(
+            "feature_not_signer",
+            not_signer,
+            revoke_accounts(FEATURE, pending()),
+        ),
+        revoke_case(
+            "feature_incorrect_owner",
+            FeatureAccountBuilder::pending()
+                .owner(Address::new_from_array([8; 32]))
+                .build(),
+        ),
+        revoke_case(
+            "feature_data_too_small",
+            FeatureAccountBuilder::pending().data(vec![0; 8]).build(),
+        ),
+        revoke_case(
+            "feature_invalid_data",
+            FeatureAccountBuilder::pending().data(vec![2; 9]).build(),
+        ),
+        revoke_case(
+            "feature_already_activated",
+            FeatureAccountBuilder::active(45).build(),
+        ),
+        Case::new(
+            "incinerator_mismatch",
+            incinerator_mismatch,
+            vec![
+                (FEATURE, pending()),
+                (wrong_incinerator, Account::default()),
+                keyed_system_program_account(),
+                keyed_audit_log_account(),
+            ],
+        ),
+    ]
+}
+
+fn signal_support_cases() -> Vec<Case> {
+    let signal = || signal_support(&FEATURE, &VOTE_ACCOUNT, &NODE, &PAYER, 3, 1, 0);
+    let accounts = |feature_account: Account| {
+        vec![
+            (
+                find_support_signal_address(&FEATURE, &VOTE_ACCOUNT).0,
+                Account::default(),
+            ),
+            (FEATURE, feature_account),
+            (VOTE_ACCOUNT, vote_account(&VOTE_ACCOUNT, &NODE, &NODE)),
+            (NODE, Account::default()),
+            (PAYER, payer_account()),
+            keyed_system_program_account(),
+        ]
+    };
+
+    let mut invalid_vote_authority = signal();
+    invalid_vote_authority.accounts[3].pubkey = AUTHORITY;
+    let mut invalid_vote_authority_accounts = accounts(FeatureAccountBuilder::pending().build());
+    invalid_vote_authority_accounts[3].0 = AUTHORITY;
+
+    vec![
+        Case::new(
+            "signal_support_success",
+            signal(),
+            accounts(FeatureAccountBuilder::pending().build()),
+        ),
+        Case::new(
+            "signal_support_invalid_vote_authority",
+            invalid_vote_authority,
+            invalid_vote_authority_accounts,
+        ),
+        Case::new(
+            "signal_support_feature_already_activated",
+            signal(),
+            accounts(FeatureAccountBuilder::active(45).build()),
+        ),
+    ]
+}
+
+fn stake_guard_cases() -> Vec<Case> {
+    let (stake_guard, _) = find_stake_guard_address(&FEATURE);
+    let guard_account = || {
+        program_account(
+            StakeGuard {
+                feature_id: FEATURE,
+                threshold_bps: 6_667,
+                epoch: 0,
+                supporting_stake: 0,
+            }
+            .pack(),
+        )
+    };
+    let initialize_accounts = || {
+        vec![
+            (stake_guard, Account::default()),
+            (FEATURE, FeatureAccountBuilder::pending().build()),
+            (PAYER, payer_account()),
+            keyed_system_program_account(),
+        ]
+    };
+
+    vec![
+        Case::new(
+            "initialize_stake_guard_success",
+            initialize_stake_guard(&FEATURE, &PAYER, 6_667),
+            initialize_accounts(),
+        ),
+        Case::new(
+            "initialize_stake_guard_invalid_threshold",
+            initialize_stake_guard(&FEATURE, &PAYER, MAX_BASIS_POINTS + 1),
+            initialize_accounts(),
+        ),
+        Case::new(
+            "tally_support_success",
+            tally_support(&FEATURE, &[]),
+            vec![(stake_guard, guard_account())],
+        ),
+        Case::new(
+            "tally_support_uninitialized_stake_guard",
+            tally_support(&FEATURE, &[]),
+            vec![(stake_guard, Account::default())],
+        ),
+        Case::new(
+            "revoke_unsupported_activation_window_closed",
+            revoke_unsupported_activation(&FEATURE),
+            vec![
+                (FEATURE, FeatureAccountBuilder::pending().build()),
+                (stake_guard, guard_account()),
+                keyed_incinerator_account(),
+                keyed_config_account(),
+                keyed_audit_log_account(),
+            ],
+        ),
+    ]
+}
+
+fn config_cases() -> Vec<Case> {
+    let initialize_accounts = || {
+        vec![
+            (find_config_address().0, Account::default()),
+            (AUTHORITY, Account::default()),
+            keyed_program_data_account(),
+            (PAYER, payer_account()),
+            keyed_system_program_account(),
+            keyed_audit_log_account(),
+        ]
+    };
+    let update_accounts = || {
+        vec![
+            keyed_config_account(),
+            (AUTHORITY, Account::default()),
+            keyed_audit_log_account(),
+        ]
+    };
+
+    let mut incorrect_upgrade_authority = initialize_config(&AUTHORITY, &PAYER, config());
+    incorrect_upgrade_authority.accounts[1].pubkey = NODE;
+    let mut incorrect_upgrade_authority_accounts = initialize_accounts();
+    incorrect_upgrade_authority_accounts[1].0 = NODE;
+    let mut incorrect_authority = update_config(&AUTHORITY, config());
+    incorrect_authority.accounts[1].pubkey = NODE;
+    let mut incorrect_authority_accounts = update_accounts();
+    incorrect_authority_accounts[1].0 = NODE;
+    let invalid_config = Config {
+        revoke_guard_window: 0,
+        ..config()
+    };
+
+    vec![
+        Case::new(
+            "initialize_config_success",
+            initialize_config(&AUTHORITY, &PAYER, config()),
+            initialize_accounts(),
+        ),
+        Case::new(
+            "initialize_config_incorrect_upgrade_authority",
+            incorrect_upgrade_authority,
+            incorrect_upgrade_authority_accounts,
+        ),
+        Case::new(
+            "update_config_success",
+            update_config(
+                &AUTHORITY,
+                Config {
+                    tombstone_policy: TombstonePolicy::Tombstone,
+                    ..config()
+                },
+            ),
+            update_accounts(),
+        ),
+        Case::new(
+            "update_config_incorrect_authority",
+            incorrect_authority,
+            incorrect_authority_accounts,
+        ),
+        Case::new(
+            "update_config_invalid_config",
+            update_config(&AUTHORITY, invalid_config),
+            update_accounts(),
+        ),
+    ]
+}
+
+fn scheduled_activation_cases() -> Vec<Case> {
+    let schedule_accounts = |feature_account: Account| {
+        vec![
+            (FEATURE, feature_account),
+            (PAYER, payer_account()),
+            keyed_system_program_account(),
+            keyed_audit_log_account(),
+        ]
+    };
+    let scheduled = |not_before_epoch| {
+        FeatureAccountBuilder::pending()
+            .data(ScheduledActivation { not_before_epoch }.pack())
+            .build()
+    };
+
+    vec![
+        Case::new(
+            "schedule_activation_success",
+            schedule_activation(&FEATURE, &PAYER, 5),
+            schedule_accounts(Account::default()),
+        ),
+        Case::new(
+            "schedule_activation_already_queued",
+            schedule_activation(&FEATURE, &PAYER, 5),
+            schedule_accounts(FeatureAccountBuilder::pending().build()),
+        ),
+        Case::new(
+            "release_scheduled_activation_success",
+            release_scheduled_activation(&FEATURE),
+            vec![(FEATURE, scheduled(0))],
+        ),
+        Case::new(
+            "release_scheduled_activation_not_due",
+            release_scheduled_activation(&FEATURE),
+            vec![(FEATURE, scheduled(5))],
+        ),
+        revoke_case("revoke_scheduled_activation_success", scheduled(5)),
+    ]
+}
+
+fn audit_log_cases() -> Vec<Case> {
+    let accounts = |audit_log: (Address, Account)| {
+        vec![
+            audit_log,
+            (PAYER, payer_account()),
+            keyed_system_program_account(),
+        ]
+    };
+
+    vec![
+        Case::new(
+            "initialize_audit_log_success",
+            initialize_audit_log(&PAYER, 4),
+            accounts(keyed_audit_log_account()),
+        ),
+        Case::new(
+            "initialize_audit_log_invalid_capacity",
+            initialize_audit_log(&PAYER, 0),
+            accounts(keyed_audit_log_account()),
+        ),
+        Case::new(
+            "initialize_audit_log_already_initialized",
+            initialize_audit_log(&PAYER, 4),
+            accounts(keyed_initialized_audit_log_account()),
+        ),
+        Case::new(
+            "extend_audit_log_success",
+            extend_audit_log(&PAYER, 4),
+            accounts(keyed_initialized_audit_log_account()),
+        ),
+        Case::new(
+            "extend_audit_log_uninitialized",
+            extend_audit_log(&PAYER, 4),
+            accounts(keyed_audit_log_account()),
+        ),
+    ]
+}
+
+fn bundle_cases() -> Vec<Case> {
+    let members = [FEATURE, OTHER_FEATURE];
+    let (bundle_address, _) = find_bundle_address(&AUTHORITY, &BUNDLE_NAME);
+    let bundle = Bundle::new(AUTHORITY, BUNDLE_NAME, &members).unwrap();
+    let with_members = |mut accounts: Vec<(Address, Account)>, member_accounts: [Account; 2]| {
+        accounts.extend(members.into_iter().zip(member_accounts));
+        accounts
+    };
+    let queue_bundle_accounts = |member_accounts| {
+        with_members(
+            vec![
+                (bundle_address, program_account(bundle.pack())),
+                (PAYER, payer_account()),
+                keyed_system_program_account(),
+                keyed_audit_log_account(),
+            ],
+            member_accounts,
+        )
+    };
+    let revoke_bundle_accounts = |member_accounts| {
+        with_members(
+            vec![
+                (bundle_address, program_account(bundle.pack())),
+                keyed_incinerator_account(),
+                keyed_system_program_account(),
+                keyed_audit_log_account(),
+            ],
+            member_accounts,
+        )
+    };
+    let pending = || FeatureAccountBuilder::pending().build();
+
+    vec![
+        Case::new(
+            "initialize_bundle_success",
+            initialize_bundle(&AUTHORITY, &PAYER, &BUNDLE_NAME, &members),
+            with_members(
+                vec![
+                    (bundle_address, Account::default()),
+                    (AUTHORITY, Account::default()),
+                    (PAYER, payer_account()),
+                    keyed_system_program_account(),
+                ],
+                [Account::default(), Account::default()],
+            ),
+        ),
+        Case::new(
+            "initialize_bundle_duplicate_members",
+            initialize_bundle(&AUTHORITY, &PAYER, &BUNDLE_NAME, &[FEATURE, FEATURE]),
+            vec![
+                (bundle_address, Account::default()),
+                (AUTHORITY, Account::default()),
+                (PAYER, payer_account()),
+                keyed_system_program_account(),
+                (FEATURE, Account::default()),
+            ],
+        ),
+        Case::new(
+            "queue_bundle_success",
+            queue_bundle(&bundle_address, &PAYER, &members),
+            queue_bundle_accounts([Account::default(), Account::default()]),
+        ),
+        Case::new(
+            "queue_bundle_member_pending",
+            queue_bundle(&bundle_address, &PAYER, &members),
+            queue_bundle_accounts([Account::default(), pending()]),
+        ),
+        Case::new(
+            "revoke_bundle_success",
+            revoke_bundle(&bundle_address, &members),
+            revoke_bundle_accounts([pending(), pending()]),
+        ),
+        Case::new(
+            "revoke_bundle_member_active",
+            revoke_bundle(&bundle_address, &members),
+            revoke_bundle_accounts([pending(), FeatureAccountBuilder::active(45).build()]),
+        ),
+    ]
+}
+
+/// The directory holding the fixtures in `format`.
+pub fn fixtures_dir(format: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join(format)
+}
+
+/// Writes the fixture of the case `name` in `format`.
+pub fn write_fixture(format: &str, name: &str, fixture: &impl SerializableFixture) {
+    let dir = fixtures_dir(format);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join(format!("{name}.fix")),
+        SerializableFixture::encode(fixture),
+    )
+    .unwrap();
+}
+
+/// Converts a fixture to its protobuf form. The feature set is sorted, since
+/// it is collected from a hash map and would otherwise make regenerated
+/// fixtures differ.
+pub fn mollusk_proto(fixture: Fixture) -> mollusk_proto::InstrFixture {
+    let mut proto = IntoSerializableFixture::into(fixture);
+    if let Some(feature_set) = proto
+        .input
+        .as_mut()
+        .and_then(|input| input.feature_set.as_mut())
+    {
+        feature_set.features.sort_unstable();
+    }
+    proto
+}
+
+/// Like [`mollusk_proto`], for Firedancer fixtures.
+pub fn firedancer_proto(fixture: FiredancerFixture) -> firedancer_proto::InstrFixture {
+    let mut proto = IntoSerializableFixture::into(fixture);
+    if let Some(feature_set) = proto
+        .input
+        .as_mut()
+        .and_then(|input| input.epoch_context.as_mut())
+        .and_then(|epoch_context| epoch_context.features.as_mut())
+    {
+        feature_set.features.sort_unstable();
+    }
+    proto
+}
+
+/// Lists the fixture files in `format`, checking that every case has one.
+///
+/// # Panics
+///
+/// Panics if the fixtures do not match [`cases`].
+pub fn fixture_files(format: &str) -> Vec<PathBuf> {
+    let mut files = fs::read_dir(fixtures_dir(format))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "fix"))
+        .collect::<Vec<_>>();
+    files.sort();
+
+    let names = files
+        .iter()
+        .map(|path| path.file_stem().unwrap().to_string_lossy().into_owned())
+        .collect::<BTreeSet<_>>();
+    let expected = cases()
+        .iter()
+        .map(|case| case.name.to_string())
+        .collect::<BTreeSet<_>>();
+    assert_eq!(names, expected, "{format} fixtures are out of date");
+    files
+}
@@ -0,0 +1,81 @@
+use {
+    mollusk_svm::{
+        fuzz::{firedancer, mollusk as mollusk_fixture},
+        result::{Compare, InstructionResult},
+        Mollusk,
+    },
+    mollusk_svm_fuzz_fixture::Fixture,
+    mollusk_svm_fuzz_fixture_firedancer::Fixture as FiredancerFixture,
+    solana_feature_gate_program_conformance::{
+        cases, firedancer_proto, fixture_files, fixtures_dir, mollusk_proto, write_fixture, FORMATS,
+    },
+    solana_feature_gate_test_utils::mollusk,
+    std::{fs, path::Path},
+};
+
+#[test]
+#[ignore = "regenerates the fixture corpus"]
+fn generate_fixtures() {
+    let mollusk = mollusk();
+    for format in FORMATS {
+        let _ = fs::remove_dir_all(fixtures_dir(format));
+    }
+    for case in cases() {
+        let result = mollusk.process_instruction(&case.instruction, &case.accounts);
+        write_fixture(
+            "mollusk",
+            case.name,
+            &mollusk_proto(mollusk_fixture::build_fixture_from_mollusk_test(
+                &mollusk,
+                &case.instruction,
+                &case.accounts,
+                &result,
+            )),
+        );
+        write_fixture(
+            "firedancer",
+            case.name,
+            &firedancer_proto(firedancer::build_fixture_from_mollusk_test(
+                &mollusk,
+                &case.instruction,
+                &case.accounts,
+                &result,
+            )),
+        );
+    }
+}
+
+fn check(path: &Path, mollusk: &Mollusk, expected: &InstructionResult, actual: &InstructionResult) {
+    assert!(
+        expected.compare_with_config(actual, &Compare::everything_but_cus(), &mollusk.config),
+        "{} does not match the built program",
+        path.display()
+    );
+}
+
+#[test]
+fn replay_mollusk_fixtures() {
+    let mut mollusk = mollusk();
+    for path in fixture_files("mollusk") {
+        let fixture = Fixture::load_from_blob_file(path.to_str().unwrap());
+        let actual = mollusk.process_fixture(&fixture);
+        check(
+            &path,
+            &mollusk,
+            &InstructionResult::from(&fixture.output),
+            &actual,
+        );
+    }
+}
+
+#[test]
+fn replay_firedancer_fixtures() {
+    let mut mollusk = mollusk();
+    for path in fixture_files("firedancer") {
+        let fixture = FiredancerFixture::load_from_blob_file(path.to_str().unwrap());
+        let (context, expected) = firedancer::load_firedancer_fixture(&fixture);
+        let actual = mollusk.process_firedancer_fixture(&fixture);
+        assert_eq!(context.instruction.data, fixture.input.instruction_data);
+        check(&path, &mollusk, &expected, &actual);
+    }
+}
@@ -0,0 +1,91 @@
+//! Runs the built program over arbitrary accounts and instruction data in
+//! Mollusk, checking the invariants in
+//! [`solana_feature_gate_test_utils::invariants`] after every execution.
+
+#![no_main]
+
+use {
+    arbitrary::Arbitrary,
+    libfuzzer_sys::fuzz_target,
+    mollusk_svm::Mollusk,
+    solana_account::Account,
+    solana_address::Address,
+    solana_feature_gate_test_utils::{
+        invariants::check_invariants, keyed_system_program_account, mollusk,
+    },
+    solana_instruction::{AccountMeta, Instruction},
+    solana_sdk_ids::{feature, incinerator, system_program},
+};
+
+#[derive(Arbitrary, Debug)]
+enum Owner {
+    FeatureGate,
+    System,
+    Other([u8; 32]),
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzAccount {
+    /// Whether the account takes the address its position expects, rather
+    /// than `address`.
+    well_known: bool,
+    address: [u8; 32],
+    owner: Owner,
+    data: Vec<u8>,
+    lamports: u64,
+    is_signer: bool,
+    is_writable: bool,
+}
+
+#[derive(Arbitrary, Debug)]
+struct FuzzInput {
+    accounts: Vec<FuzzAccount>,
+    data: Vec<u8>,
+}
+
+/// The addresses `RevokePendingActivation` expects at each position.
+const WELL_KNOWN: [Option<Address>; 3] = [None, Some(incinerator::ID), Some(system_program::ID)];
+
+thread_local! {
+    static MOLLUSK: Mollusk = mollusk();
+}
+
+fuzz_target!(|input: FuzzInput| {
+    let mut metas = Vec::new();
+    let mut accounts: Vec<(Address, Account)> = Vec::new();
+    for (index, fuzz_account) in input.accounts.into_iter().take(4).enumerate() {
+        let address = match WELL_KNOWN.get(index).copied().flatten() {
+            Some(address) if fuzz_account.well_known => address,
+            _ => Address::new_from_array(fuzz_account.address),
+        };
+        if accounts.iter().any(|(existing, _)| *existing == address) {
+            continue;
+        }
+        let account = if address == system_program::ID {
+            keyed_system_program_account().1
+        } else {
+            Account {
+                lamports: fuzz_account.lamports,
+                data: fuzz_account.data,
+                owner: match fuzz_account.owner {
+                    Owner::FeatureGate => feature::ID,
+                    Owner::System => system_program::ID,
+                    Owner::Other(owner) => Address::new_from_array(owner),
+                },
+                ..Account::default()
+            }
+        };
+        metas.push(AccountMeta {
+            pubkey: address,
+            is_signer: fuzz_account.is_signer,
+            is_writable: fuzz_account.is_writable,
+        });
+        accounts.push((address, account));
+    }
+
+    let instruction = Instruction::new_with_bytes(feature::ID, &input.data, metas);
+    let result = MOLLUSK.with(|mollusk| mollusk.process_instruction(&instruction, &accounts));
+    if let Err(violation) = check_invariants(&instruction, &accounts, &result) {
+        panic!("{violation}: {:?}", result.program_result);
+    }
+});
@@ -0,0 +1,16 @@
+//! Every byte buffer either unpacks to an instruction that packs back to the
+//! same bytes, or is rejected as invalid instruction data.
+
+#![no_main]
+
+use {
+    libfuzzer_sys::fuzz_target, solana_feature_gate_program::instruction::FeatureGateInstruction,
+    solana_program_error::ProgramError,
+};
+
+fuzz_target!(|data: &[u8]| {
+    match FeatureGateInstruction::unpack(data) {
+        Ok(instruction) => assert_eq!(instruction.pack(), data),
+        Err(error) => assert_eq!(error, ProgramError::InvalidInstructionData),
+    }
+});
@@ -0,0 +1,64 @@
+//! Program error types.
+
+use {
+    core::fmt,
+    num_enum::TryFromPrimitive,
+    solana_program_error::{ProgramError, ToStr},
+};
+
+/// Program error types.
+#[derive(Clone, Debug, Eq, PartialEq, TryFromPrimitive)]
+#[repr(u32)]
+pub enum FeatureGateError {
+    /// Feature already activated
+    FeatureAlreadyActivated,
+    /// Signer is not an authority of the vote account
+    InvalidVoteAuthority,
+    /// Feature has sufficient supporting stake
+    FeatureSufficientlySupported,
+    /// Stake guard revoke window is not open
+    RevokeWindowClosed,
+    /// Recipient is not allowed to receive refunds
+    InvalidRefundRecipient,
+    /// Scheduled activation cannot be released yet
+    ActivationNotDue,
+    /// Account does not match the bundle member
+    InvalidBundleMember,
+    /// Config is invalid
+    InvalidConfig,
+}
+
+impl ToStr for FeatureGateError {
+    fn to_str(&self) -> &'static str {
+        match self {
+            FeatureGateError::FeatureAlreadyActivated => "Feature already activated",
+            FeatureGateError::InvalidVoteAuthority => {
+                "Signer is not an authority of the vote account"
+            }
+            FeatureGateError::FeatureSufficientlySupported => {
+                "Feature has sufficient supporting stake"
+            }
+            FeatureGateError::RevokeWindowClosed => "Stake guard revoke window is not open",
+            FeatureGateError::InvalidRefundRecipient => {
+                "Recipient is not allowed to receive refunds"
+            }
+            FeatureGateError::ActivationNotDue => "Scheduled activation cannot be released yet",
+            FeatureGateError::InvalidBundleMember => "Account does not match the bundle member",
+            FeatureGateError::InvalidConfig => "Config is invalid",
+        }
+    }
+}
+
+impl From<FeatureGateError> for ProgramError {
+    fn from(e: FeatureGateError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
+impl fmt::Display for FeatureGateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str())
+    }
+}
+
+impl core::error::Error for FeatureGateError {}
@@ -0,0 +1,871 @@
+//! Program instructions
+
+use {
+    crate::state::{
+        find_audit_log_address, find_bundle_address, find_config_address, find_stake_guard_address,
+        find_support_signal_address, Config,
+    },
+    alloc::{vec, vec::Vec},
+    solana_address::Address,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_program_error::ProgramError,
+    solana_sdk_ids::{bpf_loader_upgradeable, incinerator, system_program},
+};
+
+/// Feature Gate program instructions
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub enum FeatureGateInstruction {
+    /// Revoke a pending feature activation.
+    ///
+    /// This instruction will burn any lamports in the feature account.
+    ///
+    /// A "pending" feature activation is a feature account that has been
+    /// allocated and assigned, but hasn't yet been updated by the runtime
+    /// with an `activation_slot`.
+    ///
+    /// Features that _have_ been activated by the runtime cannot be revoked.
+    /// Scheduled activations can be revoked like pending ones.
+    ///
+    /// The feature ID may be a PDA, in which case the program it is derived
+    /// from signs for it with `invoke_signed`.
+    ///
+    /// The lamports may instead be refunded to one of the refund recipients
+    /// allowed by the program config, which must then be provided.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[w+s]`    Feature account
+    ///   1. `[w]`      Incinerator, or an allowed refund recipient
+    ///   2. `[ ]`      System program
+    ///   3. `[w]`      (Optional) Audit log account
+    ///   4. `[ ]`      (Optional) Program config account
+    ///
+    /// The audit log and config are identified by their addresses, so they
+    /// may be passed in either order. The program ID may be passed in place
+    /// of an omitted one; any other address is rejected. An entry is only
+    /// recorded when the audit log is passed.
+    RevokePendingActivation,
+    /// Signal that a validator runs software supporting a pending feature.
+    ///
+    /// Creates the support signal account derived from the feature and vote
+    /// account, or records the new software version and slot in an existing
+    /// one. Either the vote account's node identity or its authorized
+    /// withdrawer may signal on its behalf.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[w]`      Support signal account
+    ///   1. `[ ]`      Feature account
+    ///   2. `[ ]`      Vote account
+    ///   3. `[s]`      Vote account node identity or authorized withdrawer
+    ///   4. `[w+s]`    Payer
+    ///   5. `[ ]`      System program
+    SignalSupport {
+        /// Major version of the validator software.
+        major: u16,
+        /// Minor version of the validator software.
+        minor: u16,
+        /// Patch version of the validator software.
+        patch: u16,
+    },
+    /// Guard a pending feature with a supporting stake threshold.
+    ///
+    /// Creates the stake guard account derived from the feature. Once
+    /// guarded, anyone may revoke the feature with
+    /// `RevokeUnsupportedActivation` if the stake signaling support for it is
+    /// below `threshold_bps` of the total stake near the end of an epoch.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[w]`      Stake guard account
+    ///   1. `[s]`      Feature account
+    ///   2. `[w+s]`    Payer
+    ///   3. `[ ]`      System program
+    InitializeStakeGuard {
+        /// The share of the total stake that must support the feature, in
+        /// basis points.
+        threshold_bps: u16,
+    },
+    /// Tally the stake signaling support for a guarded feature.
+    ///
+    /// Adds the current epoch stake of the vote accounts behind the given
+    /// support signals, which must be sorted by strictly ascending vote
+    /// account, to the guard's tally for the current epoch. Each signal
+    /// records the epoch it was tallied in and is skipped if it was already
+    /// counted this epoch, so anyone may crank this instruction in as many
+    /// transactions as the supporters need.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[w]`      Stake guard account
+    ///   1. ..`1+N`    `[w]` Support signal accounts
+    TallySupport,
+    /// Revoke a guarded pending feature that lacks supporting stake.
+    ///
+    /// Anyone may revoke the feature during the last slots of an epoch, as
+    /// set by the program config's revoke guard window, if the stake tallied
+    /// for it in that epoch is below the guard's threshold. The stake guard
+    /// account is closed and, depending on the config's tombstone policy, the
+    /// feature account is either closed or replaced with a tombstone. Any
+    /// lamports not needed for the tombstone are burned.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[w]`      Feature account
+    ///   1. `[w]`      Stake guard account
+    ///   2. `[w]`      Incinerator
+    ///   3. `[ ]`      Program config account, which may be uninitialized
+    ///   4. `[w]`      Audit log account
+    RevokeUnsupportedActivation,
+    /// Initialize the program config.
+    ///
+    /// Creates the singleton config account. Only the program's upgrade
+    /// authority may initialize it; the config's own authority then governs
+    /// updates.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[w]`      Program config account
+    ///   1. `[s]`      Program upgrade authority
+    ///   2. `[ ]`      Program data account
+    ///   3. `[w+s]`    Payer
+    ///   4. `[ ]`      System program
+    ///   5. `[w]`      Audit log account
+    InitializeConfig(Config),
+    /// Replace the program config.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[w]`      Program config account
+    ///   1. `[s]`      Config authority
+    ///   2. `[w]`      Audit log account
+    UpdateConfig(Config),
+    /// Queue a feature to activate no earlier than a given epoch.
+    ///
+    /// Creates the feature account, funded with its rent-exempt balance by
+    /// the payer, holding a scheduled activation that the runtime ignores.
+    /// Once the epoch before `not_before_epoch` begins, anyone may release it
+    /// with `ReleaseScheduledActivation`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[w+s]`    Feature account
+    ///   1. `[w+s]`    Payer
+    ///   2. `[ ]`      System program
+    ///   3. `[w]`      Audit log account
+    ScheduleActivation {
+        /// The earliest epoch in which the feature may activate.
+        not_before_epoch: u64,
+    },
+    /// Release a scheduled activation into a pending feature.
+    ///
+    /// Anyone may release a scheduled activation from the epoch before its
+    /// target onwards. The runtime then activates the feature at the next
+    /// epoch boundary, so it never activates before the target epoch.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[w]`      Feature account
+    ReleaseScheduledActivation,
+    /// Create the audit log.
+    ///
+    /// Creates the singleton audit log account with room for `capacity`
+    /// entries. Instructions that queue or revoke features or change the
+    /// program config take the audit log account and, once it exists,
+    /// record an entry in it.
+    ///
+    /// When the log is full, config changes overwrite the oldest entry.
+    /// Queueing and revoking features needs no authority, so those grow the
+    /// log by one entry instead, at the expense of the payer or of the
+    /// account being closed, and only wrap once the account has reached the
+    /// maximum account size.
+    ///
+    /// Features queued by creating the feature account directly through the
+    /// System program, as the runtime's own activation flow does, never
+    /// reach this program and so are not recorded.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[w]`      Audit log account
+    ///   1. `[w+s]`    Payer
+    ///   2. `[ ]`      System program
+    InitializeAuditLog {
+        /// The number of entries the log holds before wrapping.
+        capacity: u32,
+    },
+    /// Grow the audit log.
+    ///
+    /// Adds room for `additional_entries` entries, funded by the payer. Only
+    /// the oldest entries are ever overwritten, so none are lost by growing.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[w]`      Audit log account
+    ///   1. `[w+s]`    Payer
+    ///   2. `[ ]`      System program
+    ExtendAuditLog {
+        /// The number of entries to add room for.
+        additional_entries: u32,
+    },
+    /// Create a bundle of features that are queued and revoked together.
+    ///
+    /// Creates the bundle account derived from the authority and `name`,
+    /// listing the given feature accounts as its members in order. A bundle
+    /// holds between one and eight distinct features.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[w]`      Bundle account
+    ///   1. `[s]`      Bundle authority
+    ///   2. `[w+s]`    Payer
+    ///   3. `[ ]`      System program
+    ///   4. ..`4+N`    `[ ]` Member feature accounts
+    InitializeBundle {
+        /// The bundle name, as UTF-8 padded with zeros.
+        name: [u8; 32],
+    },
+    /// Queue every feature in a bundle for activation.
+    ///
+    /// Creates each member feature account as a pending feature, funded with
+    /// its rent-exempt balance by the payer, so the runtime activates all
+    /// members at the same epoch boundary. Fails if any member is already
+    /// queued or active.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[ ]`      Bundle account
+    ///   1. `[w+s]`    Payer
+    ///   2. `[ ]`      System program
+    ///   3. `[w]`      Audit log account
+    ///   4. ..`4+N`    `[w+s]` Member feature accounts, in bundle order
+    QueueBundle,
+    /// Revoke the pending activation of every feature in a bundle.
+    ///
+    /// Revokes each member like `RevokePendingActivation`, burning the
+    /// lamports in its feature account. Fails if any member is already
+    /// active.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[ ]`      Bundle account
+    ///   1. `[w]`      Incinerator
+    ///   2. `[ ]`      System program
+    ///   3. `[w]`      Audit log account
+    ///   4. ..`4+N`    `[w+s]` Member feature accounts, in bundle order
+    RevokeBundle,
+}
+
+impl FeatureGateInstruction {
+    /// Unpacks a byte buffer into a
+    /// [`FeatureGateInstruction`](enum.FeatureGateInstruction.html).
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        match input.split_first() {
+            Some((&0, [])) => Ok(Self::RevokePendingActivation),
+            Some((&1, &[major_0, major_1, minor_0, minor_1, patch_0, patch_1])) => {
+                Ok(Self::SignalSupport {
+                    major: u16::from_le_bytes([major_0, major_1]),
+                    minor: u16::from_le_bytes([minor_0, minor_1]),
+                    patch: u16::from_le_bytes([patch_0, patch_1]),
+                })
+            }
+            Some((&2, &[threshold_0, threshold_1])) => Ok(Self::InitializeStakeGuard {
+                threshold_bps: u16::from_le_bytes([threshold_0, threshold_1]),
+            }),
+            Some((&3, [])) => Ok(Self::TallySupport),
+            Some((&4, [])) => Ok(Self::RevokeUnsupportedActivation),
+            Some((&5, config)) => Config::unpack_fields(config)
+                .map(Self::InitializeConfig)
+                .map_err(|_| ProgramError::InvalidInstructionData),
+            Some((&6, config)) => Config::unpack_fields(config)
+                .map(Self::UpdateConfig)
+                .map_err(|_| ProgramError::InvalidInstructionData),
+            Some((&7, not_before_epoch)) => Ok(Self::ScheduleActivation {
+                not_before_epoch: u64::from_le_bytes(
+                    not_before_epoch
+                        .try_into()
+                        .map_err(|_| ProgramError::InvalidInstructionData)?,
+                ),
+            }),
+            Some((&8, [])) => Ok(Self::ReleaseScheduledActivation),
+            Some((&9, &[capacity_0, capacity_1, capacity_2, capacity_3])) => {
+                Ok(Self::InitializeAuditLog {
+                    capacity: u32::from_le_bytes([capacity_0, capacity_1, capacity_2, capacity_3]),
+                })
+            }
+            Some((&10, &[additional_0, additional_1, additional_2, additional_3])) => {
+                Ok(Self::ExtendAuditLog {
+                    additional_entries: u32::from_le_bytes([
+                        additional_0,
+                        additional_1,
+                        additional_2,
+                        additional_3,
+                    ]),
+                })
+            }
+            Some((&11, name)) => Ok(Self::InitializeBundle {
+                name: name
+                    .try_into()
+                    .map_err(|_| ProgramError::InvalidInstructionData)?,
+            }),
+            Some((&12, [])) => Ok(Self::QueueBundle),
+            Some((&13, [])) => Ok(Self::RevokeBundle),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    /// Packs a [`FeatureGateInstruction`](enum.FeatureGateInstruction.html)
+    /// into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        match self {
+            Self::RevokePendingActivation => vec![0],
+            Self::SignalSupport {
+                major,
+                minor,
+                patch,
+            } => {
+                let mut buf = vec![1];
+                buf.extend_from_slice(&major.to_le_bytes());
+                buf.extend_from_slice(&minor.to_le_bytes());
+                buf.extend_from_slice(&patch.to_le_bytes());
+                buf
+            }
+            Self::InitializeStakeGuard { threshold_bps } => {
+                let mut buf = vec![2];
+                buf.extend_from_slice(&threshold_bps.to_le_bytes());
+                buf
+            }
+            Self::TallySupport => vec![3],
+            Self::RevokeUnsupportedActivation => vec![4],
+            Self::InitializeConfig(config) => {
+                let mut buf = vec![5];
+                config.pack_fields(&mut buf);
+                buf
+            }
+            Self::UpdateConfig(config) => {
+                let mut buf = vec![6];
+                config.pack_fields(&mut buf);
+                buf
+            }
+            Self::ScheduleActivation { not_before_epoch } => {
+                let mut buf = vec![7];
+                buf.extend_from_slice(&not_before_epoch.to_le_bytes());
+                buf
+            }
+            Self::ReleaseScheduledActivation => vec![8],
+            Self::InitializeAuditLog { capacity } => {
+                let mut buf = vec![9];
+                buf.extend_from_slice(&capacity.to_le_bytes());
+                buf
+            }
+            Self::ExtendAuditLog { additional_entries } => {
+                let mut buf = vec![10];
+                buf.extend_from_slice(&additional_entries.to_le_bytes());
+                buf
+            }
+            Self::InitializeBundle { name } => [&[11][..], name].concat(),
+            Self::QueueBundle => vec![12],
+            Self::RevokeBundle => vec![13],
+        }
+    }
+}
+
+/// Creates a `RevokePendingActivation` instruction.
+pub fn revoke_pending_activation(feature_id: &Address) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*feature_id, true),
+        AccountMeta::new(incinerator::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(find_audit_log_address().0, false),
+    ];
+
+    let data = FeatureGateInstruction::RevokePendingActivation.pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates a `RevokePendingActivation` instruction that refunds the feature
+/// account's lamports to `recipient`, which the program config must allow.
+pub fn revoke_pending_activation_with_refund(
+    feature_id: &Address,
+    recipient: &Address,
+) -> Instruction {
+    let (config, _) = find_config_address();
+    let accounts = vec![
+        AccountMeta::new(*feature_id, true),
+        AccountMeta::new(*recipient, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(find_audit_log_address().0, false),
+        AccountMeta::new_readonly(config, false),
+    ];
+
+    let data = FeatureGateInstruction::RevokePendingActivation.pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates a `SignalSupport` instruction.
+pub fn signal_support(
+    feature_id: &Address,
+    vote_account: &Address,
+    authority: &Address,
+    payer: &Address,
+    major: u16,
+    minor: u16,
+    patch: u16,
+) -> Instruction {
+    let (support_signal, _) = find_support_signal_address(feature_id, vote_account);
+    let accounts = vec![
+        AccountMeta::new(support_signal, false),
+        AccountMeta::new_readonly(*feature_id, false),
+        AccountMeta::new_readonly(*vote_account, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    let data = FeatureGateInstruction::SignalSupport {
+        major,
+        minor,
+        patch,
+    }
+    .pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates an `InitializeStakeGuard` instruction.
+pub fn initialize_stake_guard(
+    feature_id: &Address,
+    payer: &Address,
+    threshold_bps: u16,
+) -> Instruction {
+    let (stake_guard, _) = find_stake_guard_address(feature_id);
+    let accounts = vec![
+        AccountMeta::new(stake_guard, false),
+        AccountMeta::new_readonly(*feature_id, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    let data = FeatureGateInstruction::InitializeStakeGuard { threshold_bps }.pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates a `TallySupport` instruction counting the support signals of
+/// `vote_accounts`, which are sorted as the program requires.
+pub fn tally_support(feature_id: &Address, vote_accounts: &[Address]) -> Instruction {
+    let (stake_guard, _) = find_stake_guard_address(feature_id);
+    let mut vote_accounts = vote_accounts.to_vec();
+    vote_accounts.sort();
+    vote_accounts.dedup();
+
+    let accounts = core::iter::once(AccountMeta::new(stake_guard, false))
+        .chain(vote_accounts.iter().map(|vote_account| {
+            AccountMeta::new(
+                find_support_signal_address(feature_id, vote_account).0,
+                false,
+            )
+        }))
+        .collect();
+
+    let data = FeatureGateInstruction::TallySupport.pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates a `RevokeUnsupportedActivation` instruction.
+pub fn revoke_unsupported_activation(feature_id: &Address) -> Instruction {
+    let (stake_guard, _) = find_stake_guard_address(feature_id);
+    let accounts = vec![
+        AccountMeta::new(*feature_id, false),
+        AccountMeta::new(stake_guard, false),
+        AccountMeta::new(incinerator::id(), false),
+        AccountMeta::new_readonly(find_config_address().0, false),
+        AccountMeta::new(find_audit_log_address().0, false),
+    ];
+
+    let data = FeatureGateInstruction::RevokeUnsupportedActivation.pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates an `InitializeConfig` instruction.
+pub fn initialize_config(
+    upgrade_authority: &Address,
+    payer: &Address,
+    config: Config,
+) -> Instruction {
+    let (program_data, _) =
+        Address::find_program_address(&[crate::id().as_ref()], &bpf_loader_upgradeable::id());
+    let accounts = vec![
+        AccountMeta::new(find_config_address().0, false),
+        AccountMeta::new_readonly(*upgrade_authority, true),
+        AccountMeta::new_readonly(program_data, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(find_audit_log_address().0, false),
+    ];
+
+    let data = FeatureGateInstruction::InitializeConfig(config).pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates an `UpdateConfig` instruction.
+pub fn update_config(authority: &Address, config: Config) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(find_config_address().0, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(find_audit_log_address().0, false),
+    ];
+
+    let data = FeatureGateInstruction::UpdateConfig(config).pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates a `ScheduleActivation` instruction.
+pub fn schedule_activation(
+    feature_id: &Address,
+    payer: &Address,
+    not_before_epoch: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*feature_id, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(find_audit_log_address().0, false),
+    ];
+
+    let data = FeatureGateInstruction::ScheduleActivation { not_before_epoch }.pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates a `ReleaseScheduledActivation` instruction.
+pub fn release_scheduled_activation(feature_id: &Address) -> Instruction {
+    let accounts = vec![AccountMeta::new(*feature_id, false)];
+
+    let data = FeatureGateInstruction::ReleaseScheduledActivation.pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates an `InitializeAuditLog` instruction.
+pub fn initialize_audit_log(payer: &Address, capacity: u32) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(find_audit_log_address().0, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    let data = FeatureGateInstruction::InitializeAuditLog { capacity }.pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates an `ExtendAuditLog` instruction.
+pub fn extend_audit_log(payer: &Address, additional_entries: u32) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(find_audit_log_address().0, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+
+    let data = FeatureGateInstruction::ExtendAuditLog { additional_entries }.pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates an `InitializeBundle` instruction.
+pub fn initialize_bundle(
+    authority: &Address,
+    payer: &Address,
+    name: &[u8; 32],
+    members: &[Address],
+) -> Instruction {
+    let (bundle, _) = find_bundle_address(authority, name);
+    let accounts = [
+        AccountMeta::new(bundle, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ]
+    .into_iter()
+    .chain(
+        members
+            .iter()
+            .map(|member| AccountMeta::new_readonly(*member, false)),
+    )
+    .collect();
+
+    let data = FeatureGateInstruction::InitializeBundle { name: *name }.pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates a `QueueBundle` instruction. `members` must be the bundle's
+/// members in order.
+pub fn queue_bundle(bundle: &Address, payer: &Address, members: &[Address]) -> Instruction {
+    let accounts = [
+        AccountMeta::new_readonly(*bundle, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(find_audit_log_address().0, false),
+    ]
+    .into_iter()
+    .chain(members.iter().map(|member| AccountMeta::new(*member, true)))
+    .collect();
+
+    let data = FeatureGateInstruction::QueueBundle.pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+/// Creates a `RevokeBundle` instruction. `members` must be the bundle's
+/// members in order.
+pub fn revoke_bundle(bundle: &Address, members: &[Address]) -> Instruction {
+    let accounts = [
+        AccountMeta::new_readonly(*bundle, false),
+        AccountMeta::new(incinerator::id(), false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(find_audit_log_address().0, false),
+    ]
+    .into_iter()
+    .chain(members.iter().map(|member| AccountMeta::new(*member, true)))
+    .collect();
+
+    let data = FeatureGateInstruction::RevokeBundle.pack();
+
+    Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::state::{TombstonePolicy, MAX_REFUND_RECIPIENTS},
+    };
+
+    fn test_pack_unpack(instruction: &FeatureGateInstruction) {
+        let packed = instruction.pack();
+        let unpacked = FeatureGateInstruction::unpack(&packed).unwrap();
+        assert_eq!(instruction, &unpacked);
+
+        #[cfg(feature = "borsh")]
+        assert_eq!(borsh::to_vec(instruction).unwrap(), packed);
+    }
+
+    #[test]
+    fn test_pack_unpack_revoke_pending_activation() {
+        test_pack_unpack(&FeatureGateInstruction::RevokePendingActivation);
+    }
+
+    #[test]
+    fn test_pack_unpack_signal_support() {
+        test_pack_unpack(&FeatureGateInstruction::SignalSupport {
+            major: 3,
+            minor: 1,
+            patch: u16::MAX,
+        });
+    }
+
+    #[test]
+    fn test_pack_unpack_initialize_stake_guard() {
+        test_pack_unpack(&FeatureGateInstruction::InitializeStakeGuard {
+            threshold_bps: 6_667,
+        });
+    }
+
+    #[test]
+    fn test_pack_unpack_tally_support() {
+        test_pack_unpack(&FeatureGateInstruction::TallySupport);
+    }
+
+    #[test]
+    fn test_pack_unpack_revoke_unsupported_activation() {
+        test_pack_unpack(&FeatureGateInstruction::RevokeUnsupportedActivation);
+    }
+
+    #[test]
+    fn test_pack_unpack_config_instructions() {
+        let config = Config {
+            authority: Address::new_unique(),
+            revoke_guard_window: 1_000,
+            tombstone_policy: TombstonePolicy::Tombstone,
+            refund_recipients: [Address::new_unique(); MAX_REFUND_RECIPIENTS],
+        };
+        test_pack_unpack(&FeatureGateInstruction::InitializeConfig(config.clone()));
+        test_pack_unpack(&FeatureGateInstruction::UpdateConfig(config));
+    }
+
+    #[test]
+    fn test_pack_unpack_scheduled_activation_instructions() {
+        test_pack_unpack(&FeatureGateInstruction::ScheduleActivation {
+            not_before_epoch: 800,
+        });
+        test_pack_unpack(&FeatureGateInstruction::ReleaseScheduledActivation);
+    }
+
+    #[test]
+    fn test_pack_unpack_audit_log_instructions() {
+        test_pack_unpack(&FeatureGateInstruction::InitializeAuditLog { capacity: 128 });
+        test_pack_unpack(&FeatureGateInstruction::ExtendAuditLog {
+            additional_entries: u32::MAX,
+        });
+    }
+
+    #[test]
+    fn test_pack_unpack_bundle_instructions() {
+        test_pack_unpack(&FeatureGateInstruction::InitializeBundle { name: [7; 32] });
+        test_pack_unpack(&FeatureGateInstruction::QueueBundle);
+        test_pack_unpack(&FeatureGateInstruction::RevokeBundle);
+    }
+
+    #[test]
+    fn test_audit_log_account() {
+        let feature_id = Address::new_unique();
+        let bundle = Address::new_unique();
+        let audit_log = AccountMeta::new(find_audit_log_address().0, false);
+
+        for (instruction, index) in [
+            (revoke_pending_activation(&feature_id), 3),
+            (
+                revoke_pending_activation_with_refund(&feature_id, &Address::new_unique()),
+                3,
+            ),
+            (revoke_unsupported_activation(&feature_id), 4),
+            (
+                initialize_config(&feature_id, &feature_id, Config::default()),
+                5,
+            ),
+            (update_config(&feature_id, Config::default()), 2),
+            (schedule_activation(&feature_id, &feature_id, 5), 3),
+            (queue_bundle(&bundle, &feature_id, &[feature_id]), 3),
+            (revoke_bundle(&bundle, &[feature_id]), 3),
+        ] {
+            assert_eq!(instruction.accounts[index], audit_log);
+        }
+    }
+
+    #[test]
+    fn test_tally_support_sorts_vote_accounts() {
+        let feature_id = Address::new_unique();
+        let mut vote_accounts = vec![Address::new_unique(), Address::new_unique()];
+        vote_accounts.sort();
+        vote_accounts.reverse();
+        vote_accounts.push(vote_accounts[0]);
+
+        let instruction = tally_support(&feature_id, &vote_accounts);
+        let signals = instruction.accounts[1..]
+            .iter()
+            .map(|meta| meta.pubkey)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            signals,
+            vec![
+                find_support_signal_address(&feature_id, &vote_accounts[1]).0,
+                find_support_signal_address(&feature_id, &vote_accounts[0]).0,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unpack_invalid() {
+        for input in [
+            &[][..],
+            &[0, 0],
+            &[1, 0, 0, 0, 0, 0],
+            &[1; 8],
+            &[2],
+            &[2, 0, 0, 0],
+            &[3, 0],
+            &[4, 0],
+            &[5],
+            &[6; Config::LEN],
+            &[6; Config::LEN + 1],
+            &[7, 0, 0, 0, 0, 0, 0, 0],
+            &[7; 10],
+            &[8, 0],
+            &[9, 0, 0, 0],
+            &[9, 0, 0, 0, 0, 0],
+            &[10],
+            &[11],
+            &[11; 32],
+            &[12, 0],
+            &[13, 0],
+            &[14],
+        ] {
+            assert_eq!(
+                FeatureGateInstruction::unpack(input),
+                Err(ProgramError::InvalidInstructionData)
+            );
+        }
+    }
+}
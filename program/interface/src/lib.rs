@@ -0,0 +1,21 @@
+//! Instructions, state and errors of the Feature Gate program.
+//!
+//! Holds everything needed to build Feature Gate instructions and read the
+//! program's accounts without pulling in the program itself. The crate is
+//! `no_std`, using only `alloc` for instruction data and account buffers.
+//!
+//! With the `serde` or `borsh` feature, instructions and state implement the
+//! corresponding traits. The borsh encoding of an instruction matches its
+//! `pack` encoding.
+
+#![cfg_attr(not(test), no_std)]
+#![deny(missing_docs)]
+#![cfg_attr(not(test), deny(unsafe_code))]
+
+extern crate alloc;
+
+pub mod error;
+pub mod instruction;
+pub mod state;
+
+solana_address::declare_id!("Feature111111111111111111111111111111111111");
@@ -0,0 +1,1219 @@
+//! Program state
+
+use {
+    alloc::vec::Vec,
+    solana_address::{address, Address},
+    solana_clock::{Epoch, Slot},
+    solana_program_error::ProgramError,
+};
+
+/// Seed prefix of support signal addresses.
+pub const SUPPORT_SIGNAL_SEED: &[u8] = b"support_signal";
+
+/// Seed prefix of stake guard addresses.
+pub const STAKE_GUARD_SEED: &[u8] = b"stake_guard";
+
+/// The default number of slots at the end of an epoch during which anyone
+/// may revoke a guarded feature that lacks supporting stake.
+pub const STAKE_GUARD_REVOKE_WINDOW: u64 = 43_200;
+
+/// The denominator of [`StakeGuard::threshold_bps`].
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+
+/// Seed of the program config address.
+pub const CONFIG_SEED: &[u8] = b"config";
+
+/// The number of refund recipients a [`Config`] can allow.
+pub const MAX_REFUND_RECIPIENTS: usize = 4;
+
+/// Seed of the audit log address.
+pub const AUDIT_LOG_SEED: &[u8] = b"audit_log";
+
+/// Seed prefix of bundle addresses.
+pub const BUNDLE_SEED: &[u8] = b"bundle";
+
+/// The number of features a [`Bundle`] can hold.
+pub const MAX_BUNDLE_MEMBERS: usize = 8;
+
+// Every account the program owns that is not a feature starts with a tag of
+// at least 2. A feature starts with the tag of an `Option`, so none of these
+// accounts can be mistaken for one by code that only checks the owner.
+
+/// The leading byte of a [`Config`].
+pub const CONFIG_TAG: u8 = u8::MAX - 2;
+
+/// The leading byte of a [`SupportSignal`].
+pub const SUPPORT_SIGNAL_TAG: u8 = u8::MAX - 3;
+
+/// The leading byte of a [`StakeGuard`].
+pub const STAKE_GUARD_TAG: u8 = u8::MAX - 4;
+
+/// The leading byte of an [`AuditLog`].
+pub const AUDIT_LOG_TAG: u8 = u8::MAX - 5;
+
+/// The leading byte of a [`Bundle`].
+pub const BUNDLE_TAG: u8 = u8::MAX - 6;
+
+/// Strips `tag` from the start of account data, failing with
+/// [`ProgramError::InvalidAccountData`] if the data starts with anything else.
+fn untag(tag: u8, data: &[u8]) -> Result<&[u8], ProgramError> {
+    match data.split_first() {
+        Some((first, rest)) if *first == tag => Ok(rest),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// What happens to a feature account revoked by its stake guard.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[repr(u8)]
+pub enum TombstonePolicy {
+    /// Close the feature account, so the feature can be queued again.
+    #[default]
+    Close,
+    /// Replace the feature account with a [`Tombstone`], so the feature can
+    /// never be queued or activated again.
+    Tombstone,
+}
+
+impl TryFrom<u8> for TombstonePolicy {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Close),
+            1 => Ok(Self::Tombstone),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
+/// Program-wide tunables, held in a singleton account at the address derived
+/// from [`CONFIG_SEED`].
+///
+/// Until the config is initialized, the program behaves as described by
+/// `Config::default()`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct Config {
+    /// The governance authority allowed to update the config.
+    pub authority: Address,
+    /// The number of slots at the end of an epoch during which anyone may
+    /// revoke a guarded feature that lacks supporting stake.
+    pub revoke_guard_window: u64,
+    /// What happens to features revoked by their stake guard.
+    pub tombstone_policy: TombstonePolicy,
+    /// Accounts that may receive the lamports of a revoked feature instead
+    /// of the incinerator. Unused entries are the default address.
+    pub refund_recipients: [Address; MAX_REFUND_RECIPIENTS],
+}
+
+impl Config {
+    /// The serialized size of the config account, including its
+    /// [`CONFIG_TAG`].
+    pub const LEN: usize = 170;
+
+    /// Unpacks the config account, failing with
+    /// [`ProgramError::InvalidAccountData`] if `data` is not a
+    /// [`CONFIG_TAG`] followed by a valid config.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        Self::unpack_fields(untag(CONFIG_TAG, data)?)
+    }
+
+    /// Packs the config account into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::LEN);
+        buf.push(CONFIG_TAG);
+        self.pack_fields(&mut buf);
+        buf
+    }
+
+    /// Unpacks the config fields, as carried by the config instructions
+    /// without a tag, failing with [`ProgramError::InvalidAccountData`] if
+    /// `data` is not exactly one byte shorter than [`Config::LEN`] or holds
+    /// an unknown tombstone policy.
+    pub(crate) fn unpack_fields(data: &[u8]) -> Result<Self, ProgramError> {
+        let data: &[u8; Self::LEN - 1] = data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let (authority, rest) = data.split_at(32);
+        let (revoke_guard_window, rest) = rest.split_at(8);
+        let (tombstone_policy, refund_recipients) = rest.split_at(1);
+        let mut recipients = [Address::default(); MAX_REFUND_RECIPIENTS];
+        for (recipient, bytes) in recipients
+            .iter_mut()
+            .zip(refund_recipients.chunks_exact(32))
+        {
+            *recipient = Address::try_from(bytes).unwrap();
+        }
+        Ok(Self {
+            authority: Address::try_from(authority).unwrap(),
+            revoke_guard_window: u64::from_le_bytes(revoke_guard_window.try_into().unwrap()),
+            tombstone_policy: TombstonePolicy::try_from(tombstone_policy[0])
+                .map_err(|_| ProgramError::InvalidAccountData)?,
+            refund_recipients: recipients,
+        })
+    }
+
+    /// Appends the config fields to `buf`.
+    pub(crate) fn pack_fields(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.authority.as_ref());
+        buf.extend_from_slice(&self.revoke_guard_window.to_le_bytes());
+        buf.push(self.tombstone_policy as u8);
+        for recipient in &self.refund_recipients {
+            buf.extend_from_slice(recipient.as_ref());
+        }
+    }
+
+    /// Whether revoked feature lamports may be sent to `recipient`.
+    pub fn is_refund_recipient(&self, recipient: &Address) -> bool {
+        recipient != &Address::default() && self.refund_recipients.contains(recipient)
+    }
+}
+
+impl Default for Config {
+    /// The config in effect before one is initialized.
+    fn default() -> Self {
+        Self {
+            authority: Address::default(),
+            revoke_guard_window: STAKE_GUARD_REVOKE_WINDOW,
+            tombstone_policy: TombstonePolicy::Close,
+            refund_recipients: [Address::default(); MAX_REFUND_RECIPIENTS],
+        }
+    }
+}
+
+/// The program config address, derived from [`CONFIG_SEED`].
+pub const CONFIG_ADDRESS: Address = address!("GWQkM3ZsN7o6FaZqn1GerVn7oHWWQrTmhs9tdJCxsG8h");
+
+/// The bump seed of [`CONFIG_ADDRESS`].
+pub const CONFIG_BUMP_SEED: u8 = 254;
+
+/// Derives the address of the program config. Equal to
+/// ([`CONFIG_ADDRESS`], [`CONFIG_BUMP_SEED`]), which the program uses to
+/// avoid deriving it on every instruction.
+pub fn find_config_address() -> (Address, u8) {
+    Address::find_program_address(&[CONFIG_SEED], &crate::id())
+}
+
+/// The leading byte of a [`Tombstone`], which no feature account can start
+/// with.
+pub const TOMBSTONE_TAG: u8 = u8::MAX;
+
+/// The remains of a feature revoked under [`TombstonePolicy::Tombstone`].
+///
+/// A tombstone occupies the feature's address and does not deserialize as a
+/// feature, so the runtime never activates it and the address cannot be
+/// queued again.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct Tombstone {
+    /// The slot at which the feature was revoked.
+    pub revoked_at: Slot,
+}
+
+impl Tombstone {
+    /// The serialized size of a tombstone, which matches a feature account.
+    pub const LEN: usize = 9;
+
+    /// Unpacks a tombstone, failing with [`ProgramError::InvalidAccountData`]
+    /// if `data` is not a [`TOMBSTONE_TAG`] followed by a slot.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        match data {
+            [TOMBSTONE_TAG, revoked_at @ ..] => Ok(Self {
+                revoked_at: u64::from_le_bytes(
+                    revoked_at
+                        .try_into()
+                        .map_err(|_| ProgramError::InvalidAccountData)?,
+                ),
+            }),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    /// Packs a tombstone into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::LEN);
+        buf.push(TOMBSTONE_TAG);
+        buf.extend_from_slice(&self.revoked_at.to_le_bytes());
+        buf
+    }
+}
+
+/// The leading byte of a [`ScheduledActivation`], which no feature account
+/// can start with.
+pub const SCHEDULED_ACTIVATION_TAG: u8 = u8::MAX - 1;
+
+/// A feature queued to activate no earlier than a given epoch.
+///
+/// Like a [`Tombstone`], a scheduled activation occupies the feature's
+/// address and does not deserialize as a feature, so the runtime leaves it
+/// alone. Once the epoch before `not_before_epoch` begins, anyone can release
+/// it into a pending feature, which the runtime then activates at the next
+/// epoch boundary.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct ScheduledActivation {
+    /// The earliest epoch in which the feature may activate.
+    pub not_before_epoch: Epoch,
+}
+
+impl ScheduledActivation {
+    /// The serialized size of a scheduled activation, which matches a
+    /// feature account.
+    pub const LEN: usize = 9;
+
+    /// Unpacks a scheduled activation, failing with
+    /// [`ProgramError::InvalidAccountData`] if `data` is not a
+    /// [`SCHEDULED_ACTIVATION_TAG`] followed by an epoch.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        match data {
+            [SCHEDULED_ACTIVATION_TAG, not_before_epoch @ ..] => Ok(Self {
+                not_before_epoch: u64::from_le_bytes(
+                    not_before_epoch
+                        .try_into()
+                        .map_err(|_| ProgramError::InvalidAccountData)?,
+                ),
+            }),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+
+    /// Packs a scheduled activation into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::LEN);
+        buf.push(SCHEDULED_ACTIVATION_TAG);
+        buf.extend_from_slice(&self.not_before_epoch.to_le_bytes());
+        buf
+    }
+
+    /// The first epoch in which the feature may be released.
+    pub fn release_epoch(&self) -> Epoch {
+        self.not_before_epoch.saturating_sub(1)
+    }
+
+    /// Whether the feature may be released in `epoch`. A feature released in
+    /// epoch `E - 1` activates at the start of epoch `E`.
+    pub fn is_releasable_in(&self, epoch: Epoch) -> bool {
+        epoch >= self.release_epoch()
+    }
+}
+
+/// The program action recorded by an [`AuditEntry`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+#[repr(u8)]
+pub enum AuditAction {
+    /// A feature was queued with `ScheduleActivation`.
+    ScheduleActivation,
+    /// A feature was revoked with `RevokePendingActivation` or as a member of
+    /// a bundle with `RevokeBundle`.
+    RevokePendingActivation,
+    /// A feature was revoked by its stake guard.
+    RevokeUnsupportedActivation,
+    /// The program config was initialized.
+    InitializeConfig,
+    /// The program config, possibly including its authority, was replaced.
+    UpdateConfig,
+    /// A feature was queued as a member of a bundle with `QueueBundle`.
+    QueueBundleMember,
+}
+
+impl TryFrom<u8> for AuditAction {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::ScheduleActivation),
+            1 => Ok(Self::RevokePendingActivation),
+            2 => Ok(Self::RevokeUnsupportedActivation),
+            3 => Ok(Self::InitializeConfig),
+            4 => Ok(Self::UpdateConfig),
+            5 => Ok(Self::QueueBundleMember),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// A single record in the [`AuditLog`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct AuditEntry {
+    /// The recorded action.
+    pub action: AuditAction,
+    /// The feature acted on or, for config changes, the config authority
+    /// after the change.
+    pub subject: Address,
+    /// The account that authorized the action, or the default address for
+    /// permissionless actions.
+    pub signer: Address,
+    /// The slot in which the action took place.
+    pub slot: Slot,
+}
+
+impl AuditEntry {
+    /// The serialized size of an audit entry.
+    pub const LEN: usize = 73;
+
+    /// Unpacks an audit entry, failing with
+    /// [`ProgramError::InvalidAccountData`] if `data` is not a valid entry.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        let data: &[u8; Self::LEN] = data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let (action, rest) = data.split_at(1);
+        let (subject, rest) = rest.split_at(32);
+        let (signer, slot) = rest.split_at(32);
+        Ok(Self {
+            action: AuditAction::try_from(action[0])?,
+            subject: Address::try_from(subject).unwrap(),
+            signer: Address::try_from(signer).unwrap(),
+            slot: u64::from_le_bytes(slot.try_into().unwrap()),
+        })
+    }
+
+    /// Packs an audit entry into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::LEN);
+        buf.push(self.action as u8);
+        buf.extend_from_slice(self.subject.as_ref());
+        buf.extend_from_slice(self.signer.as_ref());
+        buf.extend_from_slice(&self.slot.to_le_bytes());
+        buf
+    }
+}
+
+/// The header of the audit log, a ring buffer of [`AuditEntry`] records
+/// stored at the address derived from [`AUDIT_LOG_SEED`].
+///
+/// The header is followed by as many entries as the account has room for.
+/// New entries are written at `head`, overwriting the oldest entry once the
+/// ring is full. The program grows a full log rather than overwrite it for
+/// actions that need no authority; see
+/// [`InitializeAuditLog`](crate::instruction::FeatureGateInstruction::InitializeAuditLog).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct AuditLog {
+    /// The sequence number of the next entry, i.e. the number of entries
+    /// ever recorded.
+    pub next_sequence: u64,
+    /// The index at which the next entry is written.
+    pub head: u32,
+    /// The number of entries held, at most the capacity.
+    pub len: u32,
+}
+
+impl AuditLog {
+    /// The serialized size of the audit log header, including its
+    /// [`AUDIT_LOG_TAG`].
+    pub const LEN: usize = 17;
+
+    /// The account size of an audit log holding `capacity` entries.
+    pub fn space(capacity: usize) -> usize {
+        capacity
+            .saturating_mul(AuditEntry::LEN)
+            .saturating_add(Self::LEN)
+    }
+
+    /// The number of entries an audit log account of `data_len` bytes holds.
+    pub fn capacity(data_len: usize) -> usize {
+        data_len.saturating_sub(Self::LEN) / AuditEntry::LEN
+    }
+
+    /// Unpacks the audit log header from the start of the account data,
+    /// failing with [`ProgramError::InvalidAccountData`] if it does not start
+    /// with [`AUDIT_LOG_TAG`] or is inconsistent with the account size.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        let Some(([s0, s1, s2, s3, s4, s5, s6, s7, h0, h1, h2, h3, l0, l1, l2, l3], entries)) =
+            untag(AUDIT_LOG_TAG, data)?.split_first_chunk::<{ Self::LEN - 1 }>()
+        else {
+            return Err(ProgramError::InvalidAccountData);
+        };
+        let log = Self {
+            next_sequence: u64::from_le_bytes([*s0, *s1, *s2, *s3, *s4, *s5, *s6, *s7]),
+            head: u32::from_le_bytes([*h0, *h1, *h2, *h3]),
+            len: u32::from_le_bytes([*l0, *l1, *l2, *l3]),
+        };
+        let capacity = entries.len() / AuditEntry::LEN;
+        if capacity == 0
+            || entries.len() % AuditEntry::LEN != 0
+            || log.head as usize >= capacity
+            || log.len as usize > capacity
+            || u64::from(log.len) > log.next_sequence
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(log)
+    }
+
+    /// Packs the audit log header into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::LEN);
+        buf.push(AUDIT_LOG_TAG);
+        buf.extend_from_slice(&self.next_sequence.to_le_bytes());
+        buf.extend_from_slice(&self.head.to_le_bytes());
+        buf.extend_from_slice(&self.len.to_le_bytes());
+        buf
+    }
+
+    /// Whether an audit log account of `data_len` bytes has no unused room
+    /// left, so that the next entry overwrites the oldest.
+    pub fn is_full(&self, data_len: usize) -> bool {
+        self.len as usize >= Self::capacity(data_len)
+    }
+
+    /// The index of the oldest entry held.
+    pub fn tail(&self, capacity: usize) -> usize {
+        let (head, len) = (self.head as usize, self.len as usize);
+        if len <= head {
+            head.saturating_sub(len)
+        } else {
+            head.saturating_add(capacity).saturating_sub(len)
+        }
+    }
+
+    /// Records `entry` in the audit log stored in `data`, overwriting the
+    /// oldest entry if the log is full.
+    pub fn append(data: &mut [u8], entry: &AuditEntry) -> Result<(), ProgramError> {
+        let mut log = Self::unpack(data)?;
+        let capacity = Self::capacity(data.len());
+
+        let offset = Self::LEN.saturating_add((log.head as usize).saturating_mul(AuditEntry::LEN));
+        data[offset..offset.saturating_add(AuditEntry::LEN)].copy_from_slice(&entry.pack());
+
+        log.next_sequence = log
+            .next_sequence
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        log.head = if (log.head as usize).saturating_add(1) < capacity {
+            log.head.saturating_add(1)
+        } else {
+            0
+        };
+        if (log.len as usize) < capacity {
+            log.len = log.len.saturating_add(1);
+        }
+        data[..Self::LEN].copy_from_slice(&log.pack());
+        Ok(())
+    }
+
+    /// Rearranges the audit log stored in `data` after its account grew from
+    /// `old_len` bytes, moving the oldest entry first so that new entries
+    /// fill the added room before the ring wraps again.
+    pub fn extend(data: &mut [u8], old_len: usize) -> Result<(), ProgramError> {
+        let old_data = data
+            .get_mut(..old_len)
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let mut log = Self::unpack(old_data)?;
+        let tail = log.tail(Self::capacity(old_len));
+        old_data[Self::LEN..].rotate_left(tail.saturating_mul(AuditEntry::LEN));
+        log.head = log.len;
+        data[..Self::LEN].copy_from_slice(&log.pack());
+        Self::unpack(data).map(|_| ())
+    }
+}
+
+/// The audit log address, derived from [`AUDIT_LOG_SEED`].
+pub const AUDIT_LOG_ADDRESS: Address = address!("H1ic8AHVqM2DEz8rGzgtdSMeoHvaY95Q1QMPiBrVWaMs");
+
+/// The bump seed of [`AUDIT_LOG_ADDRESS`].
+pub const AUDIT_LOG_BUMP_SEED: u8 = 255;
+
+/// Derives the address of the audit log. Equal to ([`AUDIT_LOG_ADDRESS`],
+/// [`AUDIT_LOG_BUMP_SEED`]).
+pub fn find_audit_log_address() -> (Address, u8) {
+    Address::find_program_address(&[AUDIT_LOG_SEED], &crate::id())
+}
+
+/// A validator's signal that it runs software supporting a pending feature.
+///
+/// Stored at the address derived from [`SUPPORT_SIGNAL_SEED`], the feature ID
+/// and the vote account, so each validator holds at most one signal per
+/// feature.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct SupportSignal {
+    /// The feature the validator supports.
+    pub feature_id: Address,
+    /// The signaling validator's vote account.
+    pub vote_account: Address,
+    /// Major version of the validator software.
+    pub major: u16,
+    /// Minor version of the validator software.
+    pub minor: u16,
+    /// Patch version of the validator software.
+    pub patch: u16,
+    /// The slot of the most recent signal.
+    pub signaled_at: Slot,
+    /// The epoch in which the validator's stake was last tallied, if ever.
+    /// Stored as `u64::MAX` when the signal has never been tallied.
+    pub tallied_epoch: Option<Epoch>,
+}
+
+impl SupportSignal {
+    /// The serialized size of a support signal, including its
+    /// [`SUPPORT_SIGNAL_TAG`].
+    pub const LEN: usize = 87;
+
+    /// Unpacks a support signal, failing with
+    /// [`ProgramError::InvalidAccountData`] if `data` is not a
+    /// [`SUPPORT_SIGNAL_TAG`] followed by a support signal.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        let data: &[u8; Self::LEN - 1] = untag(SUPPORT_SIGNAL_TAG, data)?
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let (feature_id, rest) = data.split_at(32);
+        let (vote_account, rest) = rest.split_at(32);
+        let (major, rest) = rest.split_at(2);
+        let (minor, rest) = rest.split_at(2);
+        let (patch, rest) = rest.split_at(2);
+        let (signaled_at, tallied_epoch) = rest.split_at(8);
+        Ok(Self {
+            feature_id: Address::try_from(feature_id).unwrap(),
+            vote_account: Address::try_from(vote_account).unwrap(),
+            major: u16::from_le_bytes(major.try_into().unwrap()),
+            minor: u16::from_le_bytes(minor.try_into().unwrap()),
+            patch: u16::from_le_bytes(patch.try_into().unwrap()),
+            signaled_at: u64::from_le_bytes(signaled_at.try_into().unwrap()),
+            tallied_epoch: match u64::from_le_bytes(tallied_epoch.try_into().unwrap()) {
+                u64::MAX => None,
+                epoch => Some(epoch),
+            },
+        })
+    }
+
+    /// Packs a support signal into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::LEN);
+        buf.push(SUPPORT_SIGNAL_TAG);
+        buf.extend_from_slice(self.feature_id.as_ref());
+        buf.extend_from_slice(self.vote_account.as_ref());
+        buf.extend_from_slice(&self.major.to_le_bytes());
+        buf.extend_from_slice(&self.minor.to_le_bytes());
+        buf.extend_from_slice(&self.patch.to_le_bytes());
+        buf.extend_from_slice(&self.signaled_at.to_le_bytes());
+        buf.extend_from_slice(&self.tallied_epoch.unwrap_or(u64::MAX).to_le_bytes());
+        buf
+    }
+
+    /// Whether the validator's stake has already been tallied in `epoch`.
+    pub fn is_tallied_in(&self, epoch: Epoch) -> bool {
+        self.tallied_epoch == Some(epoch)
+    }
+}
+
+/// Derives the address of the support signal for a feature and vote account.
+pub fn find_support_signal_address(feature_id: &Address, vote_account: &Address) -> (Address, u8) {
+    Address::find_program_address(
+        &[
+            SUPPORT_SIGNAL_SEED,
+            feature_id.as_ref(),
+            vote_account.as_ref(),
+        ],
+        &crate::id(),
+    )
+}
+
+/// An opt-in rule that lets anyone revoke a pending feature whose supporting
+/// stake is below a threshold.
+///
+/// Stored at the address derived from [`STAKE_GUARD_SEED`] and the feature
+/// ID. Supporting stake is tallied from support signals each epoch, counting
+/// each signal at most once per epoch, and the feature may be revoked permissionlessly during the last
+/// [`Config::revoke_guard_window`] slots of an epoch if the tally for that
+/// epoch falls short.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct StakeGuard {
+    /// The guarded feature.
+    pub feature_id: Address,
+    /// The share of the total epoch stake that must support the feature, in
+    /// basis points.
+    pub threshold_bps: u16,
+    /// The epoch of the most recent tally.
+    pub epoch: Epoch,
+    /// The supporting stake tallied in [`StakeGuard::epoch`].
+    pub supporting_stake: u64,
+}
+
+impl StakeGuard {
+    /// The serialized size of a stake guard, including its
+    /// [`STAKE_GUARD_TAG`].
+    pub const LEN: usize = 51;
+
+    /// Unpacks a stake guard, failing with
+    /// [`ProgramError::InvalidAccountData`] if `data` is not a
+    /// [`STAKE_GUARD_TAG`] followed by a stake guard.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        let data: &[u8; Self::LEN - 1] = untag(STAKE_GUARD_TAG, data)?
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let (feature_id, rest) = data.split_at(32);
+        let (threshold_bps, rest) = rest.split_at(2);
+        let (epoch, supporting_stake) = rest.split_at(8);
+        Ok(Self {
+            feature_id: Address::try_from(feature_id).unwrap(),
+            threshold_bps: u16::from_le_bytes(threshold_bps.try_into().unwrap()),
+            epoch: u64::from_le_bytes(epoch.try_into().unwrap()),
+            supporting_stake: u64::from_le_bytes(supporting_stake.try_into().unwrap()),
+        })
+    }
+
+    /// Packs a stake guard into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::LEN);
+        buf.push(STAKE_GUARD_TAG);
+        buf.extend_from_slice(self.feature_id.as_ref());
+        buf.extend_from_slice(&self.threshold_bps.to_le_bytes());
+        buf.extend_from_slice(&self.epoch.to_le_bytes());
+        buf.extend_from_slice(&self.supporting_stake.to_le_bytes());
+        buf
+    }
+
+    /// The stake tallied in `epoch`, which is zero if the most recent tally
+    /// is from an earlier epoch.
+    pub fn supporting_stake_in(&self, epoch: Epoch) -> u64 {
+        if self.epoch == epoch {
+            self.supporting_stake
+        } else {
+            0
+        }
+    }
+
+    /// Whether `supporting_stake` out of `total_stake` meets the threshold.
+    pub fn is_met(&self, supporting_stake: u64, total_stake: u64) -> bool {
+        u128::from(supporting_stake).saturating_mul(u128::from(MAX_BASIS_POINTS))
+            >= u128::from(total_stake).saturating_mul(u128::from(self.threshold_bps))
+    }
+}
+
+/// Derives the address of the stake guard for a feature.
+pub fn find_stake_guard_address(feature_id: &Address) -> (Address, u8) {
+    Address::find_program_address(&[STAKE_GUARD_SEED, feature_id.as_ref()], &crate::id())
+}
+
+/// A named group of features that are queued and revoked together.
+///
+/// Stored at the address derived from [`BUNDLE_SEED`], the bundle authority
+/// and the name, so each authority has its own namespace of bundles. The
+/// member list is fixed when the bundle is created.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
+pub struct Bundle {
+    /// The account that created the bundle.
+    pub authority: Address,
+    /// The bundle name, as UTF-8 padded with zeros.
+    pub name: [u8; 32],
+    /// The member feature IDs, in the order their accounts are passed to
+    /// `QueueBundle` and `RevokeBundle`. Unused entries are the default
+    /// address.
+    pub members: [Address; MAX_BUNDLE_MEMBERS],
+}
+
+impl Bundle {
+    /// The serialized size of a bundle, including its [`BUNDLE_TAG`].
+    pub const LEN: usize = 321;
+
+    /// Creates a bundle, failing with [`ProgramError::InvalidArgument`]
+    /// unless `members` holds between one and [`MAX_BUNDLE_MEMBERS`]
+    /// distinct feature IDs.
+    pub fn new(
+        authority: Address,
+        name: [u8; 32],
+        members: &[Address],
+    ) -> Result<Self, ProgramError> {
+        if members.is_empty() || members.len() > MAX_BUNDLE_MEMBERS {
+            return Err(ProgramError::InvalidArgument);
+        }
+        let mut bundle = Self {
+            authority,
+            name,
+            members: [Address::default(); MAX_BUNDLE_MEMBERS],
+        };
+        for (index, member) in members.iter().enumerate() {
+            if member == &Address::default() || members[..index].contains(member) {
+                return Err(ProgramError::InvalidArgument);
+            }
+            bundle.members[index] = *member;
+        }
+        Ok(bundle)
+    }
+
+    /// Unpacks a bundle, failing with [`ProgramError::InvalidAccountData`]
+    /// if `data` is not a [`BUNDLE_TAG`] followed by a bundle.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        let data: &[u8; Self::LEN - 1] = untag(BUNDLE_TAG, data)?
+            .try_into()
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+        let (authority, rest) = data.split_at(32);
+        let (name, members) = rest.split_at(32);
+        let mut bundle = Self {
+            authority: Address::try_from(authority).unwrap(),
+            name: name.try_into().unwrap(),
+            members: [Address::default(); MAX_BUNDLE_MEMBERS],
+        };
+        for (member, bytes) in bundle.members.iter_mut().zip(members.chunks_exact(32)) {
+            *member = Address::try_from(bytes).unwrap();
+        }
+        Ok(bundle)
+    }
+
+    /// Packs a bundle into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(Self::LEN);
+        buf.push(BUNDLE_TAG);
+        buf.extend_from_slice(self.authority.as_ref());
+        buf.extend_from_slice(&self.name);
+        for member in &self.members {
+            buf.extend_from_slice(member.as_ref());
+        }
+        buf
+    }
+
+    /// The member feature IDs.
+    pub fn members(&self) -> impl Iterator<Item = &Address> {
+        self.members
+            .iter()
+            .take_while(|member| *member != &Address::default())
+    }
+}
+
+/// Derives the address of the bundle named `name` created by `authority`.
+pub fn find_bundle_address(authority: &Address, name: &[u8; 32]) -> (Address, u8) {
+    Address::find_program_address(&[BUNDLE_SEED, authority.as_ref(), name], &crate::id())
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_feature_gate_interface::Feature};
+
+    #[test]
+    fn test_precomputed_addresses() {
+        assert_eq!(find_config_address(), (CONFIG_ADDRESS, CONFIG_BUMP_SEED));
+        assert_eq!(
+            find_audit_log_address(),
+            (AUDIT_LOG_ADDRESS, AUDIT_LOG_BUMP_SEED)
+        );
+    }
+
+    #[test]
+    fn test_pack_unpack_bundle() {
+        let members = [Address::new_unique(), Address::new_unique()];
+        let bundle = Bundle::new(Address::new_unique(), [7; 32], &members).unwrap();
+        let packed = bundle.pack();
+        assert_eq!(packed.len(), Bundle::LEN);
+        assert_eq!(Bundle::unpack(&packed).as_ref(), Ok(&bundle));
+        assert!(bundle.members().eq(members.iter()));
+
+        assert_eq!(
+            Bundle::unpack(&packed[1..]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_new_bundle_invalid_members() {
+        let member = Address::new_unique();
+        for members in [
+            vec![],
+            vec![member, member],
+            vec![Address::default()],
+            (0..=MAX_BUNDLE_MEMBERS)
+                .map(|_| Address::new_unique())
+                .collect(),
+        ] {
+            assert_eq!(
+                Bundle::new(Address::new_unique(), [0; 32], &members),
+                Err(ProgramError::InvalidArgument)
+            );
+        }
+    }
+
+    #[test]
+    fn test_pack_unpack_support_signal() {
+        let signal = SupportSignal {
+            feature_id: Address::new_unique(),
+            vote_account: Address::new_unique(),
+            major: 3,
+            minor: 1,
+            patch: 8,
+            signaled_at: u64::MAX,
+            tallied_epoch: None,
+        };
+        let packed = signal.pack();
+        assert_eq!(packed.len(), SupportSignal::LEN);
+        assert_eq!(SupportSignal::unpack(&packed), Ok(signal.clone()));
+
+        let tallied = SupportSignal {
+            tallied_epoch: Some(7),
+            ..signal
+        };
+        assert_eq!(SupportSignal::unpack(&tallied.pack()), Ok(tallied.clone()));
+        assert!(tallied.is_tallied_in(7));
+        assert!(!tallied.is_tallied_in(8));
+
+        assert_eq!(
+            SupportSignal::unpack(&packed[1..]),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            SupportSignal::unpack(&[0; SupportSignal::LEN + 1]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_pack_unpack_stake_guard() {
+        let guard = StakeGuard {
+            feature_id: Address::new_unique(),
+            threshold_bps: 6_667,
+            epoch: 42,
+            supporting_stake: u64::MAX,
+        };
+        let packed = guard.pack();
+        assert_eq!(packed.len(), StakeGuard::LEN);
+        assert_eq!(StakeGuard::unpack(&packed), Ok(guard));
+
+        assert_eq!(
+            StakeGuard::unpack(&packed[1..]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_stake_guard_threshold() {
+        let guard = StakeGuard {
+            feature_id: Address::new_unique(),
+            threshold_bps: 6_667,
+            epoch: 42,
+            supporting_stake: 6_667,
+        };
+        assert_eq!(guard.supporting_stake_in(42), 6_667);
+        assert_eq!(guard.supporting_stake_in(43), 0);
+
+        assert!(guard.is_met(6_667, 10_000));
+        assert!(!guard.is_met(6_666, 10_000));
+        assert!(guard.is_met(u64::MAX, u64::MAX));
+        assert!(guard.is_met(0, 0));
+    }
+
+    #[test]
+    fn test_pack_unpack_config() {
+        let mut config = Config {
+            authority: Address::new_unique(),
+            revoke_guard_window: 1_000,
+            tombstone_policy: TombstonePolicy::Tombstone,
+            refund_recipients: [Address::default(); MAX_REFUND_RECIPIENTS],
+        };
+        config.refund_recipients[1] = Address::new_unique();
+        let packed = config.pack();
+        assert_eq!(packed.len(), Config::LEN);
+        assert_eq!(Config::unpack(&packed), Ok(config.clone()));
+
+        assert!(config.is_refund_recipient(&config.refund_recipients[1]));
+        assert!(!config.is_refund_recipient(&Address::default()));
+        assert!(!config.is_refund_recipient(&Address::new_unique()));
+
+        let mut invalid_policy = packed.clone();
+        invalid_policy[41] = 2;
+        assert_eq!(
+            Config::unpack(&invalid_policy),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            Config::unpack(&packed[1..]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_program_accounts_are_not_features() {
+        // Fill every field that follows the tag with the bytes of a pending
+        // and an active feature.
+        for fill in [0, 1] {
+            let address = Address::new_from_array([fill; 32]);
+            let slot = u64::from_le_bytes([fill; 8]);
+            let mut log = empty_audit_log(1);
+            log[1..].fill(fill);
+            for data in [
+                Config {
+                    authority: address,
+                    revoke_guard_window: slot,
+                    tombstone_policy: TombstonePolicy::Close,
+                    refund_recipients: [address; MAX_REFUND_RECIPIENTS],
+                }
+                .pack(),
+                SupportSignal {
+                    feature_id: address,
+                    vote_account: address,
+                    major: u16::from(fill),
+                    minor: u16::from(fill),
+                    patch: u16::from(fill),
+                    signaled_at: slot,
+                    tallied_epoch: Some(slot),
+                }
+                .pack(),
+                StakeGuard {
+                    feature_id: address,
+                    threshold_bps: u16::from(fill),
+                    epoch: slot,
+                    supporting_stake: slot,
+                }
+                .pack(),
+                log,
+                Bundle {
+                    authority: address,
+                    name: [fill; 32],
+                    members: [address; MAX_BUNDLE_MEMBERS],
+                }
+                .pack(),
+                Tombstone { revoked_at: slot }.pack(),
+                ScheduledActivation {
+                    not_before_epoch: slot,
+                }
+                .pack(),
+            ] {
+                assert!(data[0] >= 2);
+                assert!(bincode::deserialize::<Feature>(&data).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_pack_unpack_tombstone() {
+        let tombstone = Tombstone { revoked_at: 42 };
+        let packed = tombstone.pack();
+        assert_eq!(packed.len(), Tombstone::LEN);
+        assert_eq!(Tombstone::unpack(&packed), Ok(tombstone));
+
+        // Neither a pending nor an active feature is a tombstone.
+        assert_eq!(
+            Tombstone::unpack(&[0; Tombstone::LEN]),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            Tombstone::unpack(&[1; Tombstone::LEN]),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            Tombstone::unpack(&[TOMBSTONE_TAG]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    fn audit_entry(slot: Slot) -> AuditEntry {
+        AuditEntry {
+            action: AuditAction::RevokePendingActivation,
+            subject: Address::new_unique(),
+            signer: Address::new_unique(),
+            slot,
+        }
+    }
+
+    fn empty_audit_log(capacity: usize) -> Vec<u8> {
+        let mut data = vec![0; AuditLog::space(capacity)];
+        data[..AuditLog::LEN].copy_from_slice(&AuditLog::default().pack());
+        data
+    }
+
+    /// The entries of the audit log in `data`, oldest first.
+    fn audit_entries(data: &[u8]) -> Vec<AuditEntry> {
+        let log = AuditLog::unpack(data).unwrap();
+        let capacity = AuditLog::capacity(data.len());
+        (0..log.len as usize)
+            .map(|i| {
+                let index = log
+                    .tail(capacity)
+                    .saturating_add(i)
+                    .checked_rem(capacity)
+                    .unwrap();
+                let offset = AuditLog::LEN.saturating_add(index.saturating_mul(AuditEntry::LEN));
+                AuditEntry::unpack(&data[offset..offset.saturating_add(AuditEntry::LEN)]).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_pack_unpack_audit_entry() {
+        let entry = audit_entry(42);
+        let packed = entry.pack();
+        assert_eq!(packed.len(), AuditEntry::LEN);
+        assert_eq!(AuditEntry::unpack(&packed), Ok(entry));
+
+        let mut invalid_action = packed.clone();
+        invalid_action[0] = 6;
+        assert_eq!(
+            AuditEntry::unpack(&invalid_action),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            AuditEntry::unpack(&packed[1..]),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_unpack_audit_log_invalid() {
+        let mut data = vec![0; AuditLog::space(2)];
+        assert_eq!(
+            AuditLog::unpack(&data),
+            Err(ProgramError::InvalidAccountData)
+        );
+        data[..AuditLog::LEN].copy_from_slice(&AuditLog::default().pack());
+        assert_eq!(AuditLog::unpack(&data), Ok(AuditLog::default()));
+        for log in [
+            AuditLog {
+                next_sequence: 3,
+                head: 2,
+                len: 1,
+            },
+            AuditLog {
+                next_sequence: 3,
+                head: 0,
+                len: 3,
+            },
+            AuditLog {
+                next_sequence: 1,
+                head: 0,
+                len: 2,
+            },
+        ] {
+            data[..AuditLog::LEN].copy_from_slice(&log.pack());
+            assert_eq!(
+                AuditLog::unpack(&data),
+                Err(ProgramError::InvalidAccountData)
+            );
+        }
+        // A log without room for entries, and one with a partial entry.
+        for data in [empty_audit_log(0), [empty_audit_log(1), vec![0]].concat()] {
+            assert_eq!(
+                AuditLog::unpack(&data),
+                Err(ProgramError::InvalidAccountData)
+            );
+        }
+    }
+
+    #[test]
+    fn test_audit_log_wraps() {
+        let mut data = empty_audit_log(3);
+        let entries = (0..5).map(audit_entry).collect::<Vec<_>>();
+
+        for entry in &entries[..3] {
+            assert!(!AuditLog::unpack(&data).unwrap().is_full(data.len()));
+            AuditLog::append(&mut data, entry).unwrap();
+        }
+        assert!(AuditLog::unpack(&data).unwrap().is_full(data.len()));
+        assert_eq!(audit_entries(&data), entries[..3]);
+        assert_eq!(
+            AuditLog::unpack(&data),
+            Ok(AuditLog {
+                next_sequence: 3,
+                head: 0,
+                len: 3,
+            })
+        );
+
+        // The oldest entries are overwritten in order.
+        for entry in &entries[3..] {
+            AuditLog::append(&mut data, entry).unwrap();
+        }
+        assert_eq!(audit_entries(&data), entries[2..]);
+        assert_eq!(
+            AuditLog::unpack(&data),
+            Ok(AuditLog {
+                next_sequence: 5,
+                head: 2,
+                len: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_audit_log_extend_after_wrap() {
+        let mut data = empty_audit_log(3);
+        let entries = (0..7).map(audit_entry).collect::<Vec<_>>();
+        for entry in &entries[..5] {
+            AuditLog::append(&mut data, entry).unwrap();
+        }
+
+        data.resize(AuditLog::space(4), 0);
+        AuditLog::extend(&mut data, AuditLog::space(3)).unwrap();
+        assert_eq!(audit_entries(&data), entries[2..5]);
+        assert_eq!(
+            AuditLog::unpack(&data),
+            Ok(AuditLog {
+                next_sequence: 5,
+                head: 3,
+                len: 3,
+            })
+        );
+
+        // The new slot is filled before the ring wraps again.
+        AuditLog::append(&mut data, &entries[5]).unwrap();
+        assert_eq!(audit_entries(&data), entries[2..6]);
+        AuditLog::append(&mut data, &entries[6]).unwrap();
+        assert_eq!(audit_entries(&data), entries[3..7]);
+    }
+
+    #[test]
+    fn test_pack_unpack_scheduled_activation() {
+        let scheduled = ScheduledActivation {
+            not_before_epoch: 42,
+        };
+        let packed = scheduled.pack();
+        assert_eq!(packed.len(), ScheduledActivation::LEN);
+        assert_eq!(ScheduledActivation::unpack(&packed), Ok(scheduled));
+        assert_eq!(
+            Tombstone::unpack(&packed),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            ScheduledActivation::unpack(&[0; ScheduledActivation::LEN]),
+            Err(ProgramError::InvalidAccountData)
+        );
+
+        assert!(!scheduled.is_releasable_in(40));
+        assert!(scheduled.is_releasable_in(41));
+        assert!(scheduled.is_releasable_in(u64::MAX));
+    }
+}
@@ -0,0 +1,31 @@
+//! Epoch stake queries
+//!
+//! A minimal wrapper around the `sol_get_epoch_stake` syscall, matching the
+//! `solana-epoch-stake` crate.
+
+#![allow(unsafe_code)]
+
+use solana_address::Address;
+
+fn get_epoch_stake(var_addr: *const u8) -> u64 {
+    #[cfg(target_os = "solana")]
+    // SAFETY: the syscall reads a 32-byte address from `var_addr`, or returns
+    // the total stake if it is null.
+    let result = unsafe { solana_define_syscall::definitions::sol_get_epoch_stake(var_addr) };
+
+    #[cfg(not(target_os = "solana"))]
+    let result = solana_sysvar::program_stubs::sol_get_epoch_stake(var_addr);
+
+    result
+}
+
+/// Returns the total stake delegated in the current epoch.
+pub fn get_epoch_total_stake() -> u64 {
+    get_epoch_stake(core::ptr::null())
+}
+
+/// Returns the stake delegated to `vote_address` in the current epoch, or
+/// zero if it is not a vote account with stake.
+pub fn get_epoch_stake_for_vote_account(vote_address: &Address) -> u64 {
+    get_epoch_stake(vote_address.as_ref().as_ptr())
+}
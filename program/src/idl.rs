@@ -0,0 +1,596 @@
+//! Shank descriptions of the program interface.
+//!
+//! `idl.json` is generated from these definitions, which mirror
+//! `solana-feature-gate-program-interface` with the annotations Shank needs.
+//! Variants and fields must stay in the same order as in the interface crate.
+
+use {
+    shank::{ShankAccount, ShankInstruction, ShankType},
+    solana_address::Address,
+    thiserror::Error,
+};
+
+/// Feature Gate program instructions
+#[rustfmt::skip]
+#[derive(ShankInstruction)]
+pub enum FeatureGateInstruction {
+    /// Revoke a pending feature activation.
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "feature",
+        description = "The feature account to revoke"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "incinerator",
+        description = "The incinerator account, or an allowed refund recipient"
+    )]
+    #[account(
+        2,
+        name = "system_program",
+        description = "The system program"
+    )]
+    #[account(
+        3,
+        optional,
+        writable,
+        name = "audit_log",
+        description = "The audit log account"
+    )]
+    RevokePendingActivation,
+    /// Signal that a validator runs software supporting a pending feature.
+    #[account(
+        0,
+        writable,
+        name = "support_signal",
+        description = "The support signal account"
+    )]
+    #[account(
+        1,
+        name = "feature",
+        description = "The pending feature account"
+    )]
+    #[account(
+        2,
+        name = "vote_account",
+        description = "The signaling validator's vote account"
+    )]
+    #[account(
+        3,
+        signer,
+        name = "authority",
+        description = "The vote account's node identity or authorized withdrawer"
+    )]
+    #[account(
+        4,
+        writable,
+        signer,
+        name = "payer",
+        description = "The account paying for the support signal account"
+    )]
+    #[account(
+        5,
+        name = "system_program",
+        description = "The system program"
+    )]
+    SignalSupport {
+        /// Major version of the validator software.
+        major: u16,
+        /// Minor version of the validator software.
+        minor: u16,
+        /// Patch version of the validator software.
+        patch: u16,
+    },
+    /// Guard a pending feature with a supporting stake threshold.
+    #[account(
+        0,
+        writable,
+        name = "stake_guard",
+        description = "The stake guard account"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "feature",
+        description = "The pending feature account to guard"
+    )]
+    #[account(
+        2,
+        writable,
+        signer,
+        name = "payer",
+        description = "The account paying for the stake guard account"
+    )]
+    #[account(
+        3,
+        name = "system_program",
+        description = "The system program"
+    )]
+    InitializeStakeGuard {
+        /// The share of the total stake that must support the feature, in
+        /// basis points.
+        threshold_bps: u16,
+    },
+    /// Tally the stake signaling support for a guarded feature.
+    #[account(
+        0,
+        writable,
+        name = "stake_guard",
+        description = "The stake guard account"
+    )]
+    TallySupport,
+    /// Revoke a guarded pending feature that lacks supporting stake.
+    #[account(
+        0,
+        writable,
+        name = "feature",
+        description = "The feature account to revoke"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "stake_guard",
+        description = "The feature's stake guard account"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "incinerator",
+        description = "The incinerator account"
+    )]
+    #[account(
+        3,
+        name = "config",
+        description = "The program config account"
+    )]
+    #[account(
+        4,
+        writable,
+        name = "audit_log",
+        description = "The audit log account"
+    )]
+    RevokeUnsupportedActivation,
+    /// Initialize the program config.
+    #[account(
+        0,
+        writable,
+        name = "config",
+        description = "The program config account"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "upgrade_authority",
+        description = "The program's upgrade authority"
+    )]
+    #[account(
+        2,
+        name = "program_data",
+        description = "The program's data account"
+    )]
+    #[account(
+        3,
+        writable,
+        signer,
+        name = "payer",
+        description = "The account paying for the config account"
+    )]
+    #[account(
+        4,
+        name = "system_program",
+        description = "The system program"
+    )]
+    #[account(
+        5,
+        writable,
+        name = "audit_log",
+        description = "The audit log account"
+    )]
+    InitializeConfig(ConfigArgs),
+    /// Replace the program config.
+    #[account(
+        0,
+        writable,
+        name = "config",
+        description = "The program config account"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "config_authority",
+        description = "The config's governance authority"
+    )]
+    #[account(
+        2,
+        writable,
+        name = "audit_log",
+        description = "The audit log account"
+    )]
+    UpdateConfig(ConfigArgs),
+    /// Queue a feature to activate no earlier than a given epoch.
+    #[account(
+        0,
+        writable,
+        signer,
+        name = "feature",
+        description = "The feature account to schedule"
+    )]
+    #[account(
+        1,
+        writable,
+        signer,
+        name = "payer",
+        description = "The account paying for the feature account"
+    )]
+    #[account(
+        2,
+        name = "system_program",
+        description = "The system program"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "audit_log",
+        description = "The audit log account"
+    )]
+    ScheduleActivation {
+        /// The earliest epoch in which the feature may activate.
+        not_before_epoch: u64,
+    },
+    /// Release a scheduled activation into a pending feature.
+    #[account(
+        0,
+        writable,
+        name = "feature",
+        description = "The scheduled feature account"
+    )]
+    ReleaseScheduledActivation,
+    /// Create the audit log.
+    #[account(
+        0,
+        writable,
+        name = "audit_log",
+        description = "The audit log account"
+    )]
+    #[account(
+        1,
+        writable,
+        signer,
+        name = "payer",
+        description = "The account paying for the audit log account"
+    )]
+    #[account(
+        2,
+        name = "system_program",
+        description = "The system program"
+    )]
+    InitializeAuditLog {
+        /// The number of entries the log holds before wrapping.
+        capacity: u32,
+    },
+    /// Grow the audit log.
+    #[account(
+        0,
+        writable,
+        name = "audit_log",
+        description = "The audit log account"
+    )]
+    #[account(
+        1,
+        writable,
+        signer,
+        name = "payer",
+        description = "The account paying for the added room"
+    )]
+    #[account(
+        2,
+        name = "system_program",
+        description = "The system program"
+    )]
+    ExtendAuditLog {
+        /// The number of entries to add room for.
+        additional_entries: u32,
+    },
+    /// Create a bundle of features that are queued and revoked together.
+    #[account(
+        0,
+        writable,
+        name = "bundle",
+        description = "The bundle account"
+    )]
+    #[account(
+        1,
+        signer,
+        name = "authority",
+        description = "The bundle authority"
+    )]
+    #[account(
+        2,
+        writable,
+        signer,
+        name = "payer",
+        description = "The account paying for the bundle account"
+    )]
+    #[account(
+        3,
+        name = "system_program",
+        description = "The system program"
+    )]
+    InitializeBundle {
+        /// The bundle name, as UTF-8 padded with zeros.
+        name: [u8; 32],
+    },
+    /// Queue every feature in a bundle for activation.
+    #[account(
+        0,
+        name = "bundle",
+        description = "The bundle account"
+    )]
+    #[account(
+        1,
+        writable,
+        signer,
+        name = "payer",
+        description = "The account paying for the feature accounts"
+    )]
+    #[account(
+        2,
+        name = "system_program",
+        description = "The system program"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "audit_log",
+        description = "The audit log account"
+    )]
+    QueueBundle,
+    /// Revoke the pending activation of every feature in a bundle.
+    #[account(
+        0,
+        name = "bundle",
+        description = "The bundle account"
+    )]
+    #[account(
+        1,
+        writable,
+        name = "incinerator",
+        description = "The incinerator account"
+    )]
+    #[account(
+        2,
+        name = "system_program",
+        description = "The system program"
+    )]
+    #[account(
+        3,
+        writable,
+        name = "audit_log",
+        description = "The audit log account"
+    )]
+    RevokeBundle,
+}
+
+/// A validator's signal that it runs software supporting a pending feature.
+#[derive(ShankAccount)]
+pub struct SupportSignal {
+    /// `SUPPORT_SIGNAL_TAG`.
+    pub discriminator: u8,
+    /// The feature the validator supports.
+    pub feature_id: Address,
+    /// The signaling validator's vote account.
+    pub vote_account: Address,
+    /// Major version of the validator software.
+    pub major: u16,
+    /// Minor version of the validator software.
+    pub minor: u16,
+    /// Patch version of the validator software.
+    pub patch: u16,
+    /// The slot of the most recent signal.
+    pub signaled_at: u64,
+    /// The epoch in which the validator's stake was last tallied, or
+    /// `u64::MAX` if never.
+    pub tallied_epoch: u64,
+}
+
+/// An opt-in rule that lets anyone revoke an unsupported pending feature.
+#[derive(ShankAccount)]
+pub struct StakeGuard {
+    /// `STAKE_GUARD_TAG`.
+    pub discriminator: u8,
+    /// The guarded feature.
+    pub feature_id: Address,
+    /// The share of the total epoch stake that must support the feature, in
+    /// basis points.
+    pub threshold_bps: u16,
+    /// The epoch of the most recent tally.
+    pub epoch: u64,
+    /// The supporting stake tallied in [`StakeGuard::epoch`].
+    pub supporting_stake: u64,
+}
+
+/// Program-wide tunables.
+#[derive(ShankAccount)]
+pub struct Config {
+    /// `CONFIG_TAG`.
+    pub discriminator: u8,
+    /// The governance authority allowed to update the config.
+    pub authority: Address,
+    /// The number of slots at the end of an epoch during which anyone may
+    /// revoke a guarded feature that lacks supporting stake.
+    pub revoke_guard_window: u64,
+    /// What happens to features revoked by their stake guard, as a
+    /// `TombstonePolicy` discriminant.
+    pub tombstone_policy: u8,
+    /// Accounts that may receive the lamports of a revoked feature instead
+    /// of the incinerator. Unused entries are the default address.
+    // Shank requires a literal length.
+    pub refund_recipients: [Address; 4],
+}
+
+/// The config carried by `InitializeConfig` and `UpdateConfig`, which is the
+/// config account without its discriminator.
+#[derive(ShankType)]
+pub struct ConfigArgs {
+    /// The governance authority allowed to update the config.
+    pub authority: Address,
+    /// The revoke guard window, in slots.
+    pub revoke_guard_window: u64,
+    /// A `TombstonePolicy` discriminant.
+    pub tombstone_policy: u8,
+    /// Allowed refund recipients.
+    pub refund_recipients: [Address; 4],
+}
+
+/// A named group of features that are queued and revoked together.
+#[derive(ShankAccount)]
+pub struct Bundle {
+    /// `BUNDLE_TAG`.
+    pub discriminator: u8,
+    /// The account that created the bundle.
+    pub authority: Address,
+    /// The bundle name, as UTF-8 padded with zeros.
+    pub name: [u8; 32],
+    /// The member feature IDs, in the order their accounts are passed to
+    /// `QueueBundle` and `RevokeBundle`. Unused entries are the default
+    /// address.
+    // Shank requires a literal length.
+    pub members: [Address; 8],
+}
+
+/// Program error types.
+// Note: Shank does not export the type when we use `spl_program_error`.
+#[derive(Error, Debug)]
+#[repr(u32)]
+pub enum FeatureGateError {
+    /// Feature already activated
+    #[error("Feature already activated")]
+    FeatureAlreadyActivated,
+    /// Signer is not an authority of the vote account
+    #[error("Signer is not an authority of the vote account")]
+    InvalidVoteAuthority,
+    /// Feature has sufficient supporting stake
+    #[error("Feature has sufficient supporting stake")]
+    FeatureSufficientlySupported,
+    /// Stake guard revoke window is not open
+    #[error("Stake guard revoke window is not open")]
+    RevokeWindowClosed,
+    /// Recipient is not allowed to receive refunds
+    #[error("Recipient is not allowed to receive refunds")]
+    InvalidRefundRecipient,
+    /// Scheduled activation cannot be released yet
+    #[error("Scheduled activation cannot be released yet")]
+    ActivationNotDue,
+    /// Account does not match the bundle member
+    #[error("Account does not match the bundle member")]
+    InvalidBundleMember,
+    /// Config is invalid
+    #[error("Config is invalid")]
+    InvalidConfig,
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{
+            error::FeatureGateError as InterfaceError,
+            instruction::{self, FeatureGateInstruction as InterfaceInstruction},
+            state::Config as InterfaceConfig,
+        },
+        serde_json::Value,
+        solana_instruction::Instruction,
+        solana_program_error::ToStr,
+    };
+
+    /// `idl.json`, as generated from this module.
+    fn idl() -> Value {
+        serde_json::from_str(include_str!("../../idl.json")).unwrap()
+    }
+
+    /// One instruction per interface variant, in variant order, with no
+    /// variable-length accounts.
+    fn interface_instructions() -> Vec<Instruction> {
+        let address = Address::new_unique();
+        let config = InterfaceConfig::default();
+        vec![
+            instruction::revoke_pending_activation(&address),
+            instruction::signal_support(&address, &address, &address, &address, 1, 2, 3),
+            instruction::initialize_stake_guard(&address, &address, 5_000),
+            instruction::tally_support(&address, &[]),
+            instruction::revoke_unsupported_activation(&address),
+            instruction::initialize_config(&address, &address, config.clone()),
+            instruction::update_config(&address, config),
+            instruction::schedule_activation(&address, &address, 1),
+            instruction::release_scheduled_activation(&address),
+            instruction::initialize_audit_log(&address, 1),
+            instruction::extend_audit_log(&address, 1),
+            instruction::initialize_bundle(&address, &address, &[0; 32], &[]),
+            instruction::queue_bundle(&address, &address, &[]),
+            instruction::revoke_bundle(&address, &[]),
+        ]
+    }
+
+    /// The name of the interface variant `data` decodes to.
+    fn variant_name(data: &[u8]) -> String {
+        let debug = format!("{:?}", InterfaceInstruction::unpack(data).unwrap());
+        debug
+            .split(|c: char| !c.is_alphanumeric())
+            .next()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn instructions_match_interface() {
+        let idl = idl();
+        let idl_instructions = idl["instructions"].as_array().unwrap();
+        let interface_instructions = interface_instructions();
+        assert_eq!(idl_instructions.len(), interface_instructions.len());
+
+        for (idl_instruction, instruction) in idl_instructions.iter().zip(interface_instructions) {
+            let name = variant_name(&instruction.data);
+            assert_eq!(idl_instruction["name"], name.as_str());
+            assert_eq!(
+                idl_instruction["discriminant"]["value"], instruction.data[0],
+                "{name} discriminant"
+            );
+            assert_eq!(
+                idl_instruction["accounts"].as_array().unwrap().len(),
+                instruction.accounts.len(),
+                "{name} accounts"
+            );
+        }
+    }
+
+    #[test]
+    fn errors_match_interface() {
+        let idl = idl();
+        let idl_errors = idl["errors"].as_array().unwrap();
+        let errors = [
+            FeatureGateError::FeatureAlreadyActivated,
+            FeatureGateError::InvalidVoteAuthority,
+            FeatureGateError::FeatureSufficientlySupported,
+            FeatureGateError::RevokeWindowClosed,
+            FeatureGateError::InvalidRefundRecipient,
+            FeatureGateError::ActivationNotDue,
+            FeatureGateError::InvalidBundleMember,
+            FeatureGateError::InvalidConfig,
+        ];
+        assert_eq!(idl_errors.len(), errors.len());
+        assert!(InterfaceError::try_from(errors.len() as u32).is_err());
+
+        for (idl_error, error) in idl_errors.iter().zip(errors) {
+            let message = error.to_string();
+            let code = error as u32;
+            let interface_error = InterfaceError::try_from(code).unwrap();
+            assert_eq!(idl_error["code"], code);
+            assert_eq!(idl_error["name"], format!("{interface_error:?}"));
+            assert_eq!(idl_error["msg"], message.as_str());
+            assert_eq!(interface_error.to_str(), message);
+        }
+    }
+}
@@ -1,12 +1,14 @@
 //! Feature Gate program
 
 #![deny(missing_docs)]
-#![cfg_attr(not(test), forbid(unsafe_code))]
+#![cfg_attr(not(test), deny(unsafe_code))]
 
 #[cfg(target_os = "solana")]
 mod entrypoint;
-pub mod error;
-pub mod instruction;
+mod epoch_stake;
+#[cfg(feature = "idl")]
+#[allow(dead_code)]
+mod idl;
 pub mod processor;
 
-solana_address::declare_id!("Feature111111111111111111111111111111111111");
+pub use solana_feature_gate_program_interface::{check_id, error, id, instruction, state, ID};
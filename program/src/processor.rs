@@ -1,55 +1,1081 @@
 //! Program state processor
 
 use {
-    crate::{error::FeatureGateError, instruction::FeatureGateInstruction},
-    solana_account_info::{next_account_info, AccountInfo},
+    crate::{
+        epoch_stake::{get_epoch_stake_for_vote_account, get_epoch_total_stake},
+        error::FeatureGateError,
+        instruction::FeatureGateInstruction,
+        state::{
+            find_bundle_address, find_stake_guard_address, find_support_signal_address,
+            AuditAction, AuditEntry, AuditLog, Bundle, Config, ScheduledActivation, StakeGuard,
+            SupportSignal, Tombstone, TombstonePolicy, AUDIT_LOG_ADDRESS, AUDIT_LOG_BUMP_SEED,
+            AUDIT_LOG_SEED, BUNDLE_SEED, CONFIG_ADDRESS, CONFIG_BUMP_SEED, CONFIG_SEED,
+            MAX_BASIS_POINTS, STAKE_GUARD_SEED, SUPPORT_SIGNAL_SEED,
+        },
+    },
+    solana_account_info::{next_account_info, AccountInfo, MAX_PERMITTED_DATA_INCREASE},
     solana_address::Address,
-    solana_cpi::invoke,
+    solana_cpi::{invoke, invoke_signed},
     solana_feature_gate_interface::Feature,
     solana_msg::msg,
     solana_program_error::{ProgramError, ProgramResult},
-    solana_sdk_ids::incinerator,
-    solana_system_interface::{instruction as system_instruction, program as system_program},
+    solana_sdk_ids::{bpf_loader_upgradeable, incinerator, vote},
+    solana_system_interface::{
+        instruction as system_instruction, program as system_program, MAX_PERMITTED_DATA_LENGTH,
+    },
+    solana_sysvar::{clock::Clock, epoch_schedule::EpochSchedule, rent::Rent, Sysvar},
 };
 
 /// Processes a [`RevokePendingActivation`](enum.FeatureGateInstruction.html)
 /// instruction.
 pub fn process_revoke_pending_activation(
-    _program_id: &Address,
+    program_id: &Address,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
     let account_info_iter = &mut accounts.iter();
 
     let feature_info = next_account_info(account_info_iter)?;
-    let incinerator_info = next_account_info(account_info_iter)?;
+    let destination_info = next_account_info(account_info_iter)?;
     let _system_program_info = next_account_info(account_info_iter)?;
 
+    // The optional audit log and config are told apart by address rather
+    // than position, so either may be omitted. Callers predating the audit
+    // log pass only the first three accounts.
+    let (mut config_info, mut audit_log_info) = (None, None);
+    for account_info in account_info_iter {
+        if account_info.key == &CONFIG_ADDRESS {
+            config_info = Some(account_info);
+        } else if account_info.key == &AUDIT_LOG_ADDRESS {
+            audit_log_info = Some(account_info);
+        } else if account_info.key != program_id {
+            // The program ID stands in for an omitted optional account.
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
     if !feature_info.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    // This will also check the program ID
-    if Feature::from_account_info(feature_info)?
-        .activated_at
-        .is_some()
-    {
+    if is_activated(program_id, feature_info)? {
+        return Err(FeatureGateError::FeatureAlreadyActivated.into());
+    }
+
+    if destination_info.key != &incinerator::id() {
+        let config = match config_info {
+            Some(config_info) => load_config(program_id, config_info)?,
+            None => Config::default(),
+        };
+        if !config.is_refund_recipient(destination_info.key) {
+            return Err(FeatureGateError::InvalidRefundRecipient.into());
+        }
+    }
+
+    // Record while the feature account can still pay to grow the log.
+    if let Some(audit_log_info) = audit_log_info {
+        record_audit_entry(
+            program_id,
+            audit_log_info,
+            Some(RentSource::ProgramAccount(feature_info)),
+            AuditAction::RevokePendingActivation,
+            feature_info.key,
+            feature_info.key,
+        )?;
+    }
+
+    close_feature_account(feature_info, destination_info)
+}
+
+/// Processes a [`SignalSupport`](enum.FeatureGateInstruction.html)
+/// instruction.
+pub fn process_signal_support(
+    program_id: &Address,
+    accounts: &[AccountInfo],
+    major: u16,
+    minor: u16,
+    patch: u16,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let support_signal_info = next_account_info(account_info_iter)?;
+    let feature_info = next_account_info(account_info_iter)?;
+    let vote_account_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if is_activated(program_id, feature_info)? {
+        return Err(FeatureGateError::FeatureAlreadyActivated.into());
+    }
+
+    if vote_account_info.owner != &vote::id() {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let (node_pubkey, authorized_withdrawer) =
+        vote_authorities(&vote_account_info.try_borrow_data()?)?;
+    if authority_info.key != &node_pubkey && authority_info.key != &authorized_withdrawer {
+        return Err(FeatureGateError::InvalidVoteAuthority.into());
+    }
+
+    let (support_signal_address, bump_seed) =
+        find_support_signal_address(feature_info.key, vote_account_info.key);
+    if support_signal_info.key != &support_signal_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // A renewed signal keeps its tally, so it is still counted once per
+    // epoch.
+    let tallied_epoch = if support_signal_info.owner == program_id {
+        SupportSignal::unpack(&support_signal_info.try_borrow_data()?)?.tallied_epoch
+    } else {
+        create_pda_account(
+            program_id,
+            payer_info,
+            support_signal_info,
+            system_program_info,
+            SupportSignal::LEN,
+            &[
+                SUPPORT_SIGNAL_SEED,
+                feature_info.key.as_ref(),
+                vote_account_info.key.as_ref(),
+                &[bump_seed],
+            ],
+        )?;
+        None
+    };
+
+    let signal = SupportSignal {
+        feature_id: *feature_info.key,
+        vote_account: *vote_account_info.key,
+        major,
+        minor,
+        patch,
+        signaled_at: Clock::get()?.slot,
+        tallied_epoch,
+    };
+    support_signal_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&signal.pack());
+
+    Ok(())
+}
+
+/// Processes an
+/// [`InitializeStakeGuard`](enum.FeatureGateInstruction.html) instruction.
+pub fn process_initialize_stake_guard(
+    program_id: &Address,
+    accounts: &[AccountInfo],
+    threshold_bps: u16,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let stake_guard_info = next_account_info(account_info_iter)?;
+    let feature_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !feature_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if is_activated(program_id, feature_info)? {
+        return Err(FeatureGateError::FeatureAlreadyActivated.into());
+    }
+
+    if threshold_bps == 0 || threshold_bps > MAX_BASIS_POINTS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (stake_guard_address, bump_seed) = find_stake_guard_address(feature_info.key);
+    if stake_guard_info.key != &stake_guard_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if stake_guard_info.owner == program_id {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    create_pda_account(
+        program_id,
+        payer_info,
+        stake_guard_info,
+        system_program_info,
+        StakeGuard::LEN,
+        &[STAKE_GUARD_SEED, feature_info.key.as_ref(), &[bump_seed]],
+    )?;
+
+    let guard = StakeGuard {
+        feature_id: *feature_info.key,
+        threshold_bps,
+        epoch: Clock::get()?.epoch,
+        supporting_stake: 0,
+    };
+    stake_guard_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&guard.pack());
+
+    Ok(())
+}
+
+/// Processes a [`TallySupport`](enum.FeatureGateInstruction.html)
+/// instruction.
+pub fn process_tally_support(program_id: &Address, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let stake_guard_info = next_account_info(account_info_iter)?;
+
+    if stake_guard_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let mut guard = StakeGuard::unpack(&stake_guard_info.try_borrow_data()?)?;
+
+    let epoch = Clock::get()?.epoch;
+
+    // Strictly ascending vote accounts ensure each one is counted once per
+    // instruction, and each signal records the epoch it was tallied in so it
+    // is counted once per epoch across instructions.
+    let mut previous_vote_account = None;
+    let mut supporting_stake = guard.supporting_stake_in(epoch);
+    for support_signal_info in account_info_iter {
+        if support_signal_info.owner != program_id {
+            return Err(ProgramError::InvalidAccountOwner);
+        }
+        let mut signal = SupportSignal::unpack(&support_signal_info.try_borrow_data()?)?;
+        if signal.feature_id != guard.feature_id
+            || previous_vote_account.is_some_and(|previous| previous >= signal.vote_account)
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+        previous_vote_account = Some(signal.vote_account);
+        if signal.is_tallied_in(epoch) {
+            continue;
+        }
+        supporting_stake =
+            supporting_stake.saturating_add(get_epoch_stake_for_vote_account(&signal.vote_account));
+        signal.tallied_epoch = Some(epoch);
+        support_signal_info
+            .try_borrow_mut_data()?
+            .copy_from_slice(&signal.pack());
+    }
+
+    guard.supporting_stake = supporting_stake;
+    guard.epoch = epoch;
+    stake_guard_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&guard.pack());
+
+    Ok(())
+}
+
+/// Processes a
+/// [`RevokeUnsupportedActivation`](enum.FeatureGateInstruction.html)
+/// instruction.
+pub fn process_revoke_unsupported_activation(
+    program_id: &Address,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let feature_info = next_account_info(account_info_iter)?;
+    let stake_guard_info = next_account_info(account_info_iter)?;
+    let incinerator_info = next_account_info(account_info_iter)?;
+    let config_info = next_account_info(account_info_iter)?;
+    let audit_log_info = next_account_info(account_info_iter)?;
+
+    if is_activated(program_id, feature_info)? {
         return Err(FeatureGateError::FeatureAlreadyActivated.into());
     }
 
+    let config = load_config(program_id, config_info)?;
+
+    if stake_guard_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let guard = StakeGuard::unpack(&stake_guard_info.try_borrow_data()?)?;
+    if &guard.feature_id != feature_info.key {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if incinerator_info.key != &incinerator::id() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let clock = Clock::get()?;
+    let last_slot_in_epoch = EpochSchedule::get()?.get_last_slot_in_epoch(clock.epoch);
+    if last_slot_in_epoch.saturating_sub(clock.slot) >= config.revoke_guard_window {
+        return Err(FeatureGateError::RevokeWindowClosed.into());
+    }
+
+    if guard.is_met(
+        guard.supporting_stake_in(clock.epoch),
+        get_epoch_total_stake(),
+    ) {
+        return Err(FeatureGateError::FeatureSufficientlySupported.into());
+    }
+
+    // Anyone may revoke an unsupported feature, so no signer is recorded.
+    // Record while the stake guard can still pay to grow the log.
+    record_audit_entry(
+        program_id,
+        audit_log_info,
+        Some(RentSource::ProgramAccount(stake_guard_info)),
+        AuditAction::RevokeUnsupportedActivation,
+        feature_info.key,
+        &Address::default(),
+    )?;
+
+    // The program owns both accounts, so it can burn their lamports directly.
+    move_lamports(
+        stake_guard_info,
+        incinerator_info,
+        stake_guard_info.lamports(),
+    )?;
+    stake_guard_info.resize(0)?;
+    stake_guard_info.assign(&system_program::id());
+
+    match config.tombstone_policy {
+        TombstonePolicy::Close => {
+            move_lamports(feature_info, incinerator_info, feature_info.lamports())?;
+            // Clear data and reassign.
+            feature_info.resize(0)?;
+            feature_info.assign(&system_program::id());
+        }
+        TombstonePolicy::Tombstone => {
+            let rent_exempt_lamports = Rent::get()?.minimum_balance(Tombstone::LEN);
+            move_lamports(
+                feature_info,
+                incinerator_info,
+                feature_info.lamports().saturating_sub(rent_exempt_lamports),
+            )?;
+            feature_info.resize(Tombstone::LEN)?;
+            feature_info.try_borrow_mut_data()?.copy_from_slice(
+                &Tombstone {
+                    revoked_at: clock.slot,
+                }
+                .pack(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Processes an [`InitializeConfig`](enum.FeatureGateInstruction.html)
+/// instruction.
+pub fn process_initialize_config(
+    program_id: &Address,
+    accounts: &[AccountInfo],
+    config: Config,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let config_info = next_account_info(account_info_iter)?;
+    let upgrade_authority_info = next_account_info(account_info_iter)?;
+    let program_data_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let audit_log_info = next_account_info(account_info_iter)?;
+
+    if !upgrade_authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    validate_config(&config)?;
+
+    let (program_data_address, _) =
+        Address::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id());
+    if program_data_info.key != &program_data_address
+        || program_data_info.owner != &bpf_loader_upgradeable::id()
+    {
+        return Err(ProgramError::InvalidArgument);
+    }
+    if upgrade_authority(&program_data_info.try_borrow_data()?)?
+        != Some(*upgrade_authority_info.key)
+    {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    if config_info.key != &CONFIG_ADDRESS {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if config_info.owner == program_id {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    create_pda_account(
+        program_id,
+        payer_info,
+        config_info,
+        system_program_info,
+        Config::LEN,
+        &[CONFIG_SEED, &[CONFIG_BUMP_SEED]],
+    )?;
+
+    config_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&config.pack());
+
+    record_audit_entry(
+        program_id,
+        audit_log_info,
+        None,
+        AuditAction::InitializeConfig,
+        &config.authority,
+        upgrade_authority_info.key,
+    )
+}
+
+/// Processes an [`UpdateConfig`](enum.FeatureGateInstruction.html)
+/// instruction.
+pub fn process_update_config(
+    program_id: &Address,
+    accounts: &[AccountInfo],
+    config: Config,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let config_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let audit_log_info = next_account_info(account_info_iter)?;
+
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if config_info.owner != program_id {
+        return Err(ProgramError::UninitializedAccount);
+    }
+    let current = load_config(program_id, config_info)?;
+    if authority_info.key != &current.authority {
+        return Err(ProgramError::IncorrectAuthority);
+    }
+
+    validate_config(&config)?;
+
+    config_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&config.pack());
+
+    record_audit_entry(
+        program_id,
+        audit_log_info,
+        None,
+        AuditAction::UpdateConfig,
+        &config.authority,
+        authority_info.key,
+    )
+}
+
+/// Processes a [`ScheduleActivation`](enum.FeatureGateInstruction.html)
+/// instruction.
+pub fn process_schedule_activation(
+    program_id: &Address,
+    accounts: &[AccountInfo],
+    not_before_epoch: u64,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let feature_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let audit_log_info = next_account_info(account_info_iter)?;
+
+    if !feature_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Queued, scheduled, activated and tombstoned features are all owned by
+    // the program.
+    if feature_info.owner == program_id {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // The feature keypair signed the transaction, so no seeds are needed.
+    create_program_account(
+        program_id,
+        payer_info,
+        feature_info,
+        system_program_info,
+        ScheduledActivation::LEN,
+        &[],
+    )?;
+
+    feature_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&ScheduledActivation { not_before_epoch }.pack());
+
+    record_audit_entry(
+        program_id,
+        audit_log_info,
+        Some(RentSource::Payer {
+            payer_info,
+            system_program_info,
+        }),
+        AuditAction::ScheduleActivation,
+        feature_info.key,
+        feature_info.key,
+    )
+}
+
+/// Processes a
+/// [`ReleaseScheduledActivation`](enum.FeatureGateInstruction.html)
+/// instruction.
+pub fn process_release_scheduled_activation(
+    program_id: &Address,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let feature_info = next_account_info(account_info_iter)?;
+
+    if feature_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let scheduled = ScheduledActivation::unpack(&feature_info.try_borrow_data()?)?;
+    if !scheduled.is_releasable_in(Clock::get()?.epoch) {
+        return Err(FeatureGateError::ActivationNotDue.into());
+    }
+
+    // A zeroed feature account is a pending feature, exactly as left by
+    // queueing with the System program.
+    feature_info.try_borrow_mut_data()?.fill(0);
+
+    Ok(())
+}
+
+/// Processes an [`InitializeAuditLog`](enum.FeatureGateInstruction.html)
+/// instruction.
+pub fn process_initialize_audit_log(
+    program_id: &Address,
+    accounts: &[AccountInfo],
+    capacity: u32,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let audit_log_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if audit_log_info.key != &AUDIT_LOG_ADDRESS {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if audit_log_info.owner == program_id {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    // Accounts created through CPI are subject to the same size limit as
+    // reallocations.
+    let space = AuditLog::space(capacity as usize);
+    if capacity == 0 || space > MAX_PERMITTED_DATA_INCREASE {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    create_pda_account(
+        program_id,
+        payer_info,
+        audit_log_info,
+        system_program_info,
+        space,
+        &[AUDIT_LOG_SEED, &[AUDIT_LOG_BUMP_SEED]],
+    )?;
+
+    // The entries following the header are zeroed and unused.
+    audit_log_info.try_borrow_mut_data()?[..AuditLog::LEN]
+        .copy_from_slice(&AuditLog::default().pack());
+
+    Ok(())
+}
+
+/// Processes an [`ExtendAuditLog`](enum.FeatureGateInstruction.html)
+/// instruction.
+pub fn process_extend_audit_log(
+    program_id: &Address,
+    accounts: &[AccountInfo],
+    additional_entries: u32,
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let audit_log_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if audit_log_info.key != &AUDIT_LOG_ADDRESS {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if audit_log_info.owner != program_id {
+        return Err(ProgramError::UninitializedAccount);
+    }
+
+    let increase = (additional_entries as usize).saturating_mul(AuditEntry::LEN);
+    if additional_entries == 0 || increase > MAX_PERMITTED_DATA_INCREASE {
+        return Err(ProgramError::InvalidArgument);
+    }
+    let old_len = audit_log_info.data_len();
+    let new_len = old_len.saturating_add(increase);
+
+    let shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(audit_log_info.lamports());
+    if shortfall > 0 {
+        RentSource::Payer {
+            payer_info,
+            system_program_info,
+        }
+        .pay(audit_log_info, shortfall)?;
+    }
+
+    audit_log_info.resize(new_len)?;
+    AuditLog::extend(&mut audit_log_info.try_borrow_mut_data()?, old_len)
+}
+
+/// Processes an [`InitializeBundle`](enum.FeatureGateInstruction.html)
+/// instruction.
+pub fn process_initialize_bundle(
+    program_id: &Address,
+    accounts: &[AccountInfo],
+    name: [u8; 32],
+) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let bundle_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let members = account_info_iter
+        .map(|member_info| *member_info.key)
+        .collect::<Vec<_>>();
+
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let bundle = Bundle::new(*authority_info.key, name, &members)?;
+
+    let (bundle_address, bump_seed) = find_bundle_address(authority_info.key, &name);
+    if bundle_info.key != &bundle_address {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if bundle_info.owner == program_id {
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    create_pda_account(
+        program_id,
+        payer_info,
+        bundle_info,
+        system_program_info,
+        Bundle::LEN,
+        &[
+            BUNDLE_SEED,
+            authority_info.key.as_ref(),
+            &name,
+            &[bump_seed],
+        ],
+    )?;
+
+    bundle_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&bundle.pack());
+
+    Ok(())
+}
+
+/// Processes a [`QueueBundle`](enum.FeatureGateInstruction.html)
+/// instruction.
+pub fn process_queue_bundle(program_id: &Address, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let bundle_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let audit_log_info = next_account_info(account_info_iter)?;
+    let member_infos = next_bundle_members(program_id, bundle_info, account_info_iter)?;
+
+    // Check every member before queueing any, so an activated member is
+    // reported as such.
+    for member_info in &member_infos {
+        if !member_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if member_info.owner == program_id {
+            if matches!(is_activated(program_id, member_info), Ok(true)) {
+                return Err(FeatureGateError::FeatureAlreadyActivated.into());
+            }
+            return Err(ProgramError::AccountAlreadyInitialized);
+        }
+    }
+
+    for member_info in &member_infos {
+        // The member keypair signed the transaction, so no seeds are needed.
+        // The new account is zeroed, which is a pending feature.
+        create_program_account(
+            program_id,
+            payer_info,
+            member_info,
+            system_program_info,
+            Feature::size_of(),
+            &[],
+        )?;
+        record_audit_entry(
+            program_id,
+            audit_log_info,
+            Some(RentSource::Payer {
+                payer_info,
+                system_program_info,
+            }),
+            AuditAction::QueueBundleMember,
+            member_info.key,
+            member_info.key,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Processes a [`RevokeBundle`](enum.FeatureGateInstruction.html)
+/// instruction.
+pub fn process_revoke_bundle(program_id: &Address, accounts: &[AccountInfo]) -> ProgramResult {
+    let account_info_iter = &mut accounts.iter();
+
+    let bundle_info = next_account_info(account_info_iter)?;
+    let incinerator_info = next_account_info(account_info_iter)?;
+    let _system_program_info = next_account_info(account_info_iter)?;
+    let audit_log_info = next_account_info(account_info_iter)?;
+    let member_infos = next_bundle_members(program_id, bundle_info, account_info_iter)?;
+
+    if incinerator_info.key != &incinerator::id() {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    for member_info in &member_infos {
+        if !member_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        if is_activated(program_id, member_info)? {
+            return Err(FeatureGateError::FeatureAlreadyActivated.into());
+        }
+    }
+
+    for member_info in &member_infos {
+        record_audit_entry(
+            program_id,
+            audit_log_info,
+            Some(RentSource::ProgramAccount(member_info)),
+            AuditAction::RevokePendingActivation,
+            member_info.key,
+            member_info.key,
+        )?;
+        close_feature_account(member_info, incinerator_info)?;
+    }
+
+    Ok(())
+}
+
+/// Loads the bundle and takes its member feature accounts, in bundle order,
+/// from `account_info_iter`.
+fn next_bundle_members<'a, 'b, I: Iterator<Item = &'a AccountInfo<'b>>>(
+    program_id: &Address,
+    bundle_info: &AccountInfo,
+    account_info_iter: &mut I,
+) -> Result<Vec<&'a AccountInfo<'b>>, ProgramError> {
+    if bundle_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    let bundle = Bundle::unpack(&bundle_info.try_borrow_data()?)?;
+    if bundle_info.key != &find_bundle_address(&bundle.authority, &bundle.name).0 {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    bundle
+        .members()
+        .map(|member| {
+            let member_info = next_account_info(account_info_iter)?;
+            if member_info.key != member {
+                return Err(FeatureGateError::InvalidBundleMember.into());
+            }
+            Ok(member_info)
+        })
+        .collect()
+}
+
+/// Closes a pending feature account, sending its lamports to
+/// `destination_info`.
+///
+/// The program owns the feature account, so it moves the lamports directly.
+/// A System program transfer would fail once the feature account has paid to
+/// grow the audit log, since the runtime checks that the accounts balance
+/// before the CPI and the audit log is not part of it.
+fn close_feature_account(
+    feature_info: &AccountInfo,
+    destination_info: &AccountInfo,
+) -> ProgramResult {
+    // Burn or refund the lamports.
+    move_lamports(feature_info, destination_info, feature_info.lamports())?;
+
     // Clear data and reassign.
     feature_info.resize(0)?;
     feature_info.assign(&system_program::id());
+    Ok(())
+}
 
-    // Burn the lamports.
-    let lamports = feature_info.lamports();
-    invoke(
-        &system_instruction::transfer(feature_info.key, &incinerator::id(), lamports),
-        &[feature_info.clone(), incinerator_info.clone()],
-    )?;
+/// An account paying to grow the audit log.
+enum RentSource<'b, 'a> {
+    /// A signer, debited through the System program.
+    Payer {
+        payer_info: &'b AccountInfo<'a>,
+        system_program_info: &'b AccountInfo<'a>,
+    },
+    /// An account owned by the program, debited directly.
+    ProgramAccount(&'b AccountInfo<'a>),
+}
+
+impl<'a> RentSource<'_, 'a> {
+    /// Moves `lamports` from the source to `recipient_info`.
+    fn pay(&self, recipient_info: &AccountInfo<'a>, lamports: u64) -> ProgramResult {
+        match self {
+            Self::Payer {
+                payer_info,
+                system_program_info,
+            } => invoke(
+                &system_instruction::transfer(payer_info.key, recipient_info.key, lamports),
+                &[
+                    (*payer_info).clone(),
+                    recipient_info.clone(),
+                    (*system_program_info).clone(),
+                ],
+            ),
+            Self::ProgramAccount(account_info) => {
+                move_lamports(account_info, recipient_info, lamports)
+            }
+        }
+    }
+}
+
+/// Records an action in the audit log. Nothing is recorded until the audit
+/// log has been initialized.
+///
+/// Actions that need no authority pass a `rent_source`: once the log is
+/// full, it grows by one entry at the source's expense rather than
+/// overwriting the oldest entry, so such actions cannot push earlier ones out
+/// of the log. The log only wraps for them once it reaches the maximum
+/// account size.
+fn record_audit_entry<'a>(
+    program_id: &Address,
+    audit_log_info: &AccountInfo<'a>,
+    rent_source: Option<RentSource<'_, 'a>>,
+    action: AuditAction,
+    subject: &Address,
+    signer: &Address,
+) -> ProgramResult {
+    if audit_log_info.key != &AUDIT_LOG_ADDRESS {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if audit_log_info.owner != program_id {
+        return Ok(());
+    }
+
+    let old_len = audit_log_info.data_len();
+    let new_len = old_len.saturating_add(AuditEntry::LEN);
+    let is_full = AuditLog::unpack(&audit_log_info.try_borrow_data()?)?.is_full(old_len);
+    if let Some(rent_source) = rent_source {
+        if is_full && new_len as u64 <= MAX_PERMITTED_DATA_LENGTH {
+            let shortfall = Rent::get()?
+                .minimum_balance(new_len)
+                .saturating_sub(audit_log_info.lamports());
+            if shortfall > 0 {
+                rent_source.pay(audit_log_info, shortfall)?;
+            }
+            audit_log_info.resize(new_len)?;
+            AuditLog::extend(&mut audit_log_info.try_borrow_mut_data()?, old_len)?;
+        }
+    }
+
+    AuditLog::append(
+        &mut audit_log_info.try_borrow_mut_data()?,
+        &AuditEntry {
+            action,
+            subject: *subject,
+            signer: *signer,
+            slot: Clock::get()?.slot,
+        },
+    )
+}
+
+/// Whether the feature account holds an activated feature. Scheduled
+/// activations count as pending.
+fn is_activated(program_id: &Address, feature_info: &AccountInfo) -> Result<bool, ProgramError> {
+    if feature_info.owner == program_id
+        && ScheduledActivation::unpack(&feature_info.try_borrow_data()?).is_ok()
+    {
+        return Ok(false);
+    }
+    // This will also check the program ID
+    Ok(Feature::from_account_info(feature_info)?
+        .activated_at
+        .is_some())
+}
+
+/// Loads the program config from the account at the config address, falling
+/// back to the default config if it has not been initialized.
+fn load_config(program_id: &Address, config_info: &AccountInfo) -> Result<Config, ProgramError> {
+    if config_info.key != &CONFIG_ADDRESS {
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if config_info.owner != program_id {
+        return Ok(Config::default());
+    }
+    Config::unpack(&config_info.try_borrow_data()?)
+}
+
+/// Checks a config before it is stored: the revoke guard window must be
+/// non-zero, and the refund recipients must be distinct and must not include
+/// the incinerator, which is always allowed.
+fn validate_config(config: &Config) -> ProgramResult {
+    if config.revoke_guard_window == 0 {
+        return Err(FeatureGateError::InvalidConfig.into());
+    }
+    let recipients = &config.refund_recipients;
+    for (i, recipient) in recipients.iter().enumerate() {
+        if recipient == &Address::default() {
+            continue;
+        }
+        if recipient == &incinerator::id() || recipients[..i].contains(recipient) {
+            return Err(FeatureGateError::InvalidConfig.into());
+        }
+    }
+    Ok(())
+}
 
+/// Reads the upgrade authority from upgradeable loader program data.
+fn upgrade_authority(data: &[u8]) -> Result<Option<Address>, ProgramError> {
+    // `UpgradeableLoaderState::ProgramData` is a four-byte tag, the
+    // deployment slot and an optional authority.
+    match data.get(..45) {
+        Some([3, 0, 0, 0, _, _, _, _, _, _, _, _, 0, ..]) => Ok(None),
+        Some([3, 0, 0, 0, _, _, _, _, _, _, _, _, 1, authority @ ..]) => {
+            Ok(Some(Address::try_from(authority).unwrap()))
+        }
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// Moves `lamports` out of an account owned by the program into
+/// `recipient_info`, such as the incinerator.
+fn move_lamports(
+    account_info: &AccountInfo,
+    recipient_info: &AccountInfo,
+    lamports: u64,
+) -> ProgramResult {
+    let remaining = account_info
+        .lamports()
+        .checked_sub(lamports)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    let received = recipient_info
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **account_info.try_borrow_mut_lamports()? = remaining;
+    **recipient_info.try_borrow_mut_lamports()? = received;
     Ok(())
 }
 
+/// Reads the node identity and authorized withdrawer from vote account data.
+///
+/// Every vote state version since 1.14.11 stores both right after its
+/// four-byte version tag; older accounts are rejected.
+fn vote_authorities(data: &[u8]) -> Result<(Address, Address), ProgramError> {
+    match data.get(..68) {
+        Some([1..=3, 0, 0, 0, authorities @ ..]) => {
+            let (node_pubkey, authorized_withdrawer) = authorities.split_at(32);
+            Ok((
+                Address::try_from(node_pubkey).unwrap(),
+                Address::try_from(authorized_withdrawer).unwrap(),
+            ))
+        }
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// Creates a rent-exempt account owned by the program at a derived address,
+/// topping up any lamports the address already holds.
+fn create_pda_account<'a>(
+    program_id: &Address,
+    payer_info: &AccountInfo<'a>,
+    new_account_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    create_program_account(
+        program_id,
+        payer_info,
+        new_account_info,
+        system_program_info,
+        space,
+        &[signer_seeds],
+    )
+}
+
+/// Creates a rent-exempt account owned by the program. `new_account_info`
+/// must either sign the transaction or be a PDA derived from one of
+/// `signers_seeds`.
+fn create_program_account<'a>(
+    program_id: &Address,
+    payer_info: &AccountInfo<'a>,
+    new_account_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    space: usize,
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let required_lamports = Rent::get()?.minimum_balance(space);
+
+    if new_account_info.lamports() == 0 {
+        return invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                new_account_info.key,
+                required_lamports,
+                space as u64,
+                program_id,
+            ),
+            &[
+                payer_info.clone(),
+                new_account_info.clone(),
+                system_program_info.clone(),
+            ],
+            signers_seeds,
+        );
+    }
+
+    // Anyone can transfer lamports to the address before it is created, so
+    // `create_account` would fail.
+    let shortfall = required_lamports.saturating_sub(new_account_info.lamports());
+    if shortfall > 0 {
+        invoke(
+            &system_instruction::transfer(payer_info.key, new_account_info.key, shortfall),
+            &[
+                payer_info.clone(),
+                new_account_info.clone(),
+                system_program_info.clone(),
+            ],
+        )?;
+    }
+    invoke_signed(
+        &system_instruction::allocate(new_account_info.key, space as u64),
+        &[new_account_info.clone(), system_program_info.clone()],
+        signers_seeds,
+    )?;
+    invoke_signed(
+        &system_instruction::assign(new_account_info.key, program_id),
+        &[new_account_info.clone(), system_program_info.clone()],
+        signers_seeds,
+    )
+}
+
 /// Processes an [Instruction](enum.Instruction.html).
 pub fn process(program_id: &Address, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
     let instruction = FeatureGateInstruction::unpack(input)?;
@@ -58,5 +1084,110 @@ pub fn process(program_id: &Address, accounts: &[AccountInfo], input: &[u8]) ->
             msg!("Instruction: RevokePendingActivation");
             process_revoke_pending_activation(program_id, accounts)
         }
+        FeatureGateInstruction::SignalSupport {
+            major,
+            minor,
+            patch,
+        } => {
+            msg!("Instruction: SignalSupport");
+            process_signal_support(program_id, accounts, major, minor, patch)
+        }
+        FeatureGateInstruction::InitializeStakeGuard { threshold_bps } => {
+            msg!("Instruction: InitializeStakeGuard");
+            process_initialize_stake_guard(program_id, accounts, threshold_bps)
+        }
+        FeatureGateInstruction::TallySupport => {
+            msg!("Instruction: TallySupport");
+            process_tally_support(program_id, accounts)
+        }
+        FeatureGateInstruction::RevokeUnsupportedActivation => {
+            msg!("Instruction: RevokeUnsupportedActivation");
+            process_revoke_unsupported_activation(program_id, accounts)
+        }
+        FeatureGateInstruction::InitializeConfig(config) => {
+            msg!("Instruction: InitializeConfig");
+            process_initialize_config(program_id, accounts, config)
+        }
+        FeatureGateInstruction::UpdateConfig(config) => {
+            msg!("Instruction: UpdateConfig");
+            process_update_config(program_id, accounts, config)
+        }
+        FeatureGateInstruction::ScheduleActivation { not_before_epoch } => {
+            msg!("Instruction: ScheduleActivation");
+            process_schedule_activation(program_id, accounts, not_before_epoch)
+        }
+        FeatureGateInstruction::ReleaseScheduledActivation => {
+            msg!("Instruction: ReleaseScheduledActivation");
+            process_release_scheduled_activation(program_id, accounts)
+        }
+        FeatureGateInstruction::InitializeAuditLog { capacity } => {
+            msg!("Instruction: InitializeAuditLog");
+            process_initialize_audit_log(program_id, accounts, capacity)
+        }
+        FeatureGateInstruction::ExtendAuditLog { additional_entries } => {
+            msg!("Instruction: ExtendAuditLog");
+            process_extend_audit_log(program_id, accounts, additional_entries)
+        }
+        FeatureGateInstruction::InitializeBundle { name } => {
+            msg!("Instruction: InitializeBundle");
+            process_initialize_bundle(program_id, accounts, name)
+        }
+        FeatureGateInstruction::QueueBundle => {
+            msg!("Instruction: QueueBundle");
+            process_queue_bundle(program_id, accounts)
+        }
+        FeatureGateInstruction::RevokeBundle => {
+            msg!("Instruction: RevokeBundle");
+            process_revoke_bundle(program_id, accounts)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        solana_clock::Clock,
+        solana_vote_interface::state::{
+            VoteInit, VoteState1_14_11, VoteStateV3, VoteStateV4, VoteStateVersions,
+        },
+    };
+
+    fn vote_init() -> VoteInit {
+        VoteInit {
+            node_pubkey: Address::new_unique(),
+            authorized_voter: Address::new_unique(),
+            authorized_withdrawer: Address::new_unique(),
+            commission: 5,
+        }
+    }
+
+    #[test]
+    fn test_vote_authorities() {
+        let vote_init = vote_init();
+        let expected = Ok((vote_init.node_pubkey, vote_init.authorized_withdrawer));
+        let v3 = VoteStateV3::new(&vote_init, &Clock::default());
+
+        for versions in [
+            VoteStateVersions::new_v4(VoteStateV4::new(
+                &Address::new_unique(),
+                &vote_init,
+                &Clock::default(),
+            )),
+            VoteStateVersions::V1_14_11(Box::new(VoteState1_14_11::from(v3.clone()))),
+            VoteStateVersions::new_v3(v3),
+        ] {
+            let data = bincode::serialize(&versions).unwrap();
+            assert_eq!(vote_authorities(&data), expected);
+        }
+
+        assert_eq!(
+            vote_authorities(&[0; 68]),
+            Err(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            vote_authorities(&[3, 0, 0, 0]),
+            Err(ProgramError::InvalidAccountData)
+        );
     }
 }
@@ -0,0 +1,190 @@
+//! Invariants every Feature Gate instruction must uphold.
+//!
+//! Whatever accounts and instruction data it is given, the program must
+//! never:
+//!
+//! - create lamports,
+//! - debit, reassign or write to an activated feature account,
+//! - debit, reassign or write to an account that did not sign, other than
+//!   program state it owns afterwards (such as the accounts derived from its
+//!   seeds) or a pending feature revoked by `RevokeUnsupportedActivation`,
+//!   which anyone may send,
+//! - leave any account modified when the instruction fails.
+//!
+//! [`check_invariants`] checks an executed instruction against all of them,
+//! for use by property tests and fuzz targets.
+
+use {
+    mollusk_svm::result::InstructionResult, solana_account::Account, solana_address::Address,
+    solana_feature_gate_interface::from_account,
+    solana_feature_gate_program_interface::instruction::FeatureGateInstruction,
+    solana_instruction::Instruction, solana_sdk_ids::feature, std::fmt,
+};
+
+/// An invariant broken by an executed instruction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvariantViolation {
+    /// The accounts hold more lamports after the instruction than before.
+    LamportsCreated {
+        /// The total lamports before the instruction.
+        before: u128,
+        /// The total lamports after the instruction.
+        after: u128,
+    },
+    /// An activated feature account was debited, reassigned or written to.
+    ActivatedFeatureModified(Address),
+    /// An account that did not sign was debited, reassigned or written to.
+    UnauthorizedModification(Address),
+    /// The instruction failed but an account was modified.
+    ModifiedOnFailure(Address),
+    /// An account is missing from the instruction result.
+    MissingAccount(Address),
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LamportsCreated { before, after } => {
+                write!(f, "lamports created: {before} before, {after} after")
+            }
+            Self::ActivatedFeatureModified(address) => {
+                write!(f, "activated feature {address} was modified")
+            }
+            Self::UnauthorizedModification(address) => {
+                write!(f, "account {address} was modified without signing")
+            }
+            Self::ModifiedOnFailure(address) => {
+                write!(f, "account {address} was modified by a failed instruction")
+            }
+            Self::MissingAccount(address) => {
+                write!(f, "account {address} is missing from the result")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvariantViolation {}
+
+/// Checks that executing `instruction` over `accounts` upheld every
+/// invariant, given its `result`.
+///
+/// Activated features may only be credited: an account whose lamports
+/// decreased or whose owner or data changed must not hold an activated
+/// feature. It must also have signed the instruction, unless it is not a
+/// feature and the program owns it afterwards, or the instruction is
+/// `RevokeUnsupportedActivation`.
+pub fn check_invariants(
+    instruction: &Instruction,
+    accounts: &[(Address, Account)],
+    result: &InstructionResult,
+) -> Result<(), InvariantViolation> {
+    let total_lamports = |accounts: &[(Address, Account)]| {
+        accounts
+            .iter()
+            .map(|(_, account)| u128::from(account.lamports))
+            .fold(0u128, u128::saturating_add)
+    };
+    let (before, after) = (
+        total_lamports(accounts),
+        total_lamports(&result.resulting_accounts),
+    );
+    if after > before {
+        return Err(InvariantViolation::LamportsCreated { before, after });
+    }
+
+    let is_signer = |address: &Address| {
+        instruction
+            .accounts
+            .iter()
+            .any(|meta| meta.pubkey == *address && meta.is_signer)
+    };
+    let is_permissionless_revoke = matches!(
+        FeatureGateInstruction::unpack(&instruction.data),
+        Ok(FeatureGateInstruction::RevokeUnsupportedActivation)
+    );
+
+    for (address, account) in accounts {
+        let Some((_, resulting)) = result
+            .resulting_accounts
+            .iter()
+            .find(|(resulting, _)| resulting == address)
+        else {
+            return Err(InvariantViolation::MissingAccount(*address));
+        };
+        if resulting == account {
+            continue;
+        }
+
+        if result.program_result.is_err() {
+            return Err(InvariantViolation::ModifiedOnFailure(*address));
+        }
+        let only_credited = resulting.lamports >= account.lamports
+            && resulting.owner == account.owner
+            && resulting.data == account.data
+            && resulting.executable == account.executable;
+        if only_credited {
+            continue;
+        }
+        let held_feature = from_account(account);
+        if held_feature
+            .as_ref()
+            .is_some_and(|feature| feature.activated_at.is_some())
+        {
+            return Err(InvariantViolation::ActivatedFeatureModified(*address));
+        }
+        let is_program_state = held_feature.is_none() && resulting.owner == feature::id();
+        if !is_signer(address) && !is_program_state && !is_permissionless_revoke {
+            return Err(InvariantViolation::UnauthorizedModification(*address));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{mollusk, revoke_accounts, FeatureAccountBuilder},
+        solana_feature_gate_program_interface::instruction::revoke_pending_activation,
+    };
+
+    #[test]
+    fn test_check_invariants() {
+        let feature = Address::new_unique();
+        let instruction = revoke_pending_activation(&feature);
+        let accounts = revoke_accounts(feature, FeatureAccountBuilder::pending().build());
+        let result = mollusk().process_instruction(&instruction, &accounts);
+        assert_eq!(check_invariants(&instruction, &accounts, &result), Ok(()));
+
+        // The same outcome without the feature's signature.
+        let mut unsigned = instruction.clone();
+        unsigned.accounts[0].is_signer = false;
+        assert_eq!(
+            check_invariants(&unsigned, &accounts, &result),
+            Err(InvariantViolation::UnauthorizedModification(feature))
+        );
+
+        // Anyone may revoke a pending feature through its stake guard.
+        let mut permissionless = unsigned.clone();
+        permissionless.data = FeatureGateInstruction::RevokeUnsupportedActivation.pack();
+        assert_eq!(
+            check_invariants(&permissionless, &accounts, &result),
+            Ok(())
+        );
+
+        // The same outcome for an activated feature.
+        let activated = revoke_accounts(feature, FeatureAccountBuilder::active(1).build());
+        assert_eq!(
+            check_invariants(&instruction, &activated, &result),
+            Err(InvariantViolation::ActivatedFeatureModified(feature))
+        );
+
+        let mut minted = result.clone();
+        minted.resulting_accounts[1].1.lamports = u64::MAX;
+        assert!(matches!(
+            check_invariants(&instruction, &accounts, &minted),
+            Err(InvariantViolation::LamportsCreated { .. })
+        ));
+    }
+}
@@ -0,0 +1,205 @@
+//! Test fixtures for the Feature Gate program.
+//!
+//! Builds feature accounts in every state the program distinguishes, the
+//! other accounts its instructions expect, and a [`Mollusk`] instance with
+//! the program loaded, so that the program's own tests and downstream
+//! integrations set up their tests the same way. [`simulator`] extends this
+//! to whole feature lifecycles across epoch boundaries, and [`invariants`]
+//! checks the guarantees every instruction must uphold.
+
+pub mod invariants;
+pub mod simulator;
+
+use {
+    mollusk_svm::{
+        program::{create_program_account_loader_v3, keyed_account_for_system_program},
+        Mollusk,
+    },
+    solana_account::Account,
+    solana_address::Address,
+    solana_clock::{Clock, Slot},
+    solana_feature_gate_interface::{create_account, Feature},
+    solana_feature_gate_program_interface::state::find_audit_log_address,
+    solana_rent::Rent,
+    solana_sdk_ids::{feature, incinerator, vote},
+    solana_vote_interface::state::{VoteInit, VoteStateV4, VoteStateVersions},
+};
+
+/// The name of the built Feature Gate program, without the `.so` extension.
+pub const PROGRAM_NAME: &str = "solana_feature_gate_program";
+
+/// Creates a [`Mollusk`] instance with the Feature Gate program loaded.
+///
+/// The program is loaded from Mollusk's default search paths
+/// (`tests/fixtures`, `BPF_OUT_DIR`, `SBF_OUT_DIR` and the current directory).
+///
+/// # Panics
+///
+/// Panics if the program binary cannot be found.
+pub fn mollusk() -> Mollusk {
+    Mollusk::new(&feature::id(), PROGRAM_NAME)
+}
+
+/// The rent-exempt balance of a feature account under the default rent.
+pub fn feature_rent() -> u64 {
+    Rent::default().minimum_balance(Feature::size_of())
+}
+
+/// A builder for feature accounts.
+///
+/// Accounts are owned by the Feature Gate program and hold exactly the
+/// rent-exempt balance unless configured otherwise.
+#[derive(Clone, Debug)]
+pub struct FeatureAccountBuilder {
+    data: Vec<u8>,
+    lamports: u64,
+    owner: Address,
+}
+
+impl FeatureAccountBuilder {
+    /// A feature queued for activation.
+    pub fn pending() -> Self {
+        Self::with_feature(&Feature { activated_at: None })
+    }
+
+    /// A feature activated at `activated_at`.
+    pub fn active(activated_at: Slot) -> Self {
+        Self::with_feature(&Feature {
+            activated_at: Some(activated_at),
+        })
+    }
+
+    /// An account owned by the program whose data is not a valid feature.
+    pub fn malformed() -> Self {
+        Self::pending().data(vec![2; 8])
+    }
+
+    fn with_feature(feature: &Feature) -> Self {
+        let account = Account::from(create_account(feature, feature_rent()));
+        Self {
+            data: account.data,
+            lamports: account.lamports,
+            owner: account.owner,
+        }
+    }
+
+    /// Sets the account's lamports.
+    pub fn lamports(mut self, lamports: u64) -> Self {
+        self.lamports = lamports;
+        self
+    }
+
+    /// Sets the account's owner, e.g. to build a wrongly-owned feature.
+    pub fn owner(mut self, owner: Address) -> Self {
+        self.owner = owner;
+        self
+    }
+
+    /// Replaces the account's data.
+    pub fn data(mut self, data: Vec<u8>) -> Self {
+        self.data = data;
+        self
+    }
+
+    /// Builds the account.
+    pub fn build(self) -> Account {
+        Account {
+            lamports: self.lamports,
+            data: self.data,
+            owner: self.owner,
+            ..Account::default()
+        }
+    }
+}
+
+/// The incinerator account, empty.
+pub fn keyed_incinerator_account() -> (Address, Account) {
+    (incinerator::id(), Account::default())
+}
+
+/// The audit log account, not yet initialized.
+pub fn keyed_audit_log_account() -> (Address, Account) {
+    (find_audit_log_address().0, Account::default())
+}
+
+/// The System program account.
+pub fn keyed_system_program_account() -> (Address, Account) {
+    keyed_account_for_system_program()
+}
+
+/// The Feature Gate program account, as loaded by [`mollusk`], for
+/// instructions of other programs that invoke it.
+pub fn keyed_program_account() -> (Address, Account) {
+    (
+        feature::id(),
+        create_program_account_loader_v3(&feature::id()),
+    )
+}
+
+/// A current-version vote account at `vote_account` with the given node
+/// identity and authorized withdrawer.
+pub fn vote_account(
+    vote_account: &Address,
+    node_pubkey: &Address,
+    authorized_withdrawer: &Address,
+) -> Account {
+    let vote_init = VoteInit {
+        node_pubkey: *node_pubkey,
+        authorized_voter: *node_pubkey,
+        authorized_withdrawer: *authorized_withdrawer,
+        commission: 0,
+    };
+    let vote_state = VoteStateV4::new(vote_account, &vote_init, &Clock::default());
+    let mut data = vec![0; VoteStateV4::size_of()];
+    VoteStateV4::serialize(&VoteStateVersions::new_v4(vote_state), &mut data).unwrap();
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: vote::id(),
+        ..Account::default()
+    }
+}
+
+/// The accounts `RevokePendingActivation` expects for the feature account
+/// `account` at `feature`: the feature, the incinerator, the System program
+/// and the audit log, not yet initialized.
+pub fn revoke_accounts(feature: Address, account: Account) -> Vec<(Address, Account)> {
+    vec![
+        (feature, account),
+        keyed_incinerator_account(),
+        keyed_system_program_account(),
+        keyed_audit_log_account(),
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, solana_feature_gate_interface::from_account};
+
+    #[test]
+    fn test_feature_accounts() {
+        let pending = FeatureAccountBuilder::pending().build();
+        assert_eq!(pending.owner, feature::id());
+        assert_eq!(pending.lamports, feature_rent());
+        assert_eq!(from_account(&pending), Some(Feature { activated_at: None }));
+
+        let active = FeatureAccountBuilder::active(45).build();
+        assert_eq!(
+            from_account(&active),
+            Some(Feature {
+                activated_at: Some(45)
+            })
+        );
+
+        let malformed = FeatureAccountBuilder::malformed().build();
+        assert_eq!(malformed.owner, feature::id());
+        assert_eq!(from_account(&malformed), None);
+
+        let owner = Address::new_unique();
+        let wrong_owner = FeatureAccountBuilder::pending()
+            .owner(owner)
+            .lamports(1)
+            .build();
+        assert_eq!((wrong_owner.owner, wrong_owner.lamports), (owner, 1));
+    }
+}
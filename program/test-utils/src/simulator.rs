@@ -0,0 +1,242 @@
+//! Epoch-boundary activation simulation.
+//!
+//! Mollusk executes single instructions against the accounts a test passes
+//! in; it has no notion of the runtime activating features. An
+//! [`ActivationSimulator`] holds a persistent set of accounts, executes
+//! transactions against them, and advances the slot across the cluster's
+//! [`EpochSchedule`], applying the runtime's activation rule at every epoch
+//! boundary: each pending feature account is activated at the first slot of
+//! the new epoch.
+//!
+//! Every pending feature is treated as known to the runtime; the simulator
+//! does not model validators that lack support for a feature.
+
+use {
+    crate::{keyed_program_account, mollusk},
+    mollusk_svm::{program::keyed_account_for_system_program, Mollusk},
+    solana_account::Account,
+    solana_address::Address,
+    solana_clock::{Epoch, Slot},
+    solana_epoch_schedule::EpochSchedule,
+    solana_feature_gate_interface::{
+        activate_with_lamports, create_account, from_account, Feature,
+    },
+    solana_feature_gate_program_interface::instruction::revoke_pending_activation,
+    solana_instruction::Instruction,
+    solana_sdk_ids::{feature, system_program},
+    solana_transaction_error::TransactionError,
+    std::collections::BTreeMap,
+};
+
+/// A persistent account store on which the runtime's feature activation
+/// rule is applied as slots advance.
+pub struct ActivationSimulator {
+    mollusk: Mollusk,
+    accounts: BTreeMap<Address, Account>,
+}
+
+impl Default for ActivationSimulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ActivationSimulator {
+    /// Creates a simulator at slot 0 with Mollusk's default epoch schedule.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the program binary cannot be found (see [`mollusk`]).
+    pub fn new() -> Self {
+        Self {
+            mollusk: mollusk(),
+            accounts: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a simulator at slot 0 with the given epoch schedule.
+    pub fn with_epoch_schedule(epoch_schedule: EpochSchedule) -> Self {
+        let mut simulator = Self::new();
+        simulator.mollusk.sysvars.epoch_schedule = epoch_schedule;
+        simulator.mollusk.warp_to_slot(0);
+        simulator
+    }
+
+    /// The epoch schedule.
+    pub fn epoch_schedule(&self) -> &EpochSchedule {
+        &self.mollusk.sysvars.epoch_schedule
+    }
+
+    /// The current slot.
+    pub fn slot(&self) -> Slot {
+        self.mollusk.sysvars.clock.slot
+    }
+
+    /// The current epoch.
+    pub fn epoch(&self) -> Epoch {
+        self.mollusk.sysvars.clock.epoch
+    }
+
+    /// The first slot of the next epoch, at which pending features activate.
+    pub fn next_boundary(&self) -> Slot {
+        self.epoch_schedule()
+            .get_first_slot_in_epoch(self.epoch().saturating_add(1))
+    }
+
+    /// The underlying Mollusk instance.
+    pub fn mollusk(&self) -> &Mollusk {
+        &self.mollusk
+    }
+
+    /// The underlying Mollusk instance, mutably, e.g. to load the other
+    /// programs a test's transactions invoke.
+    pub fn mollusk_mut(&mut self) -> &mut Mollusk {
+        &mut self.mollusk
+    }
+
+    /// An account, or `None` if it does not exist.
+    pub fn account(&self, address: &Address) -> Option<&Account> {
+        self.accounts.get(address)
+    }
+
+    /// Creates or replaces an account.
+    pub fn set_account(&mut self, address: Address, account: Account) {
+        self.accounts.insert(address, account);
+    }
+
+    /// Creates a System-owned account holding `lamports`, e.g. a fee payer.
+    pub fn fund(&mut self, address: Address, lamports: u64) {
+        self.set_account(address, Account::new(lamports, 0, &system_program::id()));
+    }
+
+    /// The feature stored at `feature_id`, or `None` if the account does not
+    /// exist or is not a valid feature account.
+    pub fn feature(&self, feature_id: &Address) -> Option<Feature> {
+        self.account(feature_id).and_then(from_account)
+    }
+
+    /// Executes `instructions` atomically as one transaction, committing the
+    /// resulting accounts if it succeeds.
+    ///
+    /// Accounts that do not exist are passed to the program as empty
+    /// System-owned accounts, the Feature Gate program is passed as loaded by
+    /// [`mollusk`], and accounts left without lamports are removed.
+    pub fn process_transaction(
+        &mut self,
+        instructions: &[Instruction],
+    ) -> Result<(), TransactionError> {
+        let mut accounts = Vec::<(Address, Account)>::new();
+        for address in instructions
+            .iter()
+            .flat_map(|instruction| &instruction.accounts)
+            .map(|meta| meta.pubkey)
+        {
+            if accounts.iter().any(|(key, _)| *key == address) {
+                continue;
+            }
+            accounts.push(if address == system_program::id() {
+                keyed_account_for_system_program()
+            } else if address == feature::id() {
+                keyed_program_account()
+            } else {
+                (
+                    address,
+                    self.accounts.get(&address).cloned().unwrap_or_default(),
+                )
+            });
+        }
+
+        let result = self
+            .mollusk
+            .process_transaction_instructions(instructions, &accounts);
+        result.raw_result?;
+        for (address, account) in result.resulting_accounts {
+            if address == system_program::id() || address == feature::id() {
+                continue;
+            }
+            if account.lamports == 0 {
+                self.accounts.remove(&address);
+            } else {
+                self.accounts.insert(address, account);
+            }
+        }
+        Ok(())
+    }
+
+    /// Queues `feature_id` for activation, funded with its rent-exempt
+    /// balance by `payer`.
+    pub fn queue(&mut self, feature_id: &Address, payer: &Address) -> Result<(), TransactionError> {
+        let lamports = self
+            .mollusk
+            .sysvars
+            .rent
+            .minimum_balance(Feature::size_of());
+        self.process_transaction(&activate_with_lamports(feature_id, payer, lamports))
+    }
+
+    /// Revokes the pending activation of `feature_id`.
+    pub fn revoke(&mut self, feature_id: &Address) -> Result<(), TransactionError> {
+        self.process_transaction(&[revoke_pending_activation(feature_id)])
+    }
+
+    /// Advances by `slots` slots. See
+    /// [`advance_to_slot`](Self::advance_to_slot).
+    pub fn advance_slots(&mut self, slots: u64) -> Vec<Address> {
+        self.advance_to_slot(self.slot().saturating_add(slots))
+    }
+
+    /// Advances to the first slot of the next epoch, activating every pending
+    /// feature.
+    pub fn advance_to_next_epoch(&mut self) -> Vec<Address> {
+        self.advance_to_slot(self.next_boundary())
+    }
+
+    /// Advances to `slot`, returning the features activated on the way.
+    ///
+    /// If an epoch boundary is crossed, every pending feature is activated at
+    /// the first slot of the epoch following the current one, even if
+    /// several boundaries are crossed at once. Only program-owned accounts of
+    /// exactly a feature's size count as pending features, so that other
+    /// program accounts whose data happens to deserialize as a feature, such
+    /// as a config or an audit log, are left alone.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slot` is before the current slot.
+    pub fn advance_to_slot(&mut self, slot: Slot) -> Vec<Address> {
+        assert!(
+            slot >= self.slot(),
+            "cannot rewind from slot {} to {slot}",
+            self.slot()
+        );
+        let boundary = self.next_boundary();
+        self.mollusk.warp_to_slot(slot);
+        if slot < boundary {
+            return Vec::new();
+        }
+
+        let pending = self
+            .accounts
+            .iter()
+            .filter(|(_, account)| {
+                account.data.len() == Feature::size_of()
+                    && from_account(*account).is_some_and(|feature| feature.activated_at.is_none())
+            })
+            .map(|(address, _)| *address)
+            .collect::<Vec<_>>();
+        for address in &pending {
+            let account = self
+                .accounts
+                .get_mut(address)
+                .expect("pending feature exists");
+            let activated = Account::from(create_account(
+                &Feature {
+                    activated_at: Some(boundary),
+                },
+                account.lamports,
+            ));
+            account.data = activated.data;
+        }
+        pending
+    }
+}
@@ -0,0 +1,120 @@
+use {
+    solana_address::Address,
+    solana_clock::Slot,
+    solana_feature_gate_program::error::FeatureGateError,
+    solana_feature_gate_test_utils::{simulator::ActivationSimulator, FeatureAccountBuilder},
+    solana_instruction::error::InstructionError,
+    solana_transaction_error::TransactionError,
+};
+
+const PAYER_LAMPORTS: u64 = 1_000_000_000;
+
+fn setup() -> (ActivationSimulator, Address) {
+    let mut simulator = ActivationSimulator::new();
+    let payer = Address::new_unique();
+    simulator.fund(payer, PAYER_LAMPORTS);
+    (simulator, payer)
+}
+
+fn activated_at(simulator: &ActivationSimulator, feature: &Address) -> Option<Slot> {
+    simulator.feature(feature).unwrap().activated_at
+}
+
+#[test]
+fn queued_feature_activates_at_next_boundary() {
+    let (mut simulator, payer) = setup();
+    let feature = Address::new_unique();
+
+    simulator.advance_slots(3);
+    simulator.queue(&feature, &payer).unwrap();
+    assert_eq!(activated_at(&simulator, &feature), None);
+
+    let boundary = simulator.next_boundary();
+    assert!(simulator
+        .advance_to_slot(boundary.saturating_sub(1))
+        .is_empty());
+    assert_eq!(activated_at(&simulator, &feature), None);
+
+    assert_eq!(simulator.advance_slots(1), vec![feature]);
+    assert_eq!(activated_at(&simulator, &feature), Some(boundary));
+
+    // Activation is permanent across later boundaries.
+    assert!(simulator.advance_to_next_epoch().is_empty());
+    assert_eq!(activated_at(&simulator, &feature), Some(boundary));
+}
+
+#[test]
+fn other_program_accounts_do_not_activate() {
+    let (mut simulator, _) = setup();
+    // Program data that starts like a pending feature but is longer than
+    // one.
+    let account = FeatureAccountBuilder::pending().data(vec![0; 17]).build();
+    let address = Address::new_unique();
+    simulator.set_account(address, account.clone());
+
+    assert!(simulator.advance_to_next_epoch().is_empty());
+    assert_eq!(simulator.account(&address), Some(&account));
+}
+
+#[test]
+fn skipping_several_epochs_activates_at_first_boundary() {
+    let (mut simulator, payer) = setup();
+    let feature = Address::new_unique();
+    simulator.queue(&feature, &payer).unwrap();
+
+    let boundary = simulator.next_boundary();
+    let later = simulator
+        .epoch_schedule()
+        .get_first_slot_in_epoch(simulator.epoch().saturating_add(3));
+    assert_eq!(simulator.advance_to_slot(later), vec![feature]);
+    assert_eq!(activated_at(&simulator, &feature), Some(boundary));
+}
+
+#[test]
+fn revoke_in_last_slot_before_boundary() {
+    let (mut simulator, payer) = setup();
+    let (revoked, kept) = (Address::new_unique(), Address::new_unique());
+    simulator.queue(&revoked, &payer).unwrap();
+    simulator.queue(&kept, &payer).unwrap();
+
+    let last_slot = simulator.next_boundary().saturating_sub(1);
+    simulator.advance_to_slot(last_slot);
+    simulator.revoke(&revoked).unwrap();
+    assert!(simulator.account(&revoked).is_none());
+
+    assert_eq!(simulator.advance_slots(1), vec![kept]);
+    assert!(simulator.account(&revoked).is_none());
+}
+
+#[test]
+fn revoke_after_boundary_fails() {
+    let (mut simulator, payer) = setup();
+    let feature = Address::new_unique();
+    simulator.queue(&feature, &payer).unwrap();
+    simulator.advance_to_next_epoch();
+
+    assert_eq!(
+        simulator.revoke(&feature),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(FeatureGateError::FeatureAlreadyActivated as u32),
+        ))
+    );
+    assert!(activated_at(&simulator, &feature).is_some());
+}
+
+#[test]
+fn requeue_after_revoke() {
+    let (mut simulator, payer) = setup();
+    let feature = Address::new_unique();
+
+    simulator.queue(&feature, &payer).unwrap();
+    simulator.revoke(&feature).unwrap();
+    // A feature cannot be queued twice while its account exists.
+    simulator.queue(&feature, &payer).unwrap();
+    assert!(simulator.queue(&feature, &payer).is_err());
+
+    let boundary = simulator.next_boundary();
+    assert_eq!(simulator.advance_to_next_epoch(), vec![feature]);
+    assert_eq!(activated_at(&simulator, &feature), Some(boundary));
+}
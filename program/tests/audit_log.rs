@@ -0,0 +1,302 @@
+use {
+    mollusk_svm::{program::keyed_account_for_system_program, result::Check},
+    solana_account::Account,
+    solana_address::Address,
+    solana_feature_gate_program::{
+        instruction::{
+            extend_audit_log, initialize_audit_log, revoke_pending_activation, schedule_activation,
+        },
+        state::{find_audit_log_address, AuditAction, AuditEntry, AuditLog},
+    },
+    solana_feature_gate_test_utils::{
+        feature_rent, mollusk, revoke_accounts, simulator::ActivationSimulator,
+        FeatureAccountBuilder,
+    },
+    solana_program_error::ProgramError,
+    solana_rent::Rent,
+    solana_sdk_ids::{feature, incinerator, system_program},
+};
+
+fn payer_account() -> Account {
+    Account::new(1_000_000_000, 0, &system_program::id())
+}
+
+fn audit_log_account(data: Vec<u8>) -> Account {
+    let mut account = Account::new(
+        Rent::default().minimum_balance(data.len()),
+        data.len(),
+        &feature::id(),
+    );
+    account.data = data;
+    account
+}
+
+fn empty_log(capacity: usize) -> Vec<u8> {
+    let mut data = vec![0; AuditLog::space(capacity)];
+    data[..AuditLog::LEN].copy_from_slice(&AuditLog::default().pack());
+    data
+}
+
+fn entry(slot: u64) -> AuditEntry {
+    AuditEntry {
+        action: AuditAction::ScheduleActivation,
+        subject: Address::new_unique(),
+        signer: Address::new_unique(),
+        slot,
+    }
+}
+
+/// The entries of the audit log in `data`, oldest first.
+fn entries(data: &[u8]) -> Vec<AuditEntry> {
+    let log = AuditLog::unpack(data).unwrap();
+    let capacity = AuditLog::capacity(data.len());
+    (0..log.len as usize)
+        .map(|i| {
+            let index = log
+                .tail(capacity)
+                .saturating_add(i)
+                .checked_rem(capacity)
+                .unwrap();
+            let offset = AuditLog::LEN.saturating_add(index.saturating_mul(AuditEntry::LEN));
+            AuditEntry::unpack(&data[offset..offset.saturating_add(AuditEntry::LEN)]).unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn fail_initialize_invalid_capacity() {
+    let payer = Address::new_unique();
+
+    for capacity in [0, 141] {
+        mollusk().process_and_validate_instruction(
+            &initialize_audit_log(&payer, capacity),
+            &[
+                (find_audit_log_address().0, Account::default()),
+                (payer, payer_account()),
+                keyed_account_for_system_program(),
+            ],
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+}
+
+#[test]
+fn fail_initialize_already_initialized() {
+    let payer = Address::new_unique();
+
+    mollusk().process_and_validate_instruction(
+        &initialize_audit_log(&payer, 4),
+        &[
+            (find_audit_log_address().0, audit_log_account(empty_log(4))),
+            (payer, payer_account()),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::AccountAlreadyInitialized)],
+    );
+}
+
+#[test]
+fn success_initialize() {
+    let payer = Address::new_unique();
+
+    mollusk().process_and_validate_instruction(
+        &initialize_audit_log(&payer, 140),
+        &[
+            (find_audit_log_address().0, Account::default()),
+            (payer, payer_account()),
+            keyed_account_for_system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&find_audit_log_address().0)
+                .owner(&feature::id())
+                .lamports(Rent::default().minimum_balance(AuditLog::space(140)))
+                .data(&empty_log(140))
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_extend_uninitialized() {
+    let payer = Address::new_unique();
+
+    mollusk().process_and_validate_instruction(
+        &extend_audit_log(&payer, 4),
+        &[
+            (find_audit_log_address().0, Account::default()),
+            (payer, payer_account()),
+            keyed_account_for_system_program(),
+        ],
+        &[Check::err(ProgramError::UninitializedAccount)],
+    );
+}
+
+#[test]
+fn success_extend_wrapped_log() {
+    let payer = Address::new_unique();
+    let written = (0..5).map(entry).collect::<Vec<_>>();
+    let mut data = empty_log(3);
+    for entry in &written {
+        AuditLog::append(&mut data, entry).unwrap();
+    }
+
+    let mut expected = data.clone();
+    expected.resize(AuditLog::space(5), 0);
+    AuditLog::extend(&mut expected, data.len()).unwrap();
+    assert_eq!(entries(&expected), written[2..]);
+
+    mollusk().process_and_validate_instruction(
+        &extend_audit_log(&payer, 2),
+        &[
+            (find_audit_log_address().0, audit_log_account(data)),
+            (payer, payer_account()),
+            keyed_account_for_system_program(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&find_audit_log_address().0)
+                .lamports(Rent::default().minimum_balance(expected.len()))
+                .data(&expected)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_record_wrong_audit_log() {
+    let feature_id = Address::new_unique();
+    let impostor = Address::new_unique();
+    let mut instruction = revoke_pending_activation(&feature_id);
+    instruction.accounts[3].pubkey = impostor;
+
+    let mut accounts = revoke_accounts(feature_id, FeatureAccountBuilder::pending().build());
+    accounts[3] = (impostor, audit_log_account(empty_log(4)));
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn success_revoke_without_audit_log() {
+    let feature_id = Address::new_unique();
+    let mut instruction = revoke_pending_activation(&feature_id);
+    instruction.accounts.truncate(3);
+
+    let mut accounts = revoke_accounts(feature_id, FeatureAccountBuilder::pending().build());
+    accounts.truncate(3);
+
+    // Revocations built before the audit log existed still succeed, without
+    // recording an entry.
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[
+            Check::success(),
+            Check::account(&feature_id).closed().build(),
+            Check::account(&incinerator::id())
+                .lamports(feature_rent())
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn success_revoke_grows_full_log() {
+    let feature_id = Address::new_unique();
+    let mut data = empty_log(1);
+    AuditLog::append(&mut data, &entry(0)).unwrap();
+    let log = audit_log_account(data);
+    let grown_rent = Rent::default().minimum_balance(AuditLog::space(2));
+    let shortfall = grown_rent - log.lamports;
+
+    let mut accounts = revoke_accounts(feature_id, FeatureAccountBuilder::pending().build());
+    accounts[3] = (find_audit_log_address().0, log);
+
+    // The feature account pays for the new entry before its remaining
+    // lamports are burned.
+    mollusk().process_and_validate_instruction(
+        &revoke_pending_activation(&feature_id),
+        &accounts,
+        &[
+            Check::success(),
+            Check::account(&find_audit_log_address().0)
+                .lamports(grown_rent)
+                .space(AuditLog::space(2))
+                .build(),
+            Check::account(&feature_id).closed().build(),
+            Check::account(&incinerator::id())
+                .lamports(feature_rent() - shortfall)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn permissionless_actions_grow_the_log() {
+    let mut simulator = ActivationSimulator::new();
+    let payer = Address::new_unique();
+    simulator.fund(payer, 1_000_000_000);
+    let (audit_log, _) = find_audit_log_address();
+
+    simulator
+        .process_transaction(&[initialize_audit_log(&payer, 2)])
+        .unwrap();
+
+    let features = (0..3).map(|_| Address::new_unique()).collect::<Vec<_>>();
+    for (slot, feature_id) in features.iter().enumerate() {
+        simulator.advance_to_slot(slot as u64);
+        simulator
+            .process_transaction(&[schedule_activation(feature_id, &payer, 5)])
+            .unwrap();
+    }
+
+    // The log grew by one entry instead of overwriting the first, at the
+    // payer's expense.
+    let account = simulator.account(&audit_log).unwrap();
+    assert_eq!(account.data.len(), AuditLog::space(3));
+    assert_eq!(
+        account.lamports,
+        Rent::default().minimum_balance(AuditLog::space(3))
+    );
+    assert_eq!(
+        entries(&account.data),
+        features
+            .iter()
+            .zip(0..)
+            .map(|(feature_id, slot)| AuditEntry {
+                action: AuditAction::ScheduleActivation,
+                subject: *feature_id,
+                signer: *feature_id,
+                slot,
+            })
+            .collect::<Vec<_>>()
+    );
+
+    // Growing the log explicitly leaves room without resizing again.
+    simulator
+        .process_transaction(&[extend_audit_log(&payer, 1)])
+        .unwrap();
+    simulator
+        .process_transaction(&[revoke_pending_activation(&features[2])])
+        .unwrap();
+    let data = &simulator.account(&audit_log).unwrap().data;
+    assert_eq!(data.len(), AuditLog::space(4));
+    let recorded = entries(data);
+    assert_eq!(
+        recorded
+            .iter()
+            .map(|entry| (entry.action, entry.subject))
+            .collect::<Vec<_>>(),
+        vec![
+            (AuditAction::ScheduleActivation, features[0]),
+            (AuditAction::ScheduleActivation, features[1]),
+            (AuditAction::ScheduleActivation, features[2]),
+            (AuditAction::RevokePendingActivation, features[2]),
+        ]
+    );
+    assert_eq!(AuditLog::unpack(data).unwrap().next_sequence, 4);
+}
@@ -0,0 +1,339 @@
+use {
+    mollusk_svm::{program::keyed_account_for_system_program, result::Check},
+    solana_account::Account,
+    solana_address::Address,
+    solana_feature_gate_program::{
+        error::FeatureGateError,
+        instruction::{initialize_audit_log, initialize_bundle, queue_bundle, revoke_bundle},
+        state::{find_audit_log_address, find_bundle_address, AuditAction, AuditLog, Bundle},
+    },
+    solana_feature_gate_test_utils::{
+        feature_rent, keyed_audit_log_account, keyed_incinerator_account, mollusk,
+        simulator::ActivationSimulator, FeatureAccountBuilder,
+    },
+    solana_instruction::error::InstructionError,
+    solana_program_error::ProgramError,
+    solana_rent::Rent,
+    solana_sdk_ids::{feature, incinerator, system_program},
+    solana_transaction_error::TransactionError,
+};
+
+const NAME: [u8; 32] = *b"core-bpf\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0";
+
+fn payer_account() -> Account {
+    Account::new(1_000_000_000, 0, &system_program::id())
+}
+
+fn bundle_account(bundle: &Bundle) -> Account {
+    let mut account = Account::new(
+        Rent::default().minimum_balance(Bundle::LEN),
+        Bundle::LEN,
+        &feature::id(),
+    );
+    account.data = bundle.pack();
+    account
+}
+
+/// A bundle of two new features created by a new authority, with its
+/// address.
+fn new_bundle() -> (Address, Bundle) {
+    let authority = Address::new_unique();
+    let members = [Address::new_unique(), Address::new_unique()];
+    (
+        find_bundle_address(&authority, &NAME).0,
+        Bundle::new(authority, NAME, &members).unwrap(),
+    )
+}
+
+fn queue_accounts(
+    bundle_address: Address,
+    bundle: &Bundle,
+    payer: Address,
+    member_accounts: [Account; 2],
+) -> Vec<(Address, Account)> {
+    let mut accounts = vec![
+        (bundle_address, bundle_account(bundle)),
+        (payer, payer_account()),
+        keyed_account_for_system_program(),
+        keyed_audit_log_account(),
+    ];
+    accounts.extend(bundle.members().copied().zip(member_accounts));
+    accounts
+}
+
+fn revoke_accounts(
+    bundle_address: Address,
+    bundle: &Bundle,
+    member_accounts: [Account; 2],
+) -> Vec<(Address, Account)> {
+    let mut accounts = vec![
+        (bundle_address, bundle_account(bundle)),
+        keyed_incinerator_account(),
+        keyed_account_for_system_program(),
+        keyed_audit_log_account(),
+    ];
+    accounts.extend(bundle.members().copied().zip(member_accounts));
+    accounts
+}
+
+#[test]
+fn fail_initialize_duplicate_members() {
+    let authority = Address::new_unique();
+    let payer = Address::new_unique();
+    let member = Address::new_unique();
+
+    mollusk().process_and_validate_instruction(
+        &initialize_bundle(&authority, &payer, &NAME, &[member, member]),
+        &[
+            (find_bundle_address(&authority, &NAME).0, Account::default()),
+            (authority, Account::default()),
+            (payer, payer_account()),
+            keyed_account_for_system_program(),
+            (member, Account::default()),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn fail_initialize_already_initialized() {
+    let payer = Address::new_unique();
+    let (bundle_address, bundle) = new_bundle();
+    let members = bundle.members().copied().collect::<Vec<_>>();
+
+    mollusk().process_and_validate_instruction(
+        &initialize_bundle(&bundle.authority, &payer, &NAME, &members),
+        &[
+            (bundle_address, bundle_account(&bundle)),
+            (bundle.authority, Account::default()),
+            (payer, payer_account()),
+            keyed_account_for_system_program(),
+            (members[0], Account::default()),
+            (members[1], Account::default()),
+        ],
+        &[Check::err(ProgramError::AccountAlreadyInitialized)],
+    );
+}
+
+#[test]
+fn success_initialize() {
+    let payer = Address::new_unique();
+    let (bundle_address, bundle) = new_bundle();
+    let members = bundle.members().copied().collect::<Vec<_>>();
+
+    mollusk().process_and_validate_instruction(
+        &initialize_bundle(&bundle.authority, &payer, &NAME, &members),
+        &[
+            (bundle_address, Account::default()),
+            (bundle.authority, Account::default()),
+            (payer, payer_account()),
+            keyed_account_for_system_program(),
+            (members[0], Account::default()),
+            (members[1], Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&bundle_address)
+                .owner(&feature::id())
+                .lamports(Rent::default().minimum_balance(Bundle::LEN))
+                .data(&bundle.pack())
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_queue_wrong_member() {
+    let payer = Address::new_unique();
+    let (bundle_address, bundle) = new_bundle();
+    let mut members = bundle.members().copied().collect::<Vec<_>>();
+    members.reverse();
+
+    let mut accounts = queue_accounts(
+        bundle_address,
+        &bundle,
+        payer,
+        [Account::default(), Account::default()],
+    );
+    accounts[4].0 = members[0];
+    accounts[5].0 = members[1];
+
+    mollusk().process_and_validate_instruction(
+        &queue_bundle(&bundle_address, &payer, &members),
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            FeatureGateError::InvalidBundleMember as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_queue_member_active() {
+    let payer = Address::new_unique();
+    let (bundle_address, bundle) = new_bundle();
+    let members = bundle.members().copied().collect::<Vec<_>>();
+
+    mollusk().process_and_validate_instruction(
+        &queue_bundle(&bundle_address, &payer, &members),
+        &queue_accounts(
+            bundle_address,
+            &bundle,
+            payer,
+            [
+                Account::default(),
+                FeatureAccountBuilder::active(42).build(),
+            ],
+        ),
+        &[Check::err(ProgramError::Custom(
+            FeatureGateError::FeatureAlreadyActivated as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_queue_member_pending() {
+    let payer = Address::new_unique();
+    let (bundle_address, bundle) = new_bundle();
+    let members = bundle.members().copied().collect::<Vec<_>>();
+
+    mollusk().process_and_validate_instruction(
+        &queue_bundle(&bundle_address, &payer, &members),
+        &queue_accounts(
+            bundle_address,
+            &bundle,
+            payer,
+            [FeatureAccountBuilder::pending().build(), Account::default()],
+        ),
+        &[Check::err(ProgramError::AccountAlreadyInitialized)],
+    );
+}
+
+#[test]
+fn success_queue() {
+    let payer = Address::new_unique();
+    let (bundle_address, bundle) = new_bundle();
+    let members = bundle.members().copied().collect::<Vec<_>>();
+    let pending = FeatureAccountBuilder::pending().build();
+
+    mollusk().process_and_validate_instruction(
+        &queue_bundle(&bundle_address, &payer, &members),
+        &queue_accounts(
+            bundle_address,
+            &bundle,
+            payer,
+            [Account::default(), Account::default()],
+        ),
+        &[
+            Check::success(),
+            Check::account(&members[0])
+                .owner(&feature::id())
+                .lamports(feature_rent())
+                .data(&pending.data)
+                .build(),
+            Check::account(&members[1])
+                .owner(&feature::id())
+                .lamports(feature_rent())
+                .data(&pending.data)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_revoke_member_active() {
+    let (bundle_address, bundle) = new_bundle();
+    let members = bundle.members().copied().collect::<Vec<_>>();
+
+    mollusk().process_and_validate_instruction(
+        &revoke_bundle(&bundle_address, &members),
+        &revoke_accounts(
+            bundle_address,
+            &bundle,
+            [
+                FeatureAccountBuilder::pending().build(),
+                FeatureAccountBuilder::active(42).build(),
+            ],
+        ),
+        &[Check::err(ProgramError::Custom(
+            FeatureGateError::FeatureAlreadyActivated as u32,
+        ))],
+    );
+}
+
+#[test]
+fn success_revoke() {
+    let (bundle_address, bundle) = new_bundle();
+    let members = bundle.members().copied().collect::<Vec<_>>();
+
+    mollusk().process_and_validate_instruction(
+        &revoke_bundle(&bundle_address, &members),
+        &revoke_accounts(
+            bundle_address,
+            &bundle,
+            [
+                FeatureAccountBuilder::pending().build(),
+                FeatureAccountBuilder::pending().build(),
+            ],
+        ),
+        &[
+            Check::success(),
+            Check::account(&members[0]).closed().build(),
+            Check::account(&members[1]).closed().build(),
+            Check::account(&incinerator::id())
+                .lamports(feature_rent().saturating_mul(2))
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn bundle_members_activate_together() {
+    let mut simulator = ActivationSimulator::new();
+    let payer = Address::new_unique();
+    simulator.fund(payer, 1_000_000_000);
+    let (bundle_address, bundle) = new_bundle();
+    let members = bundle.members().copied().collect::<Vec<_>>();
+
+    simulator
+        .process_transaction(&[
+            initialize_bundle(&bundle.authority, &payer, &NAME, &members),
+            initialize_audit_log(&payer, 4),
+            queue_bundle(&bundle_address, &payer, &members),
+        ])
+        .unwrap();
+
+    // Queueing again fails as a whole.
+    assert_eq!(
+        simulator.process_transaction(&[queue_bundle(&bundle_address, &payer, &members)]),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::AccountAlreadyInitialized,
+        ))
+    );
+
+    let log = &simulator.account(&find_audit_log_address().0).unwrap().data;
+    assert_eq!(AuditLog::unpack(log).unwrap().len, 2);
+    assert_eq!(log[AuditLog::LEN], AuditAction::QueueBundleMember as u8);
+
+    let boundary = simulator.next_boundary();
+    let mut activated = simulator.advance_to_next_epoch();
+    activated.sort();
+    let mut expected = members.clone();
+    expected.sort();
+    assert_eq!(activated, expected);
+    for member in &members {
+        assert_eq!(
+            simulator.feature(member).unwrap().activated_at,
+            Some(boundary)
+        );
+    }
+
+    // Active members can no longer be revoked.
+    assert_eq!(
+        simulator.process_transaction(&[revoke_bundle(&bundle_address, &members)]),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(FeatureGateError::FeatureAlreadyActivated as u32),
+        ))
+    );
+}
@@ -0,0 +1,424 @@
+use {
+    mollusk_svm::{program::keyed_account_for_system_program, result::Check},
+    solana_account::Account,
+    solana_address::Address,
+    solana_feature_gate_program::{
+        error::FeatureGateError,
+        instruction::{
+            initialize_config, revoke_pending_activation_with_refund,
+            revoke_unsupported_activation, update_config,
+        },
+        state::{
+            find_config_address, find_stake_guard_address, Config, StakeGuard, Tombstone,
+            TombstonePolicy, MAX_REFUND_RECIPIENTS,
+        },
+    },
+    solana_feature_gate_test_utils::{
+        feature_rent, keyed_audit_log_account, keyed_incinerator_account, mollusk,
+        FeatureAccountBuilder,
+    },
+    solana_program_error::ProgramError,
+    solana_rent::Rent,
+    solana_sdk_ids::{bpf_loader_upgradeable, feature, incinerator, system_program},
+};
+
+fn config_rent() -> u64 {
+    Rent::default().minimum_balance(Config::LEN)
+}
+
+fn config(authority: Address) -> Config {
+    let mut refund_recipients = [Address::default(); MAX_REFUND_RECIPIENTS];
+    refund_recipients[0] = Address::new_unique();
+    Config {
+        authority,
+        revoke_guard_window: 1_000,
+        tombstone_policy: TombstonePolicy::Tombstone,
+        refund_recipients,
+    }
+}
+
+fn config_account(config: &Config) -> Account {
+    let mut account = Account::new(config_rent(), Config::LEN, &feature::id());
+    account.data = config.pack();
+    account
+}
+
+/// The upgradeable loader's program data account for the Feature Gate
+/// program, with the given upgrade authority.
+fn program_data_account(upgrade_authority: Option<&Address>) -> (Address, Account) {
+    let address =
+        Address::find_program_address(&[feature::id().as_ref()], &bpf_loader_upgradeable::id()).0;
+    let mut data = vec![3, 0, 0, 0];
+    data.extend_from_slice(&42u64.to_le_bytes());
+    match upgrade_authority {
+        Some(authority) => {
+            data.push(1);
+            data.extend_from_slice(authority.as_ref());
+        }
+        None => data.extend_from_slice(&[0; 33]),
+    }
+    let mut account = Account::new(1_000_000, data.len(), &bpf_loader_upgradeable::id());
+    account.data = data;
+    (address, account)
+}
+
+fn initialize_accounts(
+    upgrade_authority: &Address,
+    program_data: (Address, Account),
+    payer: &Address,
+) -> Vec<(Address, Account)> {
+    vec![
+        (find_config_address().0, Account::default()),
+        (*upgrade_authority, Account::default()),
+        program_data,
+        (
+            *payer,
+            Account::new(1_000_000_000, 0, &system_program::id()),
+        ),
+        keyed_account_for_system_program(),
+        keyed_audit_log_account(),
+    ]
+}
+
+#[test]
+fn fail_initialize_incorrect_upgrade_authority() {
+    let upgrade_authority = Address::new_unique();
+    let payer = Address::new_unique();
+
+    for program_data in [
+        program_data_account(Some(&Address::new_unique())),
+        program_data_account(None),
+    ] {
+        mollusk().process_and_validate_instruction(
+            &initialize_config(&upgrade_authority, &payer, config(upgrade_authority)),
+            &initialize_accounts(&upgrade_authority, program_data, &payer),
+            &[Check::err(ProgramError::IncorrectAuthority)],
+        );
+    }
+}
+
+#[test]
+fn fail_initialize_incorrect_program_data() {
+    let upgrade_authority = Address::new_unique();
+    let payer = Address::new_unique();
+    let (address, mut account) = program_data_account(Some(&upgrade_authority));
+    account.owner = Address::new_unique();
+
+    mollusk().process_and_validate_instruction(
+        &initialize_config(&upgrade_authority, &payer, config(upgrade_authority)),
+        &initialize_accounts(&upgrade_authority, (address, account), &payer),
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn success_initialize() {
+    let upgrade_authority = Address::new_unique();
+    let payer = Address::new_unique();
+    let config = config(Address::new_unique());
+    let expected = config.pack();
+
+    mollusk().process_and_validate_instruction(
+        &initialize_config(&upgrade_authority, &payer, config),
+        &initialize_accounts(
+            &upgrade_authority,
+            program_data_account(Some(&upgrade_authority)),
+            &payer,
+        ),
+        &[
+            Check::success(),
+            Check::account(&find_config_address().0)
+                .owner(&feature::id())
+                .lamports(config_rent())
+                .data(&expected)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_update_incorrect_authority() {
+    let authority = Address::new_unique();
+    let impostor = Address::new_unique();
+    let current = config(authority);
+
+    mollusk().process_and_validate_instruction(
+        &update_config(&impostor, config(impostor)),
+        &[
+            (find_config_address().0, config_account(&current)),
+            (impostor, Account::default()),
+            keyed_audit_log_account(),
+        ],
+        &[Check::err(ProgramError::IncorrectAuthority)],
+    );
+}
+
+#[test]
+fn fail_update_uninitialized() {
+    let authority = Address::new_unique();
+
+    mollusk().process_and_validate_instruction(
+        &update_config(&authority, config(authority)),
+        &[
+            (find_config_address().0, Account::default()),
+            (authority, Account::default()),
+            keyed_audit_log_account(),
+        ],
+        &[Check::err(ProgramError::UninitializedAccount)],
+    );
+}
+
+#[test]
+fn success_update() {
+    let authority = Address::new_unique();
+    let new_config = Config {
+        authority: Address::new_unique(),
+        ..Config::default()
+    };
+    let expected = new_config.pack();
+
+    mollusk().process_and_validate_instruction(
+        &update_config(&authority, new_config),
+        &[
+            (find_config_address().0, config_account(&config(authority))),
+            (authority, Account::default()),
+            keyed_audit_log_account(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&find_config_address().0)
+                .data(&expected)
+                .build(),
+        ],
+    );
+}
+
+/// Configs that must be rejected before they are stored.
+fn invalid_configs(authority: Address) -> Vec<Config> {
+    let zero_window = Config {
+        revoke_guard_window: 0,
+        ..config(authority)
+    };
+    let mut duplicate_recipient = config(authority);
+    duplicate_recipient.refund_recipients[1] = duplicate_recipient.refund_recipients[0];
+    let mut incinerator_recipient = config(authority);
+    incinerator_recipient.refund_recipients[1] = incinerator::id();
+    vec![zero_window, duplicate_recipient, incinerator_recipient]
+}
+
+#[test]
+fn fail_initialize_invalid_config() {
+    let upgrade_authority = Address::new_unique();
+    let payer = Address::new_unique();
+
+    for config in invalid_configs(upgrade_authority) {
+        mollusk().process_and_validate_instruction(
+            &initialize_config(&upgrade_authority, &payer, config),
+            &initialize_accounts(
+                &upgrade_authority,
+                program_data_account(Some(&upgrade_authority)),
+                &payer,
+            ),
+            &[Check::err(FeatureGateError::InvalidConfig.into())],
+        );
+    }
+}
+
+#[test]
+fn fail_update_invalid_config() {
+    let authority = Address::new_unique();
+
+    for new_config in invalid_configs(authority) {
+        mollusk().process_and_validate_instruction(
+            &update_config(&authority, new_config),
+            &[
+                (find_config_address().0, config_account(&config(authority))),
+                (authority, Account::default()),
+                keyed_audit_log_account(),
+            ],
+            &[Check::err(FeatureGateError::InvalidConfig.into())],
+        );
+    }
+}
+
+#[test]
+fn fail_revoke_refund_recipient_not_allowed() {
+    let feature_id = Address::new_unique();
+    let recipient = Address::new_unique();
+    let config = config(Address::new_unique());
+
+    mollusk().process_and_validate_instruction(
+        &revoke_pending_activation_with_refund(&feature_id, &recipient),
+        &[
+            (feature_id, FeatureAccountBuilder::pending().build()),
+            (recipient, Account::default()),
+            keyed_account_for_system_program(),
+            keyed_audit_log_account(),
+            (find_config_address().0, config_account(&config)),
+        ],
+        &[Check::err(ProgramError::Custom(
+            FeatureGateError::InvalidRefundRecipient as u32,
+        ))],
+    );
+}
+
+#[test]
+fn success_revoke_with_refund() {
+    let feature_id = Address::new_unique();
+    let config = config(Address::new_unique());
+    let recipient = config.refund_recipients[0];
+
+    mollusk().process_and_validate_instruction(
+        &revoke_pending_activation_with_refund(&feature_id, &recipient),
+        &[
+            (feature_id, FeatureAccountBuilder::pending().build()),
+            (recipient, Account::default()),
+            keyed_account_for_system_program(),
+            keyed_audit_log_account(),
+            (find_config_address().0, config_account(&config)),
+        ],
+        &[
+            Check::success(),
+            Check::account(&feature_id).closed().build(),
+            Check::account(&recipient).lamports(feature_rent()).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_revoke_with_refund_config_before_audit_log() {
+    let feature_id = Address::new_unique();
+    let config = config(Address::new_unique());
+    let recipient = config.refund_recipients[0];
+
+    // The config and audit log are found by their addresses, in any order.
+    let mut instruction = revoke_pending_activation_with_refund(&feature_id, &recipient);
+    instruction.accounts.swap(3, 4);
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (feature_id, FeatureAccountBuilder::pending().build()),
+            (recipient, Account::default()),
+            keyed_account_for_system_program(),
+            (find_config_address().0, config_account(&config)),
+            keyed_audit_log_account(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&feature_id).closed().build(),
+            Check::account(&recipient).lamports(feature_rent()).build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_revoke_unknown_optional_account() {
+    let feature_id = Address::new_unique();
+    let config = config(Address::new_unique());
+    let recipient = config.refund_recipients[0];
+    let impostor = Address::new_unique();
+
+    let mut instruction = revoke_pending_activation_with_refund(&feature_id, &recipient);
+    instruction.accounts[4].pubkey = impostor;
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (feature_id, FeatureAccountBuilder::pending().build()),
+            (recipient, Account::default()),
+            keyed_account_for_system_program(),
+            keyed_audit_log_account(),
+            (impostor, config_account(&config)),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+fn guard_account(feature_id: &Address) -> Account {
+    let mut guard = Account::new(
+        Rent::default().minimum_balance(StakeGuard::LEN),
+        StakeGuard::LEN,
+        &feature::id(),
+    );
+    guard.data = StakeGuard {
+        feature_id: *feature_id,
+        threshold_bps: 6_667,
+        epoch: 0,
+        supporting_stake: 0,
+    }
+    .pack();
+    guard
+}
+
+#[test]
+fn fail_revoke_unsupported_outside_configured_window() {
+    let feature_id = Address::new_unique();
+    let config = config(Address::new_unique());
+    let mut mollusk = mollusk();
+    mollusk.epoch_stake.insert(Address::new_unique(), 100);
+    // Inside the default window, but outside the configured one.
+    mollusk.warp_to_slot(400_000);
+
+    mollusk.process_and_validate_instruction(
+        &revoke_unsupported_activation(&feature_id),
+        &[
+            (feature_id, FeatureAccountBuilder::pending().build()),
+            (
+                find_stake_guard_address(&feature_id).0,
+                guard_account(&feature_id),
+            ),
+            keyed_incinerator_account(),
+            (find_config_address().0, config_account(&config)),
+            keyed_audit_log_account(),
+        ],
+        &[Check::err(ProgramError::Custom(
+            FeatureGateError::RevokeWindowClosed as u32,
+        ))],
+    );
+}
+
+#[test]
+fn success_revoke_unsupported_with_tombstone() {
+    let feature_id = Address::new_unique();
+    let config = config(Address::new_unique());
+    let mut mollusk = mollusk();
+    mollusk.epoch_stake.insert(Address::new_unique(), 100);
+    mollusk.warp_to_slot(431_000);
+
+    let feature_account = FeatureAccountBuilder::pending()
+        .lamports(feature_rent() + 1_000)
+        .build();
+
+    let expected = Tombstone {
+        revoked_at: 431_000,
+    }
+    .pack();
+    mollusk.process_and_validate_instruction(
+        &revoke_unsupported_activation(&feature_id),
+        &[
+            (feature_id, feature_account),
+            (
+                find_stake_guard_address(&feature_id).0,
+                guard_account(&feature_id),
+            ),
+            keyed_incinerator_account(),
+            (find_config_address().0, config_account(&config)),
+            keyed_audit_log_account(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&feature_id)
+                .owner(&feature::id())
+                .lamports(feature_rent())
+                .data(&expected)
+                .build(),
+            Check::account(&find_stake_guard_address(&feature_id).0)
+                .closed()
+                .build(),
+            Check::account(&incinerator::id())
+                .lamports(Rent::default().minimum_balance(StakeGuard::LEN) + 1_000)
+                .build(),
+        ],
+    );
+}
@@ -0,0 +1,209 @@
+//! Features at PDAs of a governance program.
+//!
+//! A mock governance program, registered as a builtin, derives feature IDs
+//! from `[b"feature", name]` and signs for them the way an on-chain program
+//! does with `invoke_signed`. No feature keypair exists at any point.
+
+use {
+    mollusk_svm::{
+        program::{keyed_account_for_system_program, Builtin},
+        result::Check,
+    },
+    solana_account::Account,
+    solana_address::Address,
+    solana_feature_gate_interface::activate_with_lamports,
+    solana_feature_gate_program::{
+        error::FeatureGateError, instruction::revoke_pending_activation,
+        state::find_audit_log_address,
+    },
+    solana_feature_gate_test_utils::{
+        feature_rent, keyed_audit_log_account, keyed_incinerator_account, keyed_program_account,
+        mollusk, simulator::ActivationSimulator, FeatureAccountBuilder,
+    },
+    solana_instruction::{error::InstructionError, AccountMeta, Instruction},
+    solana_program_error::ProgramError,
+    solana_program_runtime::declare_process_instruction,
+    solana_sdk_ids::{feature, incinerator, system_program},
+    solana_transaction_error::TransactionError,
+};
+
+const GOVERNANCE: Address = Address::new_from_array([7; 32]);
+const OTHER_GOVERNANCE: Address = Address::new_from_array([8; 32]);
+const NAME: &[u8] = b"core-bpf";
+
+const QUEUE: u8 = 0;
+const REVOKE: u8 = 1;
+
+declare_process_instruction!(MockGovernance, 0, |invoke_context| {
+    let transaction_context = &invoke_context.transaction_context;
+    let instruction_context = transaction_context.get_current_instruction_context()?;
+    let program_id = *instruction_context.get_program_key()?;
+    let feature_id = *instruction_context.get_key_of_instruction_account(0)?;
+    let (op, name) = instruction_context
+        .get_instruction_data()
+        .split_first()
+        .map(|(op, name)| (*op, name.to_vec()))
+        .ok_or(InstructionError::InvalidInstructionData)?;
+
+    // The builtin equivalent of `invoke_signed` with the seeds of its own
+    // feature PDA.
+    let (signer, _) = Address::find_program_address(&[b"feature", &name], &program_id);
+    match op {
+        QUEUE => {
+            let payer = *instruction_context.get_key_of_instruction_account(1)?;
+            for instruction in activate_with_lamports(&feature_id, &payer, feature_rent()) {
+                invoke_context.native_invoke(instruction, &[signer])?;
+            }
+            Ok(())
+        }
+        REVOKE => invoke_context.native_invoke(revoke_pending_activation(&feature_id), &[signer]),
+        _ => Err(InstructionError::InvalidInstructionData),
+    }
+});
+
+fn governed_feature(program_id: &Address) -> Address {
+    Address::find_program_address(&[b"feature", NAME], program_id).0
+}
+
+fn governance_instruction(program_id: Address, op: u8, accounts: Vec<AccountMeta>) -> Instruction {
+    Instruction {
+        program_id,
+        accounts: accounts
+            .into_iter()
+            .chain([
+                AccountMeta::new_readonly(feature::id(), false),
+                AccountMeta::new_readonly(system_program::id(), false),
+            ])
+            .collect(),
+        data: [&[op], NAME].concat(),
+    }
+}
+
+fn queue(program_id: Address, feature_id: &Address, payer: &Address) -> Instruction {
+    governance_instruction(
+        program_id,
+        QUEUE,
+        vec![
+            AccountMeta::new(*feature_id, false),
+            AccountMeta::new(*payer, true),
+        ],
+    )
+}
+
+fn revoke(program_id: Address, feature_id: &Address) -> Instruction {
+    governance_instruction(
+        program_id,
+        REVOKE,
+        vec![
+            AccountMeta::new(*feature_id, false),
+            AccountMeta::new(incinerator::id(), false),
+            AccountMeta::new(find_audit_log_address().0, false),
+        ],
+    )
+}
+
+fn add_governance(mollusk: &mut mollusk_svm::Mollusk) {
+    for program_id in [GOVERNANCE, OTHER_GOVERNANCE] {
+        mollusk.program_cache.add_builtin(Builtin {
+            program_id,
+            name: "mock_governance",
+            entrypoint: MockGovernance::vm,
+        });
+    }
+}
+
+fn revoke_accounts(feature_id: Address) -> Vec<(Address, Account)> {
+    vec![
+        (feature_id, FeatureAccountBuilder::pending().build()),
+        keyed_incinerator_account(),
+        keyed_account_for_system_program(),
+        keyed_audit_log_account(),
+        keyed_program_account(),
+    ]
+}
+
+#[test]
+fn fail_revoke_without_governance() {
+    let feature_id = governed_feature(&GOVERNANCE);
+
+    // Nobody can sign for the PDA in a transaction.
+    let mut instruction = revoke_pending_activation(&feature_id);
+    instruction.accounts[0].is_signer = false;
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &revoke_accounts(feature_id),
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_revoke_by_other_program() {
+    let mut mollusk = mollusk();
+    add_governance(&mut mollusk);
+    let feature_id = governed_feature(&GOVERNANCE);
+
+    mollusk.process_and_validate_instruction(
+        &revoke(OTHER_GOVERNANCE, &feature_id),
+        &revoke_accounts(feature_id),
+        &[Check::instruction_err(
+            InstructionError::PrivilegeEscalation,
+        )],
+    );
+}
+
+#[test]
+fn success_revoke_by_governance() {
+    let mut mollusk = mollusk();
+    add_governance(&mut mollusk);
+    let feature_id = governed_feature(&GOVERNANCE);
+
+    mollusk.process_and_validate_instruction(
+        &revoke(GOVERNANCE, &feature_id),
+        &revoke_accounts(feature_id),
+        &[
+            Check::success(),
+            Check::account(&feature_id).closed().build(),
+            Check::account(&incinerator::id())
+                .lamports(feature_rent())
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn governance_owns_feature_lifecycle() {
+    let mut simulator = ActivationSimulator::new();
+    add_governance(simulator.mollusk_mut());
+    let payer = Address::new_unique();
+    simulator.fund(payer, 1_000_000_000);
+    let feature_id = governed_feature(&GOVERNANCE);
+
+    // Queue and revoke, then queue again.
+    simulator
+        .process_transaction(&[queue(GOVERNANCE, &feature_id, &payer)])
+        .unwrap();
+    assert_eq!(simulator.feature(&feature_id).unwrap().activated_at, None);
+    simulator
+        .process_transaction(&[revoke(GOVERNANCE, &feature_id)])
+        .unwrap();
+    assert!(simulator.account(&feature_id).is_none());
+    simulator
+        .process_transaction(&[queue(GOVERNANCE, &feature_id, &payer)])
+        .unwrap();
+
+    let boundary = simulator.next_boundary();
+    assert_eq!(simulator.advance_to_next_epoch(), vec![feature_id]);
+    assert_eq!(
+        simulator.feature(&feature_id).unwrap().activated_at,
+        Some(boundary)
+    );
+
+    assert_eq!(
+        simulator.process_transaction(&[revoke(GOVERNANCE, &feature_id)]),
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(FeatureGateError::FeatureAlreadyActivated as u32),
+        ))
+    );
+}
@@ -0,0 +1,302 @@
+use {
+    proptest::{collection::vec, prelude::*},
+    solana_account::Account,
+    solana_address::Address,
+    solana_feature_gate_program::{
+        instruction::{
+            extend_audit_log, initialize_audit_log, initialize_bundle, initialize_config,
+            initialize_stake_guard, queue_bundle, release_scheduled_activation, revoke_bundle,
+            revoke_pending_activation, revoke_unsupported_activation, schedule_activation,
+            signal_support, tally_support, update_config,
+        },
+        state::{
+            find_bundle_address, AuditLog, Bundle, Config, ScheduledActivation, StakeGuard,
+            SupportSignal,
+        },
+    },
+    solana_feature_gate_test_utils::{
+        feature_rent, invariants::check_invariants, keyed_system_program_account, mollusk,
+        vote_account, FeatureAccountBuilder,
+    },
+    solana_instruction::{AccountMeta, Instruction},
+    solana_rent::Rent,
+    solana_sdk_ids::{feature, system_program},
+    std::collections::HashSet,
+};
+
+fn address() -> impl Strategy<Value = Address> {
+    any::<[u8; 32]>().prop_map(Address::new_from_array)
+}
+
+fn owner() -> impl Strategy<Value = Address> {
+    prop_oneof![
+        3 => Just(feature::id()),
+        1 => Just(system_program::id()),
+        1 => address(),
+    ]
+}
+
+fn data() -> impl Strategy<Value = Vec<u8>> {
+    prop_oneof![
+        3 => Just(FeatureAccountBuilder::pending().build().data),
+        1 => any::<u64>().prop_map(|slot| FeatureAccountBuilder::active(slot).build().data),
+        1 => vec(any::<u8>(), 0..16),
+    ]
+}
+
+fn lamports() -> impl Strategy<Value = u64> {
+    prop_oneof![Just(0), Just(feature_rent()), any::<u64>()]
+}
+
+prop_compose! {
+    fn account()(owner in owner(), data in data(), lamports in lamports()) -> Account {
+        Account {
+            lamports,
+            data,
+            owner,
+            ..Account::default()
+        }
+    }
+}
+
+prop_compose! {
+    /// An account meta and its account, usually keyed by `well_known` and
+    /// holding `valid`.
+    fn keyed_account(well_known: Address, valid: Account)(
+        address in prop_oneof![3 => Just(well_known), 1 => address()],
+        account in prop_oneof![3 => Just(valid), 1 => account()],
+        is_signer in prop::bool::weighted(0.75),
+        is_writable in prop::bool::weighted(0.75),
+    ) -> (AccountMeta, Account) {
+        let account = if address == system_program::id() {
+            keyed_system_program_account().1
+        } else {
+            account
+        };
+        (AccountMeta { pubkey: address, is_signer, is_writable }, account)
+    }
+}
+
+fn funded_account() -> Account {
+    Account::new(1_000_000_000, 0, &system_program::id())
+}
+
+fn program_account(data: Vec<u8>) -> Account {
+    let mut account = Account::new(
+        Rent::default().minimum_balance(data.len()),
+        data.len(),
+        &feature::id(),
+    );
+    account.data = data;
+    account
+}
+
+/// One instruction of every kind, built with valid arguments, and accounts
+/// for its account metas, in order, that it accepts.
+fn instructions() -> Vec<(Instruction, Vec<Account>)> {
+    let feature = Address::new_unique();
+    let vote = Address::new_unique();
+    let payer = Address::new_unique();
+    let authority = Address::new_unique();
+    let members = [feature, Address::new_unique()];
+    let name = [1; 32];
+    let (bundle, _) = find_bundle_address(&authority, &name);
+
+    let pending = || FeatureAccountBuilder::pending().build();
+    let system = || keyed_system_program_account().1;
+    let config = Config {
+        authority,
+        ..Config::default()
+    };
+    let stake_guard = program_account(
+        StakeGuard {
+            feature_id: feature,
+            threshold_bps: 5_000,
+            epoch: 0,
+            supporting_stake: 0,
+        }
+        .pack(),
+    );
+    let support_signal = program_account(
+        SupportSignal {
+            feature_id: feature,
+            vote_account: vote,
+            major: 3,
+            minor: 1,
+            patch: 0,
+            signaled_at: 0,
+            tallied_epoch: None,
+        }
+        .pack(),
+    );
+    let mut audit_log = vec![0; AuditLog::space(4)];
+    audit_log[..AuditLog::LEN].copy_from_slice(&AuditLog::default().pack());
+    let bundle_account = program_account(Bundle::new(authority, name, &members).unwrap().pack());
+
+    vec![
+        (
+            revoke_pending_activation(&feature),
+            vec![pending(), Account::default(), system(), Account::default()],
+        ),
+        (
+            signal_support(&feature, &vote, &authority, &payer, 3, 1, 0),
+            vec![
+                Account::default(),
+                pending(),
+                vote_account(&vote, &authority, &authority),
+                Account::default(),
+                funded_account(),
+                system(),
+            ],
+        ),
+        (
+            initialize_stake_guard(&feature, &payer, 5_000),
+            vec![Account::default(), pending(), funded_account(), system()],
+        ),
+        (
+            tally_support(&feature, &[vote]),
+            vec![stake_guard.clone(), support_signal],
+        ),
+        (
+            revoke_unsupported_activation(&feature),
+            vec![
+                pending(),
+                stake_guard,
+                Account::default(),
+                Account::default(),
+                Account::default(),
+            ],
+        ),
+        (
+            initialize_config(&authority, &payer, config.clone()),
+            vec![
+                Account::default(),
+                Account::default(),
+                Account::default(),
+                funded_account(),
+                system(),
+                Account::default(),
+            ],
+        ),
+        (
+            update_config(&authority, config.clone()),
+            vec![
+                program_account(config.pack()),
+                Account::default(),
+                Account::default(),
+            ],
+        ),
+        (
+            schedule_activation(&feature, &payer, 1),
+            vec![
+                Account::default(),
+                funded_account(),
+                system(),
+                Account::default(),
+            ],
+        ),
+        (
+            release_scheduled_activation(&feature),
+            vec![program_account(
+                ScheduledActivation {
+                    not_before_epoch: 1,
+                }
+                .pack(),
+            )],
+        ),
+        (
+            initialize_audit_log(&payer, 4),
+            vec![Account::default(), funded_account(), system()],
+        ),
+        (
+            extend_audit_log(&payer, 4),
+            vec![program_account(audit_log), funded_account(), system()],
+        ),
+        (
+            initialize_bundle(&authority, &payer, &name, &members),
+            vec![
+                Account::default(),
+                Account::default(),
+                funded_account(),
+                system(),
+                pending(),
+                pending(),
+            ],
+        ),
+        (
+            queue_bundle(&bundle, &payer, &members),
+            vec![
+                bundle_account.clone(),
+                funded_account(),
+                system(),
+                Account::default(),
+                Account::default(),
+                Account::default(),
+            ],
+        ),
+        (
+            revoke_bundle(&bundle, &members),
+            vec![
+                bundle_account,
+                Account::default(),
+                system(),
+                Account::default(),
+                pending(),
+                pending(),
+            ],
+        ),
+    ]
+}
+
+prop_compose! {
+    /// An instruction of any kind with arbitrary accounts, flags and account
+    /// count, usually keyed like the instruction expects and holding the
+    /// accounts it accepts. The data is usually the instruction's
+    /// discriminator followed by a payload of the right length, either the
+    /// original arguments or random bytes. The weights keep a few percent of
+    /// cases valid, so that successful instructions are checked too.
+    fn case()((instruction, valid_accounts) in prop::sample::select(instructions()))(
+        accounts in instruction
+            .accounts
+            .iter()
+            .zip(valid_accounts)
+            .map(|(meta, valid)| keyed_account(meta.pubkey, valid))
+            .collect::<Vec<_>>(),
+        len in prop_oneof![
+            1 => 0..=instruction.accounts.len(),
+            3 => Just(instruction.accounts.len()),
+        ],
+        data in prop_oneof![
+            3 => Just(instruction.data.clone()),
+            2 => vec(any::<u8>(), instruction.data.len() - 1)
+                .prop_map(move |payload| [&instruction.data[..1], &payload].concat()),
+            1 => vec(any::<u8>(), 0..8),
+        ],
+    ) -> (Instruction, Vec<(Address, Account)>) {
+        let (metas, mut accounts): (Vec<_>, Vec<_>) = accounts
+            .into_iter()
+            .take(len)
+            .map(|(meta, account)| (meta.clone(), (meta.pubkey, account)))
+            .unzip();
+        // An account passed twice is given once.
+        let mut seen = HashSet::new();
+        accounts.retain(|(address, _)| seen.insert(*address));
+        (
+            Instruction::new_with_bytes(feature::id(), &data, metas),
+            accounts,
+        )
+    }
+}
+
+proptest! {
+    #[test]
+    fn process_upholds_invariants((instruction, accounts) in case()) {
+        let result = mollusk().process_instruction(&instruction, &accounts);
+        if let Err(violation) = check_invariants(&instruction, &accounts, &result) {
+            return Err(TestCaseError::fail(format!(
+                "{violation} ({:?})",
+                result.program_result
+            )));
+        }
+    }
+}
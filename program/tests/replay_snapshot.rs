@@ -0,0 +1,70 @@
+use {
+    mollusk_svm::result::Check,
+    solana_feature_gate_client::snapshot::{ClusterSnapshot, FeatureStatus},
+    solana_feature_gate_program::{
+        error::FeatureGateError, instruction::revoke_pending_activation,
+    },
+    solana_feature_gate_test_utils::{mollusk, revoke_accounts},
+    solana_program_error::ProgramError,
+    solana_sdk_ids::incinerator,
+};
+
+/// Revokes every feature in an exported cluster snapshot, checking that
+/// pending features are revoked and active features are rejected.
+fn replay_revoke(snapshot: &ClusterSnapshot) {
+    let mollusk = mollusk();
+
+    for (feature, account) in snapshot.keyed_accounts() {
+        let lamports = account.lamports;
+        let accounts = revoke_accounts(feature, account);
+        let instruction = revoke_pending_activation(&feature);
+
+        match snapshot.get(&feature).unwrap().status() {
+            FeatureStatus::Pending => {
+                mollusk.process_and_validate_instruction(
+                    &instruction,
+                    &accounts,
+                    &[
+                        Check::success(),
+                        Check::account(&feature).closed().build(),
+                        Check::account(&incinerator::id())
+                            .lamports(lamports)
+                            .build(),
+                    ],
+                );
+            }
+            FeatureStatus::Active { .. } => {
+                mollusk.process_and_validate_instruction(
+                    &instruction,
+                    &accounts,
+                    &[Check::err(ProgramError::Custom(
+                        FeatureGateError::FeatureAlreadyActivated as u32,
+                    ))],
+                );
+            }
+            status => panic!("{feature} is {}, not pending or active", status.label()),
+        }
+    }
+}
+
+#[test]
+fn replay_json_snapshot() {
+    let snapshot = ClusterSnapshot::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/cluster_snapshot.json"
+    ))
+    .unwrap();
+    assert_eq!(snapshot.accounts.len(), 2);
+    replay_revoke(&snapshot);
+}
+
+#[test]
+fn replay_csv_snapshot() {
+    let snapshot = ClusterSnapshot::load(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/cluster_snapshot.csv"
+    ))
+    .unwrap();
+    assert_eq!(snapshot.accounts.len(), 2);
+    replay_revoke(&snapshot);
+}
@@ -1,20 +1,17 @@
-mod setup;
-
 use {
-    mollusk_svm::{program::keyed_account_for_system_program, result::Check},
-    setup::{active_feature_account, pending_feature_account, setup},
-    solana_account::{Account, WritableAccount},
+    mollusk_svm::result::Check,
     solana_address::Address,
     solana_feature_gate_program::{
         error::FeatureGateError, instruction::revoke_pending_activation,
     },
+    solana_feature_gate_test_utils::{mollusk, revoke_accounts, FeatureAccountBuilder},
+    solana_instruction::error::InstructionError,
     solana_program_error::ProgramError,
-    solana_sdk_ids::incinerator,
 };
 
 #[test]
 fn fail_feature_not_signer() {
-    let mollusk = setup();
+    let mollusk = mollusk();
     let feature = Address::new_unique();
 
     let mut instruction = revoke_pending_activation(&feature);
@@ -22,67 +19,65 @@ fn fail_feature_not_signer() {
 
     mollusk.process_and_validate_instruction(
         &instruction,
-        &[
-            (feature, pending_feature_account()),
-            (incinerator::id(), Account::default()),
-            keyed_account_for_system_program(),
-        ],
+        &revoke_accounts(feature, FeatureAccountBuilder::pending().build()),
         &[Check::err(ProgramError::MissingRequiredSignature)],
     );
 }
 
+#[test]
+fn fail_feature_not_writable() {
+    let mollusk = mollusk();
+    let feature = Address::new_unique();
+
+    let mut instruction = revoke_pending_activation(&feature);
+    instruction.accounts[0].is_writable = false;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &revoke_accounts(feature, FeatureAccountBuilder::pending().build()),
+        &[Check::instruction_err(
+            InstructionError::ReadonlyLamportChange,
+        )],
+    );
+}
+
 #[test]
 fn fail_feature_incorrect_owner() {
-    let mollusk = setup();
+    let mollusk = mollusk();
     let feature = Address::new_unique();
 
     // Set up a feature account with incorrect owner.
-    let mut feature_account = pending_feature_account();
-    feature_account.set_owner(Address::new_unique());
+    let feature_account = FeatureAccountBuilder::pending()
+        .owner(Address::new_unique())
+        .build();
 
     mollusk.process_and_validate_instruction(
         &revoke_pending_activation(&feature),
-        &[
-            (feature, feature_account),
-            (incinerator::id(), Account::default()),
-            keyed_account_for_system_program(),
-        ],
+        &revoke_accounts(feature, feature_account),
         &[Check::err(ProgramError::InvalidAccountOwner)],
     );
 }
 
 #[test]
 fn fail_feature_invalid_data() {
-    let mollusk = setup();
+    let mollusk = mollusk();
     let feature = Address::new_unique();
 
-    // Set up a feature account with invalid data.
-    let mut feature_account = pending_feature_account();
-    feature_account.data = vec![2; 8];
-
     mollusk.process_and_validate_instruction(
         &revoke_pending_activation(&feature),
-        &[
-            (feature, feature_account),
-            (incinerator::id(), Account::default()),
-            keyed_account_for_system_program(),
-        ],
+        &revoke_accounts(feature, FeatureAccountBuilder::malformed().build()),
         &[Check::err(ProgramError::InvalidAccountData)],
     );
 }
 
 #[test]
 fn fail_feature_already_activated() {
-    let mollusk = setup();
+    let mollusk = mollusk();
     let feature = Address::new_unique();
 
     mollusk.process_and_validate_instruction(
         &revoke_pending_activation(&feature),
-        &[
-            (feature, active_feature_account()),
-            (incinerator::id(), Account::default()),
-            keyed_account_for_system_program(),
-        ],
+        &revoke_accounts(feature, FeatureAccountBuilder::active(45).build()),
         &[Check::err(ProgramError::Custom(
             FeatureGateError::FeatureAlreadyActivated as u32,
         ))],
@@ -91,21 +86,20 @@ fn fail_feature_already_activated() {
 
 #[test]
 fn success() {
-    let mollusk = setup();
+    let mollusk = mollusk();
     let feature = Address::new_unique();
 
-    mollusk.process_and_validate_instruction(
+    let result = mollusk.process_and_validate_instruction(
         &revoke_pending_activation(&feature),
-        &[
-            (feature, pending_feature_account()),
-            (incinerator::id(), Account::default()),
-            keyed_account_for_system_program(),
-        ],
+        &revoke_accounts(feature, FeatureAccountBuilder::pending().build()),
         &[
             Check::success(),
-            Check::compute_units(2_723),
             // Confirm feature account was closed.
             Check::account(&feature).closed().build(),
         ],
     );
+
+    // The config and audit log are found by their precomputed addresses, so
+    // revoking costs no more than it did before either existed.
+    assert!(result.compute_units_consumed <= 2_723);
 }
@@ -0,0 +1,211 @@
+use {
+    mollusk_svm::{program::keyed_account_for_system_program, result::Check},
+    solana_account::Account,
+    solana_address::Address,
+    solana_feature_gate_program::{
+        error::FeatureGateError,
+        instruction::{
+            release_scheduled_activation, revoke_pending_activation, schedule_activation,
+        },
+        state::ScheduledActivation,
+    },
+    solana_feature_gate_test_utils::{
+        feature_rent, keyed_audit_log_account, mollusk, revoke_accounts,
+        simulator::ActivationSimulator, FeatureAccountBuilder,
+    },
+    solana_instruction::error::InstructionError,
+    solana_program_error::ProgramError,
+    solana_sdk_ids::{feature, incinerator, system_program},
+    solana_transaction_error::TransactionError,
+};
+
+const NOT_BEFORE_EPOCH: u64 = 5;
+
+fn scheduled_account(not_before_epoch: u64) -> Account {
+    FeatureAccountBuilder::pending()
+        .data(ScheduledActivation { not_before_epoch }.pack())
+        .build()
+}
+
+fn schedule_accounts(feature_id: &Address, payer: &Address) -> Vec<(Address, Account)> {
+    vec![
+        (*feature_id, Account::default()),
+        (
+            *payer,
+            Account::new(1_000_000_000, 0, &system_program::id()),
+        ),
+        keyed_account_for_system_program(),
+        keyed_audit_log_account(),
+    ]
+}
+
+#[test]
+fn fail_schedule_feature_not_signer() {
+    let feature_id = Address::new_unique();
+    let payer = Address::new_unique();
+    let mut instruction = schedule_activation(&feature_id, &payer, NOT_BEFORE_EPOCH);
+    instruction.accounts[0].is_signer = false;
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &schedule_accounts(&feature_id, &payer),
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_schedule_already_queued() {
+    let feature_id = Address::new_unique();
+    let payer = Address::new_unique();
+    let mut accounts = schedule_accounts(&feature_id, &payer);
+    accounts[0].1 = FeatureAccountBuilder::pending().build();
+
+    mollusk().process_and_validate_instruction(
+        &schedule_activation(&feature_id, &payer, NOT_BEFORE_EPOCH),
+        &accounts,
+        &[Check::err(ProgramError::AccountAlreadyInitialized)],
+    );
+}
+
+#[test]
+fn success_schedule() {
+    let feature_id = Address::new_unique();
+    let payer = Address::new_unique();
+    let mut accounts = schedule_accounts(&feature_id, &payer);
+    // Lamports sent to the address beforehand are kept.
+    accounts[0].1 = Account::new(1_000, 0, &system_program::id());
+
+    let expected = ScheduledActivation {
+        not_before_epoch: NOT_BEFORE_EPOCH,
+    }
+    .pack();
+    mollusk().process_and_validate_instruction(
+        &schedule_activation(&feature_id, &payer, NOT_BEFORE_EPOCH),
+        &accounts,
+        &[
+            Check::success(),
+            Check::account(&feature_id)
+                .owner(&feature::id())
+                .lamports(feature_rent())
+                .data(&expected)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_release_not_due() {
+    let feature_id = Address::new_unique();
+    let mut mollusk = mollusk();
+    let epoch_schedule = mollusk.sysvars.epoch_schedule.clone();
+    mollusk.warp_to_slot(epoch_schedule.get_last_slot_in_epoch(NOT_BEFORE_EPOCH - 2));
+
+    mollusk.process_and_validate_instruction(
+        &release_scheduled_activation(&feature_id),
+        &[(feature_id, scheduled_account(NOT_BEFORE_EPOCH))],
+        &[Check::err(ProgramError::Custom(
+            FeatureGateError::ActivationNotDue as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_release_not_scheduled() {
+    let feature_id = Address::new_unique();
+
+    for (account, error) in [
+        (
+            FeatureAccountBuilder::pending().build(),
+            ProgramError::InvalidAccountData,
+        ),
+        (
+            Account {
+                owner: Address::new_unique(),
+                ..scheduled_account(0)
+            },
+            ProgramError::InvalidAccountOwner,
+        ),
+    ] {
+        mollusk().process_and_validate_instruction(
+            &release_scheduled_activation(&feature_id),
+            &[(feature_id, account)],
+            &[Check::err(error)],
+        );
+    }
+}
+
+#[test]
+fn success_release() {
+    let feature_id = Address::new_unique();
+    let mut mollusk = mollusk();
+    let epoch_schedule = mollusk.sysvars.epoch_schedule.clone();
+    mollusk.warp_to_slot(epoch_schedule.get_first_slot_in_epoch(NOT_BEFORE_EPOCH - 1));
+
+    let expected = FeatureAccountBuilder::pending().build().data;
+    mollusk.process_and_validate_instruction(
+        &release_scheduled_activation(&feature_id),
+        &[(feature_id, scheduled_account(NOT_BEFORE_EPOCH))],
+        &[
+            Check::success(),
+            Check::account(&feature_id)
+                .owner(&feature::id())
+                .lamports(feature_rent())
+                .data(&expected)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn success_revoke_scheduled() {
+    let feature_id = Address::new_unique();
+
+    mollusk().process_and_validate_instruction(
+        &revoke_pending_activation(&feature_id),
+        &revoke_accounts(feature_id, scheduled_account(NOT_BEFORE_EPOCH)),
+        &[
+            Check::success(),
+            Check::account(&feature_id).closed().build(),
+            Check::account(&incinerator::id())
+                .lamports(feature_rent())
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn scheduled_feature_activates_at_target_epoch() {
+    let mut simulator = ActivationSimulator::new();
+    let payer = Address::new_unique();
+    let feature_id = Address::new_unique();
+    simulator.fund(payer, 1_000_000_000);
+
+    simulator
+        .process_transaction(&[schedule_activation(&feature_id, &payer, NOT_BEFORE_EPOCH)])
+        .unwrap();
+
+    // The runtime ignores the feature while it is scheduled.
+    while simulator.epoch() < NOT_BEFORE_EPOCH - 2 {
+        assert!(simulator.advance_to_next_epoch().is_empty());
+        assert_eq!(
+            simulator.process_transaction(&[release_scheduled_activation(&feature_id)]),
+            Err(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(FeatureGateError::ActivationNotDue as u32),
+            ))
+        );
+    }
+
+    assert!(simulator.advance_to_next_epoch().is_empty());
+    simulator
+        .process_transaction(&[release_scheduled_activation(&feature_id)])
+        .unwrap();
+
+    let boundary = simulator.next_boundary();
+    assert_eq!(simulator.epoch().saturating_add(1), NOT_BEFORE_EPOCH);
+    assert_eq!(simulator.advance_to_next_epoch(), vec![feature_id]);
+    assert_eq!(
+        simulator.feature(&feature_id).unwrap().activated_at,
+        Some(boundary)
+    );
+}
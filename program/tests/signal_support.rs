@@ -0,0 +1,293 @@
+use {
+    mollusk_svm::{program::keyed_account_for_system_program, result::Check},
+    solana_account::Account,
+    solana_address::Address,
+    solana_feature_gate_program::{
+        error::FeatureGateError,
+        instruction::signal_support,
+        state::{find_support_signal_address, SupportSignal},
+    },
+    solana_feature_gate_test_utils::{mollusk, vote_account, FeatureAccountBuilder},
+    solana_program_error::ProgramError,
+    solana_rent::Rent,
+    solana_sdk_ids::{feature, system_program},
+};
+
+struct Setup {
+    feature: Address,
+    vote: Address,
+    node: Address,
+    withdrawer: Address,
+    payer: Address,
+}
+
+impl Setup {
+    fn new() -> Self {
+        Self {
+            feature: Address::new_unique(),
+            vote: Address::new_unique(),
+            node: Address::new_unique(),
+            withdrawer: Address::new_unique(),
+            payer: Address::new_unique(),
+        }
+    }
+
+    fn support_signal(&self) -> Address {
+        find_support_signal_address(&self.feature, &self.vote).0
+    }
+
+    fn accounts(&self, feature_account: Account, signal: Account) -> Vec<(Address, Account)> {
+        vec![
+            (self.support_signal(), signal),
+            (self.feature, feature_account),
+            (
+                self.vote,
+                vote_account(&self.vote, &self.node, &self.withdrawer),
+            ),
+            (self.node, Account::default()),
+            (
+                self.payer,
+                Account::new(1_000_000_000, 0, &system_program::id()),
+            ),
+            keyed_account_for_system_program(),
+        ]
+    }
+
+    fn pending_accounts(&self) -> Vec<(Address, Account)> {
+        self.accounts(FeatureAccountBuilder::pending().build(), Account::default())
+    }
+}
+
+fn signal_rent() -> u64 {
+    Rent::default().minimum_balance(SupportSignal::LEN)
+}
+
+#[test]
+fn fail_authority_not_signer() {
+    let setup = Setup::new();
+    let mut instruction = signal_support(
+        &setup.feature,
+        &setup.vote,
+        &setup.node,
+        &setup.payer,
+        3,
+        1,
+        0,
+    );
+    instruction.accounts[3].is_signer = false;
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &setup.pending_accounts(),
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_feature_already_activated() {
+    let setup = Setup::new();
+
+    mollusk().process_and_validate_instruction(
+        &signal_support(
+            &setup.feature,
+            &setup.vote,
+            &setup.node,
+            &setup.payer,
+            3,
+            1,
+            0,
+        ),
+        &setup.accounts(
+            FeatureAccountBuilder::active(45).build(),
+            Account::default(),
+        ),
+        &[Check::err(ProgramError::Custom(
+            FeatureGateError::FeatureAlreadyActivated as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_vote_account_incorrect_owner() {
+    let setup = Setup::new();
+    let mut accounts = setup.pending_accounts();
+    accounts[2].1.owner = Address::new_unique();
+
+    mollusk().process_and_validate_instruction(
+        &signal_support(
+            &setup.feature,
+            &setup.vote,
+            &setup.node,
+            &setup.payer,
+            3,
+            1,
+            0,
+        ),
+        &accounts,
+        &[Check::err(ProgramError::InvalidAccountOwner)],
+    );
+}
+
+#[test]
+fn fail_invalid_vote_authority() {
+    let setup = Setup::new();
+    let impostor = Address::new_unique();
+    let mut accounts = setup.pending_accounts();
+    accounts[3].0 = impostor;
+
+    mollusk().process_and_validate_instruction(
+        &signal_support(
+            &setup.feature,
+            &setup.vote,
+            &impostor,
+            &setup.payer,
+            3,
+            1,
+            0,
+        ),
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            FeatureGateError::InvalidVoteAuthority as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_incorrect_support_signal_address() {
+    let setup = Setup::new();
+    let wrong_signal = Address::new_unique();
+    let mut instruction = signal_support(
+        &setup.feature,
+        &setup.vote,
+        &setup.node,
+        &setup.payer,
+        3,
+        1,
+        0,
+    );
+    instruction.accounts[0].pubkey = wrong_signal;
+    let mut accounts = setup.pending_accounts();
+    accounts[0].0 = wrong_signal;
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::InvalidSeeds)],
+    );
+}
+
+#[test]
+fn success_create() {
+    let setup = Setup::new();
+    let mut mollusk = mollusk();
+    mollusk.warp_to_slot(100);
+
+    let expected = SupportSignal {
+        feature_id: setup.feature,
+        vote_account: setup.vote,
+        major: 3,
+        minor: 1,
+        patch: 0,
+        signaled_at: 100,
+        tallied_epoch: None,
+    }
+    .pack();
+    mollusk.process_and_validate_instruction(
+        &signal_support(
+            &setup.feature,
+            &setup.vote,
+            &setup.node,
+            &setup.payer,
+            3,
+            1,
+            0,
+        ),
+        &setup.pending_accounts(),
+        &[
+            Check::success(),
+            Check::account(&setup.support_signal())
+                .owner(&feature::id())
+                .lamports(signal_rent())
+                .data(&expected)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn success_create_prefunded() {
+    let setup = Setup::new();
+    let mut accounts = setup.pending_accounts();
+    accounts[0].1 = Account::new(1, 0, &system_program::id());
+    accounts[3].0 = setup.withdrawer;
+
+    mollusk().process_and_validate_instruction(
+        &signal_support(
+            &setup.feature,
+            &setup.vote,
+            &setup.withdrawer,
+            &setup.payer,
+            3,
+            1,
+            0,
+        ),
+        &accounts,
+        &[
+            Check::success(),
+            Check::account(&setup.support_signal())
+                .owner(&feature::id())
+                .lamports(signal_rent())
+                .space(SupportSignal::LEN)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn success_update() {
+    let setup = Setup::new();
+    let mut mollusk = mollusk();
+    mollusk.warp_to_slot(200);
+
+    let existing = SupportSignal {
+        feature_id: setup.feature,
+        vote_account: setup.vote,
+        major: 2,
+        minor: 3,
+        patch: 4,
+        signaled_at: 100,
+        tallied_epoch: Some(0),
+    };
+    let mut signal = Account::new(signal_rent(), SupportSignal::LEN, &feature::id());
+    signal.data = existing.pack();
+
+    // The renewed signal keeps its tally, so it is not counted twice.
+    let expected = SupportSignal {
+        major: 3,
+        minor: 0,
+        patch: 1,
+        signaled_at: 200,
+        ..existing
+    }
+    .pack();
+    mollusk.process_and_validate_instruction(
+        &signal_support(
+            &setup.feature,
+            &setup.vote,
+            &setup.node,
+            &setup.payer,
+            3,
+            0,
+            1,
+        ),
+        &setup.accounts(FeatureAccountBuilder::pending().build(), signal),
+        &[
+            Check::success(),
+            Check::account(&setup.support_signal())
+                .lamports(signal_rent())
+                .data(&expected)
+                .build(),
+            Check::account(&setup.payer).lamports(1_000_000_000).build(),
+        ],
+    );
+}
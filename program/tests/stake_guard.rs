@@ -0,0 +1,419 @@
+use {
+    mollusk_svm::{program::keyed_account_for_system_program, result::Check, Mollusk},
+    solana_account::Account,
+    solana_address::Address,
+    solana_feature_gate_program::{
+        error::FeatureGateError,
+        instruction::{initialize_stake_guard, revoke_unsupported_activation, tally_support},
+        state::{
+            find_config_address, find_stake_guard_address, find_support_signal_address,
+            ScheduledActivation, StakeGuard, SupportSignal,
+        },
+    },
+    solana_feature_gate_test_utils::{
+        feature_rent, keyed_audit_log_account, keyed_incinerator_account, mollusk,
+        FeatureAccountBuilder,
+    },
+    solana_program_error::ProgramError,
+    solana_rent::Rent,
+    solana_sdk_ids::{feature, incinerator, system_program},
+};
+
+/// A slot in the revoke window at the end of epoch 0.
+const WINDOW_SLOT: u64 = 400_000;
+
+fn guard_rent() -> u64 {
+    Rent::default().minimum_balance(StakeGuard::LEN)
+}
+
+fn guard_account(feature_id: &Address, epoch: u64, supporting_stake: u64) -> Account {
+    let mut account = Account::new(guard_rent(), StakeGuard::LEN, &feature::id());
+    account.data = StakeGuard {
+        feature_id: *feature_id,
+        threshold_bps: 6_667,
+        epoch,
+        supporting_stake,
+    }
+    .pack();
+    account
+}
+
+fn signal(feature_id: &Address, vote_account: &Address, tallied_epoch: Option<u64>) -> Vec<u8> {
+    SupportSignal {
+        feature_id: *feature_id,
+        vote_account: *vote_account,
+        major: 3,
+        minor: 1,
+        patch: 0,
+        signaled_at: 0,
+        tallied_epoch,
+    }
+    .pack()
+}
+
+fn signal_account(
+    feature_id: &Address,
+    vote_account: &Address,
+    tallied_epoch: Option<u64>,
+) -> (Address, Account) {
+    let mut account = Account::new(
+        Rent::default().minimum_balance(SupportSignal::LEN),
+        SupportSignal::LEN,
+        &feature::id(),
+    );
+    account.data = signal(feature_id, vote_account, tallied_epoch);
+    (
+        find_support_signal_address(feature_id, vote_account).0,
+        account,
+    )
+}
+
+/// A Mollusk instance with `stakes` delegated to new vote accounts, which
+/// are returned in ascending order.
+fn mollusk_with_stakes(stakes: &[u64]) -> (Mollusk, Vec<Address>) {
+    let mut mollusk = mollusk();
+    let mut vote_accounts = stakes
+        .iter()
+        .map(|_| Address::new_unique())
+        .collect::<Vec<_>>();
+    vote_accounts.sort();
+    for (vote_account, stake) in vote_accounts.iter().zip(stakes) {
+        mollusk.epoch_stake.insert(*vote_account, *stake);
+    }
+    (mollusk, vote_accounts)
+}
+
+fn initialize_accounts(feature_id: &Address, payer: &Address) -> Vec<(Address, Account)> {
+    vec![
+        (find_stake_guard_address(feature_id).0, Account::default()),
+        (*feature_id, FeatureAccountBuilder::pending().build()),
+        (
+            *payer,
+            Account::new(1_000_000_000, 0, &system_program::id()),
+        ),
+        keyed_account_for_system_program(),
+    ]
+}
+
+fn revoke_accounts(feature_id: &Address, guard: Account) -> Vec<(Address, Account)> {
+    vec![
+        (*feature_id, FeatureAccountBuilder::pending().build()),
+        (find_stake_guard_address(feature_id).0, guard),
+        keyed_incinerator_account(),
+        (find_config_address().0, Account::default()),
+        keyed_audit_log_account(),
+    ]
+}
+
+#[test]
+fn fail_initialize_feature_not_signer() {
+    let feature_id = Address::new_unique();
+    let payer = Address::new_unique();
+    let mut instruction = initialize_stake_guard(&feature_id, &payer, 6_667);
+    instruction.accounts[1].is_signer = false;
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &initialize_accounts(&feature_id, &payer),
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn fail_initialize_invalid_threshold() {
+    let feature_id = Address::new_unique();
+    let payer = Address::new_unique();
+
+    for threshold_bps in [0, 10_001] {
+        mollusk().process_and_validate_instruction(
+            &initialize_stake_guard(&feature_id, &payer, threshold_bps),
+            &initialize_accounts(&feature_id, &payer),
+            &[Check::err(ProgramError::InvalidArgument)],
+        );
+    }
+}
+
+#[test]
+fn fail_initialize_already_initialized() {
+    let feature_id = Address::new_unique();
+    let payer = Address::new_unique();
+    let mut accounts = initialize_accounts(&feature_id, &payer);
+    accounts[0].1 = guard_account(&feature_id, 0, 0);
+
+    mollusk().process_and_validate_instruction(
+        &initialize_stake_guard(&feature_id, &payer, 6_667),
+        &accounts,
+        &[Check::err(ProgramError::AccountAlreadyInitialized)],
+    );
+}
+
+#[test]
+fn success_initialize() {
+    let feature_id = Address::new_unique();
+    let payer = Address::new_unique();
+    let mut mollusk = mollusk();
+    mollusk.warp_to_slot(WINDOW_SLOT * 3);
+
+    let expected = StakeGuard {
+        feature_id,
+        threshold_bps: 6_667,
+        epoch: 2,
+        supporting_stake: 0,
+    }
+    .pack();
+    mollusk.process_and_validate_instruction(
+        &initialize_stake_guard(&feature_id, &payer, 6_667),
+        &initialize_accounts(&feature_id, &payer),
+        &[
+            Check::success(),
+            Check::account(&find_stake_guard_address(&feature_id).0)
+                .owner(&feature::id())
+                .lamports(guard_rent())
+                .data(&expected)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn success_initialize_scheduled() {
+    let feature_id = Address::new_unique();
+    let payer = Address::new_unique();
+    let mut accounts = initialize_accounts(&feature_id, &payer);
+    accounts[1].1 = FeatureAccountBuilder::pending()
+        .data(
+            ScheduledActivation {
+                not_before_epoch: 5,
+            }
+            .pack(),
+        )
+        .build();
+
+    mollusk().process_and_validate_instruction(
+        &initialize_stake_guard(&feature_id, &payer, 6_667),
+        &accounts,
+        &[
+            Check::success(),
+            Check::account(&find_stake_guard_address(&feature_id).0)
+                .owner(&feature::id())
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_tally_unsorted_signals() {
+    let feature_id = Address::new_unique();
+    let (mollusk, vote_accounts) = mollusk_with_stakes(&[10, 20]);
+
+    let mut instruction = tally_support(&feature_id, &vote_accounts);
+    instruction.accounts.swap(1, 2);
+    let mut accounts = vec![(
+        find_stake_guard_address(&feature_id).0,
+        guard_account(&feature_id, 0, 0),
+    )];
+    accounts.extend(
+        vote_accounts
+            .iter()
+            .rev()
+            .map(|vote_account| signal_account(&feature_id, vote_account, None)),
+    );
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn fail_tally_signal_for_other_feature() {
+    let feature_id = Address::new_unique();
+    let other_feature_id = Address::new_unique();
+    let (mollusk, vote_accounts) = mollusk_with_stakes(&[10]);
+
+    let (signal, account) = signal_account(&other_feature_id, &vote_accounts[0], None);
+    let mut instruction = tally_support(&feature_id, &vote_accounts);
+    instruction.accounts[1].pubkey = signal;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (
+                find_stake_guard_address(&feature_id).0,
+                guard_account(&feature_id, 0, 0),
+            ),
+            (signal, account),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn success_tally() {
+    let feature_id = Address::new_unique();
+    let (mut mollusk, vote_accounts) = mollusk_with_stakes(&[10, 20, 30]);
+    mollusk.warp_to_slot(WINDOW_SLOT * 2);
+    let stake_guard = find_stake_guard_address(&feature_id).0;
+
+    // A tally from an earlier epoch is replaced, and signals tallied in an
+    // earlier epoch count again.
+    let mut accounts = vec![(stake_guard, guard_account(&feature_id, 0, 1_000))];
+    accounts.push(signal_account(&feature_id, &vote_accounts[0], Some(0)));
+    accounts.push(signal_account(&feature_id, &vote_accounts[1], None));
+
+    let expected = guard_account(&feature_id, 1, 30).data;
+    let mut checks = vec![
+        Check::success(),
+        Check::account(&stake_guard).data(&expected).build(),
+    ];
+    let expected_signals = vote_accounts[..2]
+        .iter()
+        .map(|vote_account| signal(&feature_id, vote_account, Some(1)))
+        .collect::<Vec<_>>();
+    checks.extend(
+        accounts[1..]
+            .iter()
+            .zip(&expected_signals)
+            .map(|((address, _), expected)| Check::account(address).data(expected).build()),
+    );
+    mollusk.process_and_validate_instruction(
+        &tally_support(&feature_id, &vote_accounts[..2]),
+        &accounts,
+        &checks,
+    );
+}
+
+#[test]
+fn success_tally_accumulates() {
+    let feature_id = Address::new_unique();
+    let (mut mollusk, vote_accounts) = mollusk_with_stakes(&[10, 20, 30]);
+    mollusk.warp_to_slot(WINDOW_SLOT * 2);
+    let stake_guard = find_stake_guard_address(&feature_id).0;
+
+    // Signals already tallied this epoch are skipped, the rest are added to
+    // the epoch's tally.
+    let expected = guard_account(&feature_id, 1, 60).data;
+    mollusk.process_and_validate_instruction(
+        &tally_support(&feature_id, &vote_accounts),
+        &[
+            (stake_guard, guard_account(&feature_id, 1, 50)),
+            signal_account(&feature_id, &vote_accounts[0], None),
+            signal_account(&feature_id, &vote_accounts[1], Some(1)),
+            signal_account(&feature_id, &vote_accounts[2], Some(1)),
+        ],
+        &[
+            Check::success(),
+            Check::account(&stake_guard).data(&expected).build(),
+        ],
+    );
+}
+
+#[test]
+fn success_tally_twice() {
+    let feature_id = Address::new_unique();
+    let (mut mollusk, vote_accounts) = mollusk_with_stakes(&[10, 20]);
+    mollusk.warp_to_slot(WINDOW_SLOT * 2);
+    let stake_guard = find_stake_guard_address(&feature_id).0;
+
+    let mut accounts = vec![(stake_guard, guard_account(&feature_id, 1, 0))];
+    accounts.extend(
+        vote_accounts
+            .iter()
+            .map(|vote_account| signal_account(&feature_id, vote_account, None)),
+    );
+
+    // Each signal counts once however many times it is tallied.
+    let expected = guard_account(&feature_id, 1, 30).data;
+    mollusk.process_and_validate_instruction_chain(
+        &[
+            (
+                &tally_support(&feature_id, &vote_accounts[..1]),
+                &[Check::success()],
+            ),
+            (
+                &tally_support(&feature_id, &vote_accounts),
+                &[Check::success()],
+            ),
+            (
+                &tally_support(&feature_id, &vote_accounts),
+                &[
+                    Check::success(),
+                    Check::account(&stake_guard).data(&expected).build(),
+                ],
+            ),
+        ],
+        &accounts,
+    );
+}
+
+#[test]
+fn fail_revoke_window_closed() {
+    let feature_id = Address::new_unique();
+    let (mut mollusk, _) = mollusk_with_stakes(&[100]);
+    mollusk.warp_to_slot(WINDOW_SLOT / 2);
+
+    mollusk.process_and_validate_instruction(
+        &revoke_unsupported_activation(&feature_id),
+        &revoke_accounts(&feature_id, guard_account(&feature_id, 0, 0)),
+        &[Check::err(ProgramError::Custom(
+            FeatureGateError::RevokeWindowClosed as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_revoke_sufficiently_supported() {
+    let feature_id = Address::new_unique();
+    let (mut mollusk, _) = mollusk_with_stakes(&[100]);
+    mollusk.warp_to_slot(WINDOW_SLOT);
+
+    mollusk.process_and_validate_instruction(
+        &revoke_unsupported_activation(&feature_id),
+        &revoke_accounts(&feature_id, guard_account(&feature_id, 0, 67)),
+        &[Check::err(ProgramError::Custom(
+            FeatureGateError::FeatureSufficientlySupported as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_revoke_feature_already_activated() {
+    let feature_id = Address::new_unique();
+    let (mut mollusk, _) = mollusk_with_stakes(&[100]);
+    mollusk.warp_to_slot(WINDOW_SLOT);
+
+    let mut accounts = revoke_accounts(&feature_id, guard_account(&feature_id, 0, 0));
+    accounts[0].1 = FeatureAccountBuilder::active(45).build();
+
+    mollusk.process_and_validate_instruction(
+        &revoke_unsupported_activation(&feature_id),
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            FeatureGateError::FeatureAlreadyActivated as u32,
+        ))],
+    );
+}
+
+#[test]
+fn success_revoke() {
+    let feature_id = Address::new_unique();
+    let (mut mollusk, _) = mollusk_with_stakes(&[100]);
+    mollusk.warp_to_slot(WINDOW_SLOT + 432_000);
+
+    // Support tallied in an earlier epoch does not count.
+    mollusk.process_and_validate_instruction(
+        &revoke_unsupported_activation(&feature_id),
+        &revoke_accounts(&feature_id, guard_account(&feature_id, 0, 100)),
+        &[
+            Check::success(),
+            Check::account(&feature_id).closed().build(),
+            Check::account(&find_stake_guard_address(&feature_id).0)
+                .closed()
+                .build(),
+            Check::account(&incinerator::id())
+                .lamports(feature_rent() + guard_rent())
+                .build(),
+        ],
+    );
+}